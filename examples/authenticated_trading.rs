@@ -101,7 +101,13 @@ async fn main() -> Result<()> {
     // Step 6: Get trade history
     println!("\n6. Fetching trade history...");
     let trades = trading_client.get_trades(Default::default()).await?;
-    println!("Trade history: {:?}", trades);
+    println!("Found {} trades", trades.data.len());
+    for trade in trades.data.iter().take(5) {
+        println!(
+            "  Trade {}: {:?} {} @ {} (fee {} bps)",
+            trade.id, trade.side, trade.size, trade.price, trade.fee_rate_bps
+        );
+    }
 
     // ========================================================================
     // POLYPROXY WALLET EXAMPLE
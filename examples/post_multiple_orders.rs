@@ -2,7 +2,7 @@ use alloy_signer_local::PrivateKeySigner;
 use polymarket_rs::client::{AuthenticatedClient, TradingClient};
 use polymarket_rs::orders::OrderBuilder;
 use polymarket_rs::types::{
-    CreateOrderOptions, OrderArgs, OrderType, PostOrderArgs, Side, SignatureType,
+    CreateOrderOptions, OrderArgs, OrderStatus, OrderType, PostOrderArgs, Side, SignatureType,
 };
 use polymarket_rs::Result;
 use rust_decimal::Decimal;
@@ -101,7 +101,12 @@ async fn main() -> Result<()> {
     for (i, result) in results.iter().enumerate() {
         println!("\nOrder {}:", i + 1);
         println!("  Order ID: {}", result.order_id.as_str());
-        println!("  Status: {}", result.status);
+        match result.order_status() {
+            OrderStatus::Live => println!("  Status: resting on the book"),
+            OrderStatus::Matched => println!("  Status: matched"),
+            OrderStatus::Delayed => println!("  Status: delayed before matching"),
+            other => println!("  Status: {}", other.as_str()),
+        }
         println!("  Success: {}", result.success);
         if !result.error_msg.is_empty() {
             println!("  Error: {}", result.error_msg);
@@ -32,13 +32,12 @@
 //! ```
 
 use alloy_signer_local::PrivateKeySigner;
-use polymarket_rs::relayer::{BuilderApiCreds, CtfEncoder, RelayerClient, SafeTransaction};
-use polymarket_rs::Result;
+use polymarket_rs::config::hosts;
+use polymarket_rs::relayer::{BuilderApiCreds, RedeemableParams, RelayerClient};
+use polymarket_rs::types::IndexSet;
+use polymarket_rs::{Error, Result};
 use std::str::FromStr;
 
-const DATA_API_MAINNET: &str = "https://data-api.polymarket.com";
-const DATA_API_TESTNET: &str = "https://data-api.polymarket.com"; // Same for testnet
-
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load environment variables
@@ -52,7 +51,7 @@ async fn main() -> Result<()> {
 
     // Get Builder API credentials from environment variables
     let builder_creds = BuilderApiCreds::from_env().expect(
-        "Builder API credentials not set. Set POLY_API_KEY, POLY_API_SECRET, and POLY_PASSPHRASE",
+        "Builder API credentials missing or invalid. Set POLY_API_KEY, POLY_API_SECRET, and POLY_PASSPHRASE",
     );
 
     // Initialize the Relayer Client
@@ -62,17 +61,8 @@ async fn main() -> Result<()> {
         .parse::<u64>()
         .unwrap_or(137);
 
-    let relayer_url = if chain_id == 137 {
-        "https://relayer-v2.polymarket.com"
-    } else {
-        "https://relayer-v2-staging.polymarket.dev"
-    };
-
-    let data_api_url = if chain_id == 137 {
-        DATA_API_MAINNET
-    } else {
-        DATA_API_TESTNET
-    };
+    let relayer_url = hosts::relayer_host(chain_id).expect("No relayer host for this chain");
+    let data_api_url = hosts::data_api_host();
 
     println!("Using chain ID: {}", chain_id);
     println!("Relayer URL: {}", relayer_url);
@@ -84,35 +74,29 @@ async fn main() -> Result<()> {
     let safe_address = client.get_expected_safe()?;
     println!("Safe wallet address: {}", safe_address);
 
-    // Check if the Safe is deployed
-    let deployed = client.get_deployed(&safe_address).await?;
-    println!("Safe deployed: {}", deployed);
-
-    if !deployed {
-        println!("\n=== Deploying Safe Wallet ===");
-        let deploy_result = client.deploy().await?;
-        println!("Deploy transaction ID: {}", deploy_result.transaction_id);
-
-        // Wait for deployment to complete
-        println!("Waiting for deployment...");
-        let tx = client
-            .wait_for_transaction(&deploy_result.transaction_id, Some(30), Some(2000))
-            .await?;
-
-        if let Some(tx) = tx {
+    // Deploy the Safe first if needed
+    match client.ensure_deployed().await {
+        Ok(None) => println!("Safe already deployed"),
+        Ok(Some(tx)) => {
             println!("Safe deployed successfully!");
             println!("Transaction hash: {:?}", tx.transaction_hash);
-        } else {
-            println!("Deployment is taking longer than expected. Check the transaction ID.");
+        }
+        Err(Error::TransactionTimeout { .. }) => {
+            println!("Deployment is taking longer than expected. Try again later.");
             return Ok(());
         }
+        Err(e) => return Err(e),
     }
 
     // Get all redeemable positions and redeem them
     println!("\n=== Fetching Redeemable Positions ===");
 
     let redeemable_positions = client
-        .get_redeemable_positions(data_api_url, &safe_address)
+        .get_redeemable_positions(
+            data_api_url,
+            &safe_address,
+            Some(RedeemableParams::new().with_limit(None)),
+        )
         .await?;
 
     if redeemable_positions.is_empty() {
@@ -151,11 +135,11 @@ async fn main() -> Result<()> {
                 pos.outcome
             );
 
-            // Calculate the correct index set based on outcome_index
-            // index_set is a bitmask: 1 << outcome_index
-            // outcome_index 0 (YES) -> index_set 1 (binary: 01)
-            // outcome_index 1 (NO)  -> index_set 2 (binary: 10)
-            let index_set = 1u32 << pos.outcome_index;
+            let Some(index_set) = IndexSet::from_outcome_index(pos.outcome_index) else {
+                println!("  ✗ Outcome index {} is out of range", pos.outcome_index);
+                fail_count += 1;
+                continue;
+            };
 
             match client
                 .redeem_positions(
@@ -202,31 +186,28 @@ async fn main() -> Result<()> {
 
     // 1. Split position (convert USDC to YES/NO tokens)
     println!("\n1. Split Position:");
-    println!("   client.split_position(condition_id, amount, metadata).await?");
+    println!("   client.split_position(condition_id, amount, None, metadata).await?");
     println!("   - Converts USDC to YES and NO tokens");
     println!("   - Requires USDC approval to CTF contract first");
 
     // 2. Merge positions (convert YES+NO tokens back to USDC)
     println!("\n2. Merge Positions:");
-    println!("   client.merge_positions(condition_id, amount, metadata).await?");
+    println!("   client.merge_positions(condition_id, amount, None, metadata).await?");
     println!("   - Converts equal amounts of YES and NO tokens back to USDC");
 
     // 3. Custom transaction execution
     println!("\n3. Custom Transactions:");
     println!("   Use client.execute(transactions, metadata) for custom operations");
 
-    // Example of creating a custom approval transaction
-    let ctf_address = client.contract_config().ctf.clone();
-    let collateral_address = client.contract_config().collateral.clone();
-
-    println!("\n   Example - Approve USDC for CTF:");
-    let approve_data = CtfEncoder::encode_approve_max(&ctf_address);
-    let _approve_tx = SafeTransaction::new(&collateral_address, approve_data);
-    println!("   let tx = SafeTransaction::new(&collateral, encode_approve_max(&ctf));");
-    println!("   client.execute(vec![tx], Some(\"Approve USDC\")).await?");
+    // 4. Token approvals
+    println!("\n4. Approvals:");
+    println!("   client.approve_ctf_max(Some(\"Approve USDC for CTF\")).await?");
+    println!("   client.approve_exchange_max(Some(\"Approve USDC for Exchange\")).await?");
+    println!("   client.approve_token(token, spender, amount, metadata).await?");
 
     println!("\n=== Contract Addresses ===");
     println!("CTF: {}", client.contract_config().ctf);
+    println!("Exchange: {}", client.contract_config().exchange);
     println!("Collateral (USDC): {}", client.contract_config().collateral);
     println!("Safe Factory: {}", client.contract_config().safe_factory);
     println!(
@@ -0,0 +1,77 @@
+//! Example: Sign with a remote key (KMS, HSM, HTTP signing service)
+//!
+//! `RemoteSigner` forwards every 32-byte hash to an async function and uses the
+//! returned signature. This example posts hashes to an HTTP signing service, which
+//! could be a small proxy in front of AWS KMS.
+//!
+//! The service is expected to accept `{"hash": "0x..."}` and answer with
+//! `{"signature": "0x..."}` (65 bytes, r || s || v).
+//!
+//! API key creation signs asynchronously, so any runtime works here. Only the
+//! sync signing paths (`sign_typed_data_hash`, and `create_order` without the
+//! `_async` suffix) need the multi-threaded Tokio runtime with a `RemoteSigner`.
+//!
+//! ## Environment Variables
+//!
+//! - `SIGNER_URL`: URL of the signing service
+//! - `SIGNER_ADDRESS`: Address of the remote key
+//!
+//! ## Usage
+//!
+//! ```bash
+//! SIGNER_URL=http://localhost:8080/sign \
+//! SIGNER_ADDRESS=0x... \
+//! cargo run --example remote_signer
+//! ```
+
+use polymarket_rs::client::AuthenticatedClient;
+use polymarket_rs::{Address, RemoteSigner, Result};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let signer_url = std::env::var("SIGNER_URL").expect("SIGNER_URL environment variable not set");
+    let address: Address = std::env::var("SIGNER_ADDRESS")
+        .expect("SIGNER_ADDRESS environment variable not set")
+        .parse()
+        .expect("Invalid signer address");
+
+    let http = reqwest::Client::new();
+    let signer = RemoteSigner::new(address, move |hash| {
+        let http = http.clone();
+        let url = signer_url.clone();
+        async move {
+            let response: SignResponse = http
+                .post(&url)
+                .json(&serde_json::json!({ "hash": hash.to_string() }))
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(alloy_signer::Error::other)?
+                .json()
+                .await
+                .map_err(alloy_signer::Error::other)?;
+
+            response
+                .signature
+                .parse()
+                .map_err(alloy_signer::Error::other)
+        }
+    });
+
+    println!("Remote signer address: {}", address);
+
+    // The remote signer can be used anywhere a PrivateKeySigner is accepted
+    let auth_client =
+        AuthenticatedClient::new("https://clob.polymarket.com", signer, 137, None, None);
+
+    let api_creds = auth_client.create_or_derive_api_key().await?;
+    println!("API Key: {}", api_creds.api_key);
+
+    Ok(())
+}
@@ -5,7 +5,8 @@ use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = MarketWsClient::new();
+    // Ping every 10 seconds; the PONG replies keep the idle timeout below from firing
+    let client = MarketWsClient::new().with_ping_interval(Duration::from_secs(10));
 
     // Token IDs to subscribe to
     let token_ids = vec![
@@ -22,6 +23,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_delay: Duration::from_secs(30),
         multiplier: 2.0,
         max_attempts: None, // Infinite reconnection attempts
+        // Reconnect if neither an event nor a PONG arrives for 60 seconds
+        idle_timeout: Some(Duration::from_secs(60)),
+        // Randomize each delay by up to ±10%
+        jitter: 0.1,
+        on_reconnect: None,
     };
 
     // Create a reconnecting stream that will automatically reconnect on disconnection
@@ -30,7 +36,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let token_ids = token_ids.clone();
         async move {
             println!("🔄 Connecting to WebSocket...");
-            let (stream, _handle) = client.subscribe_with_handle(token_ids).await?;
+            let stream = client.subscribe(token_ids).await?;
             println!("✅ Connected successfully!");
             Ok(stream)
         }
@@ -42,6 +48,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut event_count = 0;
     while let Some(result) = stream.next().await {
         match result {
+            // Keep-alive reply, no market data
+            Ok(WsEvent::Pong) => {}
             Ok(event) => {
                 event_count += 1;
                 match event {
@@ -93,6 +101,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("  New Tick Size: {}", tick.new_tick_size);
                         println!();
                     }
+                    WsEvent::Unknown(value) => {
+                        println!("[Unknown Event #{}] {}", event_count, value);
+                        println!();
+                    }
+                    WsEvent::Pong => {}
                 }
             }
             Err(e) => {
@@ -103,6 +116,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("WebSocket stream ended.");
+    println!("WebSocket stream ended ({:?}).", stream.connection_state());
     Ok(())
 }
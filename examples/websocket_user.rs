@@ -39,6 +39,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_delay: Duration::from_secs(30),
         multiplier: 2.0,
         max_attempts: None, // Unlimited reconnection attempts
+        idle_timeout: None,
+        jitter: 0.1, // Spread out reconnects of many clients
+        on_reconnect: None,
     };
 
     // Create a reconnecting stream that will automatically reconnect on disconnection
@@ -0,0 +1,212 @@
+#!/usr/bin/env python3
+"""Recompute the fixed relayer test vectors pinned in src/relayer/client.rs
+
+Standalone (Python 3 standard library only) so the vectors don't depend on
+the alloy code they check. Run `python3 scripts/relayer_vectors.py` and
+compare the output with the constants in the tests.
+"""
+
+import hashlib
+import hmac
+
+# Keccak-f[1600] round constants and rotation offsets
+ROUND_CONSTANTS = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+]
+ROTATIONS = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+]
+MASK = (1 << 64) - 1
+
+
+def _rol(value, shift):
+    return ((value << shift) | (value >> (64 - shift))) & MASK if shift else value
+
+
+def _keccak_f(state):
+    for rc in ROUND_CONSTANTS:
+        c = [state[x][0] ^ state[x][1] ^ state[x][2] ^ state[x][3] ^ state[x][4] for x in range(5)]
+        d = [c[(x - 1) % 5] ^ _rol(c[(x + 1) % 5], 1) for x in range(5)]
+        state = [[state[x][y] ^ d[x] for y in range(5)] for x in range(5)]
+        b = [[0] * 5 for _ in range(5)]
+        for x in range(5):
+            for y in range(5):
+                b[y][(2 * x + 3 * y) % 5] = _rol(state[x][y], ROTATIONS[x][y])
+        state = [
+            [b[x][y] ^ (~b[(x + 1) % 5][y] & b[(x + 2) % 5][y]) for y in range(5)]
+            for x in range(5)
+        ]
+        state[0][0] ^= rc
+    return state
+
+
+def keccak256(data: bytes) -> bytes:
+    """Ethereum's Keccak-256 (original padding, not SHA3-256)"""
+    rate = 136
+    padded = bytearray(data) + b"\x01"
+    padded += b"\x00" * (-len(padded) % rate)
+    padded[-1] |= 0x80
+    state = [[0] * 5 for _ in range(5)]
+    for offset in range(0, len(padded), rate):
+        block = padded[offset:offset + rate]
+        for i in range(rate // 8):
+            lane = int.from_bytes(block[8 * i:8 * i + 8], "little")
+            state[i % 5][i // 5] ^= lane
+        state = _keccak_f(state)
+    out = b"".join(state[i % 5][i // 5].to_bytes(8, "little") for i in range(4))
+    return out
+
+
+def address(hex_address: str) -> bytes:
+    raw = bytes.fromhex(hex_address[2:])
+    assert len(raw) == 20
+    return raw
+
+
+def create2(factory: str, salt: bytes, init_code_hash: bytes) -> str:
+    digest = keccak256(b"\xff" + address(factory) + salt + init_code_hash)
+    return "0x" + digest[12:].hex()
+
+
+# Proxy wallets: the SDK's getCreate2Address with salt keccak256(encodePacked(eoa))
+PROXY_FACTORY = "0xaB45c5A4B0c941a2F231C04C3f49182e1A254052"
+PROXY_INIT_CODE_HASH = bytes.fromhex(
+    "d21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b"
+)
+PROXY_EOAS = [
+    "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+    "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+    "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+]
+
+
+def proxy_vectors():
+    print("Proxy wallets under", PROXY_FACTORY)
+    for eoa in PROXY_EOAS:
+        proxy = create2(PROXY_FACTORY, keccak256(address(eoa)), PROXY_INIT_CODE_HASH)
+        print(" ", eoa, "->", proxy)
+
+
+# secp256k1 with RFC 6979 deterministic nonces and low-s signatures
+P = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F
+N = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141
+G = (
+    0x79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798,
+    0x483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8,
+)
+
+
+def _point_add(a, b):
+    if a is None:
+        return b
+    if b is None:
+        return a
+    if a[0] == b[0] and (a[1] + b[1]) % P == 0:
+        return None
+    if a == b:
+        slope = 3 * a[0] * a[0] * pow(2 * a[1], -1, P) % P
+    else:
+        slope = (b[1] - a[1]) * pow(b[0] - a[0], -1, P) % P
+    x = (slope * slope - a[0] - b[0]) % P
+    return (x, (slope * (a[0] - x) - a[1]) % P)
+
+
+def _point_mul(k, point=G):
+    result = None
+    while k:
+        if k & 1:
+            result = _point_add(result, point)
+        point = _point_add(point, point)
+        k >>= 1
+    return result
+
+
+def _rfc6979_nonce(key: int, digest: bytes) -> int:
+    x = key.to_bytes(32, "big")
+    h = (int.from_bytes(digest, "big") % N).to_bytes(32, "big")
+    v, k = b"\x01" * 32, b"\x00" * 32
+    k = hmac.new(k, v + b"\x00" + x + h, hashlib.sha256).digest()
+    v = hmac.new(k, v, hashlib.sha256).digest()
+    k = hmac.new(k, v + b"\x01" + x + h, hashlib.sha256).digest()
+    v = hmac.new(k, v, hashlib.sha256).digest()
+    while True:
+        v = hmac.new(k, v, hashlib.sha256).digest()
+        nonce = int.from_bytes(v, "big")
+        if 1 <= nonce < N:
+            return nonce
+        k = hmac.new(k, v + b"\x00", hashlib.sha256).digest()
+        v = hmac.new(k, v, hashlib.sha256).digest()
+
+
+def sign_prehash(key: int, digest: bytes) -> bytes:
+    """65-byte r || s || v signature of a 32-byte digest, v = 27/28"""
+    nonce = _rfc6979_nonce(key, digest)
+    point = _point_mul(nonce)
+    r = point[0] % N
+    s = pow(nonce, -1, N) * (int.from_bytes(digest, "big") + r * key) % N
+    recovery_id = point[1] & 1
+    if s > N // 2:
+        s = N - s
+        recovery_id ^= 1
+    return r.to_bytes(32, "big") + s.to_bytes(32, "big") + bytes([27 + recovery_id])
+
+
+def eip191_hash(message: bytes) -> bytes:
+    return keccak256(b"\x19Ethereum Signed Message:\n" + str(len(message)).encode() + message)
+
+
+# anvil/hardhat account #0, TEST_KEY in the tests
+TEST_KEY = 0xAC0974BEC39A17E36BA4A6B4D238FF944BACB478CBED5EFCAE784D7BF4F2FF80
+
+
+def struct_hash_vectors():
+    # test_sign_struct_hash_v_values: the EIP-191 prefixed struct hash,
+    # v shifted by 4 for Safe and left at 27/28 for Proxy
+    signature = sign_prehash(TEST_KEY, eip191_hash(keccak256(b"polymarket relayer")))
+    print("Struct hash signatures")
+    print("  safe ", signature[:64].hex() + bytes([signature[64] + 4]).hex())
+    print("  proxy", signature.hex())
+
+
+def typed_data_vectors():
+    # test_safe_and_typed_data_conventions: the digest signed directly, and
+    # the EIP-191 prefixed digest signed the Safe way
+    digest = keccak256(b"\x19\x01" + keccak256(b"domain") + keccak256(b"struct"))
+    typed = sign_prehash(TEST_KEY, digest)
+    safe = sign_prehash(TEST_KEY, eip191_hash(digest))
+    print("Typed data signatures")
+    print("  typed", typed.hex())
+    print("  safe ", safe[:64].hex() + bytes([safe[64] + 4]).hex())
+
+
+def self_check():
+    assert keccak256(b"").hex() == (
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    )
+    # Safe of the test EOA under the mainnet factory, pinned upstream
+    safe = create2(
+        "0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b",
+        keccak256(b"\x00" * 12 + address(PROXY_EOAS[0])),
+        bytes.fromhex("2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf"),
+    )
+    assert safe == "0x6d8c4e9adf5748af82dabe2c6225207770d6b4fa"
+    # The test key's address
+    public = _point_mul(TEST_KEY)
+    eoa = keccak256(public[0].to_bytes(32, "big") + public[1].to_bytes(32, "big"))[12:]
+    assert eoa.hex() == PROXY_EOAS[1][2:]
+
+
+if __name__ == "__main__":
+    self_check()
+    proxy_vectors()
+    struct_hash_vectors()
+    typed_data_vectors()
@@ -0,0 +1,284 @@
+//! Blocking wrappers around the read-only clients
+//!
+//! Enabled with the `blocking` feature. Each client owns a current-thread
+//! Tokio runtime and blocks on the async client's futures, so synchronous
+//! applications don't need to set up a runtime themselves.
+//!
+//! Like `reqwest::blocking`, these clients must not be used from within an
+//! async runtime; calling them from async code panics.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_rs::blocking::GammaClient;
+//! use polymarket_rs::request::GammaMarketParams;
+//!
+//! # fn main() -> polymarket_rs::Result<()> {
+//! let client = GammaClient::new("https://gamma-api.polymarket.com")?;
+//! let markets = client.get_markets(Some(GammaMarketParams::new().with_limit(10)))?;
+//! println!("Found {} markets", markets.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use tokio::runtime::Runtime;
+
+use crate::client;
+use crate::config::RequestOptions;
+use crate::error::{Error, Result};
+use crate::http::{HttpClient, HttpTransport};
+use crate::request::{
+    ActivityQueryParams, GammaEventParams, GammaMarketParams, GammaSeriesParams,
+    PositionQueryParams, TradeQueryParams,
+};
+use crate::types::{
+    Activity, ClosedPosition, GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag,
+    PortfolioSummary, Position, PositionValue, Resolution, Trade,
+};
+
+/// Runtime driving the requests of one blocking client
+fn new_runtime() -> Result<Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| Error::Config(format!("Failed to start Tokio runtime: {}", e)))
+}
+
+/// Blocking version of [`client::GammaClient`]
+pub struct GammaClient<T = HttpClient> {
+    inner: client::GammaClient<T>,
+    runtime: Runtime,
+}
+
+impl GammaClient {
+    /// Create a new blocking GammaClient
+    ///
+    /// # Arguments
+    /// * `host` - The base URL for the Gamma API (e.g., "https://gamma-api.polymarket.com")
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if the runtime cannot be started.
+    pub fn new(host: impl Into<String>) -> Result<Self> {
+        client::GammaClient::new(host).blocking()
+    }
+}
+
+impl<T: HttpTransport> client::GammaClient<T> {
+    /// Turn this client into a [`blocking::GammaClient`](GammaClient)
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if the runtime cannot be started.
+    pub fn blocking(self) -> Result<GammaClient<T>> {
+        Ok(GammaClient {
+            inner: self,
+            runtime: new_runtime()?,
+        })
+    }
+}
+
+impl<T: HttpTransport> GammaClient<T> {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [`client::GammaClient::get_markets`]
+    pub fn get_markets(&self, params: Option<GammaMarketParams>) -> Result<Vec<GammaMarket>> {
+        self.block_on(self.inner.get_markets(params))
+    }
+
+    /// See [`client::GammaClient::get_market`]
+    pub fn get_market(&self, condition_id: &str) -> Result<GammaMarket> {
+        self.block_on(self.inner.get_market(condition_id))
+    }
+
+    /// See [`client::GammaClient::get_market_by_id`]
+    pub fn get_market_by_id(&self, id: &str) -> Result<GammaMarket> {
+        self.block_on(self.inner.get_market_by_id(id))
+    }
+
+    /// See [`client::GammaClient::get_market_by_slug`]
+    pub fn get_market_by_slug(&self, slug: &str) -> Result<GammaMarket> {
+        self.block_on(self.inner.get_market_by_slug(slug))
+    }
+
+    /// See [`client::GammaClient::get_market_by_condition_id`]
+    pub fn get_market_by_condition_id(&self, condition_id: &str) -> Result<GammaMarket> {
+        self.block_on(self.inner.get_market_by_condition_id(condition_id))
+    }
+
+    /// See [`client::GammaClient::get_resolution`]
+    pub fn get_resolution(&self, condition_id: &str) -> Result<Resolution> {
+        self.block_on(self.inner.get_resolution(condition_id))
+    }
+
+    /// See [`client::GammaClient::get_tags`]
+    pub fn get_tags(&self) -> Result<Vec<GammaTag>> {
+        self.block_on(self.inner.get_tags())
+    }
+
+    /// See [`client::GammaClient::get_categories`]
+    pub fn get_categories(&self) -> Result<Vec<GammaCategory>> {
+        self.block_on(self.inner.get_categories())
+    }
+
+    /// See [`client::GammaClient::get_events`]
+    pub fn get_events(&self) -> Result<Vec<GammaEvent>> {
+        self.block_on(self.inner.get_events())
+    }
+
+    /// See [`client::GammaClient::get_events_with`]
+    pub fn get_events_with(&self, params: GammaEventParams) -> Result<Vec<GammaEvent>> {
+        self.block_on(self.inner.get_events_with(params))
+    }
+
+    /// See [`client::GammaClient::get_event_by_id`]
+    pub fn get_event_by_id(&self, id: &str) -> Result<GammaEvent> {
+        self.block_on(self.inner.get_event_by_id(id))
+    }
+
+    /// See [`client::GammaClient::get_series`]
+    pub fn get_series(&self) -> Result<Vec<GammaSeries>> {
+        self.block_on(self.inner.get_series())
+    }
+
+    /// See [`client::GammaClient::get_series_with`]
+    pub fn get_series_with(&self, params: GammaSeriesParams) -> Result<Vec<GammaSeries>> {
+        self.block_on(self.inner.get_series_with(params))
+    }
+
+    /// See [`client::GammaClient::get_series_by_id`]
+    pub fn get_series_by_id(&self, id: &str) -> Result<GammaSeries> {
+        self.block_on(self.inner.get_series_by_id(id))
+    }
+}
+
+/// Blocking version of [`client::DataClient`]
+pub struct DataClient<T = HttpClient> {
+    inner: client::DataClient<T>,
+    runtime: Runtime,
+}
+
+impl DataClient {
+    /// Create a new blocking DataClient
+    ///
+    /// # Arguments
+    /// * `host` - The base URL for the data API
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if the runtime cannot be started.
+    pub fn new(host: impl Into<String>) -> Result<Self> {
+        client::DataClient::new(host).blocking()
+    }
+}
+
+impl<T: HttpTransport> client::DataClient<T> {
+    /// Turn this client into a [`blocking::DataClient`](DataClient)
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if the runtime cannot be started.
+    pub fn blocking(self) -> Result<DataClient<T>> {
+        Ok(DataClient {
+            inner: self,
+            runtime: new_runtime()?,
+        })
+    }
+}
+
+impl<T: HttpTransport> DataClient<T> {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [`client::DataClient::get_positions`]
+    pub fn get_positions(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+    ) -> Result<Vec<Position>> {
+        self.block_on(self.inner.get_positions(user, params))
+    }
+
+    /// See [`client::DataClient::get_positions_with_options`]
+    pub fn get_positions_with_options(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+        options: &RequestOptions,
+    ) -> Result<Vec<Position>> {
+        self.block_on(self.inner.get_positions_with_options(user, params, options))
+    }
+
+    /// See [`client::DataClient::get_portfolio_summary`]
+    pub fn get_portfolio_summary(&self, user: &str) -> Result<PortfolioSummary> {
+        self.block_on(self.inner.get_portfolio_summary(user))
+    }
+
+    /// See [`client::DataClient::get_positions_value`]
+    pub fn get_positions_value(&self, user: &str) -> Result<Vec<PositionValue>> {
+        self.block_on(self.inner.get_positions_value(user))
+    }
+
+    /// See [`client::DataClient::get_trades`]
+    pub fn get_trades(&self, user: &str, params: Option<TradeQueryParams>) -> Result<Vec<Trade>> {
+        self.block_on(self.inner.get_trades(user, params))
+    }
+
+    /// See [`client::DataClient::get_activity`]
+    pub fn get_activity(
+        &self,
+        user: &str,
+        params: Option<ActivityQueryParams>,
+    ) -> Result<Vec<Activity>> {
+        self.block_on(self.inner.get_activity(user, params))
+    }
+
+    /// See [`client::DataClient::get_closed_positions`]
+    pub fn get_closed_positions(&self, user: &str) -> Result<Vec<ClosedPosition>> {
+        self.block_on(self.inner.get_closed_positions(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+
+    /// Start a mock server on a throwaway runtime, outside of any async context
+    fn mock_server(responses: Vec<(u16, String)>) -> (Runtime, MockServer) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start(responses));
+        (runtime, server)
+    }
+
+    #[test]
+    fn test_blocking_gamma_client() {
+        let (_runtime, server) = mock_server(vec![(
+            200,
+            r#"[{"id":"1","question":"Will it rain?","description":"","conditionId":"0xabc","slug":"rain"}]"#
+                .to_string(),
+        )]);
+
+        let client = GammaClient::new(server.url()).unwrap();
+        let markets = client
+            .get_markets(Some(GammaMarketParams::new().with_limit(1)))
+            .unwrap();
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].question, "Will it rain?");
+        assert_eq!(server.requests()[0].path, "/markets?limit=1");
+    }
+
+    #[test]
+    fn test_blocking_data_client() {
+        let (_runtime, server) = mock_server(vec![(200, "[]".to_string())]);
+
+        let client = client::DataClient::new(server.url()).blocking().unwrap();
+        let user = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+        assert!(client.get_closed_positions(user).unwrap().is_empty());
+        assert_eq!(
+            server.requests()[0].path,
+            format!("/closed-positions?user={}", user)
+        );
+    }
+}
@@ -1,7 +1,13 @@
+use super::pagination::fetch_all_pages;
+use crate::config::HttpClientConfig;
 use crate::error::{Error, Result};
 use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
+use crate::request::QueryBuilder;
 use crate::signing::EthSigner;
-use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
+use crate::types::{
+    ApiCreds, ApiKeysResponse, BalanceAllowance, BalanceAllowanceParams, OpenOrder,
+    OpenOrderParams, OpenOrdersResponse,
+};
 use alloy_primitives::{Address, U256};
 
 /// Client for authenticated operations
@@ -51,6 +57,26 @@ impl AuthenticatedClient {
         }
     }
 
+    /// Create a new AuthenticatedClient with custom HTTP timeouts
+    ///
+    /// Same as [`new`](Self::new), with an additional `config` for the underlying HTTP client.
+    pub fn with_config(
+        host: impl Into<String>,
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+        api_creds: Option<ApiCreds>,
+        funder: Option<Address>,
+        config: HttpClientConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: HttpClient::with_config(host, config)?,
+            signer: Box::new(signer),
+            chain_id,
+            api_creds,
+            funder,
+        })
+    }
+
     /// Get the API credentials if available
     ///
     /// Returns a reference to the API credentials if they were provided when creating
@@ -132,7 +158,7 @@ impl AuthenticatedClient {
     /// This creates a new API key for the signer's address.
     /// Requires wallet signature.
     pub async fn create_api_key(&self, nonce: Option<U256>) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, nonce)?;
+        let headers = create_l1_headers(&*self.signer, self.chain_id, nonce).await?;
         self.http_client
             .post("/auth/api-key", &serde_json::json!({}), Some(headers))
             .await
@@ -140,7 +166,7 @@ impl AuthenticatedClient {
 
     /// Derive API key from existing credentials (L1 authentication required)
     pub async fn derive_api_key(&self) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, None)?;
+        let headers = create_l1_headers(&*self.signer, self.chain_id, None).await?;
         self.http_client
             .get("/auth/derive-api-key", Some(headers))
             .await
@@ -184,57 +210,95 @@ impl AuthenticatedClient {
 
     /// Get balance and allowance information (L2 authentication required)
     ///
+    /// Use `AssetType::Collateral` for the USDC balance and the allowances
+    /// granted to the exchange contracts, or `AssetType::Conditional` with a
+    /// `token_id` for an outcome token.
+    ///
     /// # Arguments
     /// * `params` - Query parameters for balance/allowance
     pub async fn get_balance_allowance(
         &self,
         params: BalanceAllowanceParams,
+    ) -> Result<BalanceAllowance> {
+        self.balance_allowance_request("/balance-allowance", &params)
+            .await
+    }
+
+    /// Ask the server to refresh its cached balance and allowance (L2 authentication required)
+    ///
+    /// Call this after changing balances or approvals on chain, before reading
+    /// them with [`get_balance_allowance`](Self::get_balance_allowance).
+    ///
+    /// # Arguments
+    /// * `params` - The asset to refresh, as for `get_balance_allowance`
+    pub async fn update_balance_allowance(
+        &self,
+        params: BalanceAllowanceParams,
     ) -> Result<serde_json::Value> {
+        self.balance_allowance_request("/balance-allowance/update", &params)
+            .await
+    }
+
+    async fn balance_allowance_request<T: serde::de::DeserializeOwned>(
+        &self,
+        base_path: &str,
+        params: &BalanceAllowanceParams,
+    ) -> Result<T> {
         let api_creds = self
             .api_creds
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         // IMPORTANT: Sign the base path WITHOUT query parameters
-        let base_path = "/balance-allowance";
         let headers = create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let query = QueryBuilder::new().push_all(params.to_query_params());
+        let request_path = format!("{}{}", base_path, query.build());
 
         self.http_client.get(&request_path, Some(headers)).await
     }
 
-    /// Update balance allowance (L2 authentication required)
-    pub async fn update_balance_allowance(&self) -> Result<serde_json::Value> {
+    /// Get one page of open orders (L2 authentication required)
+    ///
+    /// Pass the returned `next_cursor` back through `params` to fetch the next
+    /// page; the last page returns [`END_CURSOR`](crate::request::END_CURSOR).
+    ///
+    /// # Arguments
+    /// * `params` - Filter by order ID, market or asset ID
+    pub async fn get_open_orders_page(
+        &self,
+        params: OpenOrderParams,
+    ) -> Result<OpenOrdersResponse> {
         let api_creds = self
             .api_creds
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers = create_l2_headers::<_, ()>(
-            &self.signer,
-            api_creds,
-            "GET",
-            "/balance-allowance/update",
-            None,
-        )?;
-        self.http_client
-            .get("/balance-allowance/update", Some(headers))
-            .await
+        // IMPORTANT: Sign the base path WITHOUT query parameters
+        let base_path = "/data/orders";
+        let headers = create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None)?;
+
+        // Build the full request path WITH query parameters
+        let query = QueryBuilder::new().push_all(params.to_query_params());
+        let request_path = format!("{}{}", base_path, query.build());
+
+        self.http_client.get(&request_path, Some(headers)).await
+    }
+
+    /// Get all open orders by following `next_cursor` (L2 authentication required)
+    ///
+    /// Useful to reconcile local order state, e.g. after a reconnect.
+    ///
+    /// # Arguments
+    /// * `params` - Filter by order ID, market or asset ID
+    pub async fn get_open_orders(&self, params: OpenOrderParams) -> Result<Vec<OpenOrder>> {
+        fetch_all_pages(params.next_cursor.clone(), |cursor| {
+            let mut params = params.clone();
+            params.next_cursor = cursor;
+            self.get_open_orders_page(params)
+        })
+        .await
     }
 
     /// Get notifications for the current user (L2 authentication required)
@@ -282,3 +346,147 @@ impl AuthenticatedClient {
         self.funder
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::END_CURSOR;
+    use crate::test_utils::MockServer;
+    use crate::types::{AssetType, Side};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    fn authenticated_client(host: String) -> AuthenticatedClient {
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "c2VjcmV0c2VjcmV0c2VjcmV0".to_string(),
+            "pass".to_string(),
+        );
+        AuthenticatedClient::new(host, PrivateKeySigner::random(), 137, Some(creds), None)
+    }
+
+    fn order_json(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "status": "LIVE",
+            "owner": "f4f247b7-4ac7-ff29-a152-04fda0a8755a",
+            "maker_address": "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "side": "BUY",
+            "original_size": "100",
+            "size_matched": "25.5",
+            "price": "0.57",
+            "associate_trades": ["d7f8a5b0-0000-0000-0000-000000000000"],
+            "outcome": "Yes",
+            "created_at": 1700000000,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+
+    fn orders_page(ids: &[&str], next_cursor: &str) -> (u16, String) {
+        let data: Vec<_> = ids.iter().map(|id| order_json(id)).collect();
+        let page = serde_json::json!({
+            "limit": 100,
+            "count": data.len(),
+            "next_cursor": next_cursor,
+            "data": data,
+        });
+        (200, page.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_open_orders_parses_and_follows_cursor() {
+        let server = MockServer::start(vec![
+            orders_page(&["0x1", "0x2"], "MTAw"),
+            orders_page(&["0x3"], END_CURSOR),
+        ])
+        .await;
+        let client = authenticated_client(server.url());
+
+        let orders = client
+            .get_open_orders(OpenOrderParams::new().market("0xabc"))
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = orders.iter().map(|order| order.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x1", "0x2", "0x3"]);
+
+        let order = &orders[0];
+        assert_eq!(order.status, "LIVE");
+        assert_eq!(order.side, Side::Buy);
+        assert_eq!(order.price, dec!(0.57));
+        assert_eq!(order.original_size, dec!(100));
+        assert_eq!(order.size_matched, dec!(25.5));
+        assert_eq!(order.created_at, 1700000000);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/data/orders?market=0xabc");
+        assert_eq!(
+            requests[1].path,
+            "/data/orders?market=0xabc&next_cursor=MTAw"
+        );
+        assert_eq!(requests[0].header("POLY_API_KEY"), Some("key"));
+    }
+
+    #[tokio::test]
+    async fn test_balance_allowance_requests() {
+        let server = MockServer::start(vec![
+            (200, r#"{"balance":"1500000","allowances":{}}"#.to_string()),
+            (200, r#"{"balance":"0","allowances":{}}"#.to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+        let client = authenticated_client(server.url());
+
+        let collateral = client
+            .get_balance_allowance(
+                BalanceAllowanceParams::new()
+                    .asset_type(AssetType::Collateral)
+                    .signature_type(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(collateral.balance, "1500000");
+
+        let conditional = BalanceAllowanceParams::new()
+            .asset_type(AssetType::Conditional)
+            .token_id("123");
+        client
+            .get_balance_allowance(conditional.clone())
+            .await
+            .unwrap();
+        client.update_balance_allowance(conditional).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/balance-allowance?asset_type=COLLATERAL&signature_type=2"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/balance-allowance?asset_type=CONDITIONAL&token_id=123"
+        );
+        assert_eq!(
+            requests[2].path,
+            "/balance-allowance/update?asset_type=CONDITIONAL&token_id=123"
+        );
+        assert!(requests[2].header("POLY_SIGNATURE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_open_orders_requires_creds() {
+        let client = AuthenticatedClient::new(
+            "http://localhost",
+            PrivateKeySigner::random(),
+            137,
+            None,
+            None,
+        );
+        assert!(matches!(
+            client.get_open_orders(OpenOrderParams::new()).await,
+            Err(Error::AuthRequired(_))
+        ));
+    }
+}
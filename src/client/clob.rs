@@ -1,12 +1,18 @@
-use crate::error::Result;
+use super::pagination::fetch_all_pages;
+use crate::config::{HttpClientConfig, RetryPolicy};
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
-use crate::request::PaginationParams;
+use crate::request::{PaginationParams, PriceHistoryParams};
 use crate::types::{
-    BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
-    SpreadResponse, TickSizeResponse, TokenId,
+    BookParams, ConditionId, ConditionToken, CreateOrderOptions, Market, MarketsResponse,
+    MidpointResponse, NegRiskResponse, OrderBookSummary, PriceHistoryResponse, PricePoint,
+    PricePointsResponse, PriceResponse, RewardConfig, RewardConfigsResponse, SimplifiedMarket,
+    SimplifiedMarketsResponse, SpreadResponse, TickSizeResponse, TokenDecimals, TokenId,
 };
 use crate::Side;
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 
 /// Client for CLOB (Central Limit Order Book) market data APIs
 ///
@@ -14,6 +20,8 @@ use crate::Side;
 /// without requiring authentication.
 pub struct ClobClient {
     http_client: HttpClient,
+    market_params: Mutex<HashMap<String, CreateOrderOptions>>,
+    condition_tokens: Mutex<HashMap<String, Vec<ConditionToken>>>,
 }
 
 impl ClobClient {
@@ -24,9 +32,40 @@ impl ClobClient {
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
+            market_params: Mutex::new(HashMap::new()),
+            condition_tokens: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Create a new ClobClient with custom HTTP timeouts
+    ///
+    /// # Arguments
+    /// * `host` - The base URL for the API
+    /// * `config` - HTTP client configuration
+    pub fn with_config(host: impl Into<String>, config: HttpClientConfig) -> Result<Self> {
+        Ok(Self {
+            http_client: HttpClient::with_config(host, config)?,
+            market_params: Mutex::new(HashMap::new()),
+            condition_tokens: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Retry failed GET requests with exponential backoff
+    ///
+    /// Requests are retried on 5xx responses and connection errors, never on 4xx.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry(policy);
+        self
+    }
+
+    /// Limit outgoing requests to `requests_per_second`
+    ///
+    /// Requests wait instead of failing with a 429. A non-positive rate disables limiting.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.http_client = self.http_client.with_rate_limit(requests_per_second);
+        self
+    }
+
     /// Check if the server is responsive
     pub async fn get_ok(&self) -> Result<serde_json::Value> {
         self.http_client.get("/", None).await
@@ -46,15 +85,15 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get midpoint prices for multiple tokens
+    /// Get midpoint prices for multiple tokens in one request
+    ///
+    /// Returns the midpoint of each token by token ID. Tokens without an
+    /// active order book are left out of the map.
     ///
     /// # Arguments
     /// * `token_ids` - List of token IDs to query
-    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<Vec<MidpointResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/midpoints", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<HashMap<String, Decimal>> {
+        self.post_token_ids("/midpoints", token_ids).await
     }
 
     /// Get the current price for a token
@@ -104,18 +143,61 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get the price history of a token as timestamped points
+    ///
+    /// Unlike [`get_prices_history`](Self::get_prices_history), unset
+    /// parameters are left to the CLOB's defaults.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::client::ClobClient;
+    /// use polymarket_rs::request::{PriceHistoryInterval, PriceHistoryParams};
+    /// use polymarket_rs::types::TokenId;
+    ///
+    /// # async fn example() -> polymarket_rs::Result<()> {
+    /// let client = ClobClient::new("https://clob.polymarket.com");
+    /// let params = PriceHistoryParams::new(TokenId::new("123"))
+    ///     .with_interval(PriceHistoryInterval::OneDay)
+    ///     .with_fidelity(60);
+    /// for point in client.get_price_history(&params).await? {
+    ///     println!("{} {}", point.timestamp, point.price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_price_history(&self, params: &PriceHistoryParams) -> Result<Vec<PricePoint>> {
+        let path = format!("/prices-history{}", params.to_query_string());
+        let response: PricePointsResponse = self.http_client.get(&path, None).await?;
+        Ok(response.history)
+    }
+
     /// Get the bid/ask spread for a token
     pub async fn get_spread(&self, token_id: &TokenId) -> Result<SpreadResponse> {
         let path = format!("/spread?token_id={}", token_id.as_str());
         self.http_client.get(&path, None).await
     }
 
-    /// Get spreads for multiple tokens
-    pub async fn get_spreads(&self, token_ids: &[TokenId]) -> Result<Vec<SpreadResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/spreads", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    /// Get bid/ask spreads for multiple tokens in one request
+    ///
+    /// Returns the spread of each token by token ID. Tokens without an
+    /// active order book are left out of the map.
+    pub async fn get_spreads(&self, token_ids: &[TokenId]) -> Result<HashMap<String, Decimal>> {
+        self.post_token_ids("/spreads", token_ids).await
+    }
+
+    /// POST `[{"token_id": ...}]` to a batch endpoint answering with a map
+    async fn post_token_ids(
+        &self,
+        path: &str,
+        token_ids: &[TokenId],
+    ) -> Result<HashMap<String, Decimal>> {
+        let body: Vec<_> = token_ids
+            .iter()
+            .map(|id| serde_json::json!({ "token_id": id.as_str() }))
+            .collect();
+        let response: TokenDecimals = self.http_client.post(path, &body, None).await?;
+        Ok(response.0)
     }
 
     /// Get the minimum tick size for a token
@@ -124,12 +206,44 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get whether a market uses negative risk
-    pub async fn get_neg_risk(&self, condition_id: &ConditionId) -> Result<NegRiskResponse> {
-        let path = format!("/neg-risk?condition_id={}", condition_id.as_str());
+    /// Get whether a token's market uses negative risk
+    pub async fn get_neg_risk(&self, token_id: &TokenId) -> Result<NegRiskResponse> {
+        let path = format!("/neg-risk?token_id={}", token_id.as_str());
         self.http_client.get(&path, None).await
     }
 
+    /// Get the tick size and neg risk flag needed to create orders for a token
+    ///
+    /// Results are cached per token, since they rarely change.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    pub async fn get_market_params(&self, token_id: &TokenId) -> Result<CreateOrderOptions> {
+        if let Some(options) = self.cached_market_params(token_id) {
+            return Ok(options);
+        }
+
+        let tick_size = self.get_tick_size(token_id).await?;
+        let neg_risk = self.get_neg_risk(token_id).await?;
+        let options = CreateOrderOptions::new()
+            .tick_size(tick_size.minimum_tick_size)
+            .neg_risk(neg_risk.neg_risk);
+
+        self.market_params
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(token_id.as_str().to_string(), options.clone());
+        Ok(options)
+    }
+
+    fn cached_market_params(&self, token_id: &TokenId) -> Option<CreateOrderOptions> {
+        self.market_params
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(token_id.as_str())
+            .cloned()
+    }
+
     /// Get the order book for a token
     ///
     /// # Arguments
@@ -200,6 +314,17 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get all sampling simplified markets, following the cursor until the last page
+    ///
+    /// Sampling markets are the active markets currently eligible for rewards,
+    /// which makes this a light way to list what is tradeable right now.
+    pub async fn get_all_sampling_simplified_markets(&self) -> Result<Vec<SimplifiedMarket>> {
+        fetch_all_pages(None, |cursor| {
+            self.get_sampling_simplified_markets(cursor.map(PaginationParams::with_cursor))
+        })
+        .await
+    }
+
     /// Get markets with pagination
     ///
     /// # Arguments
@@ -219,6 +344,50 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get all markets, following the cursor until the last page
+    pub async fn get_all_markets(&self) -> Result<Vec<Market>> {
+        fetch_all_pages(None, |cursor| {
+            self.get_markets(cursor.map(PaginationParams::with_cursor))
+        })
+        .await
+    }
+
+    /// Get the markets trading the given tokens
+    ///
+    /// Each token is resolved to its condition like
+    /// [`get_condition_for_token`](Self::get_condition_for_token), and each
+    /// market is then fetched once by condition ID. The market's tokens are
+    /// cached, so later lookups of any of them skip the order book request.
+    /// Markets are returned in the order of their first token; tokens without
+    /// an order book (e.g. of resolved markets) are left out.
+    pub async fn get_markets_by_token_ids(&self, token_ids: &[TokenId]) -> Result<Vec<Market>> {
+        let mut seen = HashSet::new();
+        let mut markets = Vec::new();
+        for token_id in token_ids {
+            let condition_id = match self.cached_condition_token(token_id) {
+                Some(token) => token.condition_id,
+                None => match self.get_order_book(token_id).await {
+                    Ok(book) => book.market,
+                    Err(Error::Api(e)) if e.status == 404 => continue,
+                    Err(e) => return Err(e),
+                },
+            };
+            if !seen.insert(condition_id.clone()) {
+                continue;
+            }
+
+            let market = self
+                .get_market(&ConditionId::new(condition_id.as_str()))
+                .await?;
+            self.condition_tokens
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(condition_id, market.condition_tokens());
+            markets.push(market);
+        }
+        Ok(markets)
+    }
+
     /// Get simplified markets with pagination
     pub async fn get_simplified_markets(
         &self,
@@ -235,18 +404,106 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get all simplified markets, following the cursor until the last page
+    pub async fn get_all_simplified_markets(&self) -> Result<Vec<SimplifiedMarket>> {
+        fetch_all_pages(None, |cursor| {
+            self.get_simplified_markets(cursor.map(PaginationParams::with_cursor))
+        })
+        .await
+    }
+
     /// Get a specific market by condition ID
     pub async fn get_market(&self, condition_id: &ConditionId) -> Result<Market> {
         let path = format!("/markets/{}", condition_id.as_str());
         self.http_client.get(&path, None).await
     }
 
+    /// Get the tokens of a condition with their outcomes and outcome indices
+    ///
+    /// Results are cached per condition, since a market's tokens never change.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    pub async fn get_tokens_for_condition(
+        &self,
+        condition_id: &ConditionId,
+    ) -> Result<Vec<ConditionToken>> {
+        if let Some(tokens) = self
+            .condition_tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(condition_id.as_str())
+        {
+            return Ok(tokens.clone());
+        }
+
+        let tokens = self.get_market(condition_id).await?.condition_tokens();
+        self.condition_tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(condition_id.as_str().to_string(), tokens.clone());
+        Ok(tokens)
+    }
+
+    /// Get the condition a token belongs to, with the token's outcome and outcome index
+    ///
+    /// Uses the tokens cached by [`get_tokens_for_condition`](Self::get_tokens_for_condition)
+    /// when possible; otherwise the condition is looked up from the token's order
+    /// book and its tokens are cached.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to resolve
+    pub async fn get_condition_for_token(&self, token_id: &TokenId) -> Result<ConditionToken> {
+        if let Some(token) = self.cached_condition_token(token_id) {
+            return Ok(token);
+        }
+
+        let book = self.get_order_book(token_id).await?;
+        self.get_tokens_for_condition(&ConditionId::new(book.market.as_str()))
+            .await?
+            .into_iter()
+            .find(|token| token.token_id == token_id.as_str())
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!(
+                    "Token {} not found in market {}",
+                    token_id, book.market
+                ))
+            })
+    }
+
+    fn cached_condition_token(&self, token_id: &TokenId) -> Option<ConditionToken> {
+        self.condition_tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .flatten()
+            .find(|token| token.token_id == token_id.as_str())
+            .cloned()
+    }
+
     /// Get a specific market by slug
     pub async fn get_market_by_slug(&self, market_slug: &str) -> Result<Market> {
         let path = format!("/markets/slug/{}", market_slug);
         self.http_client.get(&path, None).await
     }
 
+    /// Get the liquidity rewards configuration of a market
+    ///
+    /// Returns [`Error::InvalidParameter`] if the market has no active rewards.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    pub async fn get_rewards(&self, condition_id: &ConditionId) -> Result<RewardConfig> {
+        let path = format!("/rewards/markets/{}", condition_id.as_str());
+        let page: RewardConfigsResponse = self.http_client.get(&path, None).await?;
+        page.data.into_iter().next().ok_or_else(|| {
+            Error::InvalidParameter(format!(
+                "No rewards configured for market {}",
+                condition_id.as_str()
+            ))
+        })
+    }
+
     /// Get live activity events for a market (trades and events)
     ///
     /// # Arguments
@@ -259,3 +516,471 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{PriceHistoryInterval, END_CURSOR};
+    use crate::test_utils::MockServer;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    const TOKEN_ID: &str =
+        "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+    fn book_json(asset_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+            "asset_id": asset_id,
+            "timestamp": "1727379862357",
+            "hash": "0x1b4ba4f4a0ddb28b3e8e25d2e4c1b38a8de4bcb2",
+            "bids": [
+                { "price": "0.48", "size": "30" },
+                { "price": "0.49", "size": "20" }
+            ],
+            "asks": [
+                { "price": "0.52", "size": "25" },
+                { "price": "0.51", "size": "10.5" }
+            ],
+            "min_order_size": "5",
+            "tick_size": "0.01",
+            "neg_risk": false
+        })
+    }
+
+    fn market_json(condition_id: &str, token_ids: [&str; 2]) -> serde_json::Value {
+        serde_json::json!({
+            "condition_id": condition_id,
+            "tokens": [
+                { "token_id": token_ids[0], "outcome": "Yes", "price": 0.345, "winner": false },
+                { "token_id": token_ids[1], "outcome": "No", "price": 0.655, "winner": false }
+            ],
+            "rewards": { "rates": null, "min_size": 0, "max_spread": 0 },
+            "min_incentive_size": null,
+            "max_incentive_spread": null,
+            "active": true,
+            "closed": false,
+            "enable_order_book": true,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": "2024-12-29T22:38:10Z",
+            "question_id": "0x01",
+            "question": "Will it happen?",
+            "minimum_order_size": 5,
+            "minimum_tick_size": 0.01,
+            "description": "",
+            "category": null,
+            "end_date_iso": null,
+            "game_start_time": null,
+            "market_slug": condition_id,
+            "icon": "",
+            "fpmm": "",
+            "neg_risk": true,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": ""
+        })
+    }
+
+    fn markets_page(markets: Vec<serde_json::Value>, next_cursor: &str) -> (u16, String) {
+        let page = serde_json::json!({
+            "limit": 2,
+            "count": markets.len(),
+            "next_cursor": next_cursor,
+            "data": markets
+        });
+        (200, page.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_all_markets_follows_cursor() {
+        let server = MockServer::start(vec![
+            markets_page(vec![market_json("0xa", ["1", "2"])], "MQ=="),
+            markets_page(vec![market_json("0xb", ["3", "4"])], END_CURSOR),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+
+        let markets = client.get_all_markets().await.unwrap();
+        assert_eq!(markets.len(), 2);
+        assert_eq!(markets[1].condition_id, "0xb");
+        assert!(markets[0].neg_risk);
+        assert_eq!(markets[0].minimum_order_size, dec!(5));
+        assert_eq!(markets[0].minimum_tick_size, dec!(0.01));
+        assert_eq!(markets[0].token("2").unwrap().price, Some(dec!(0.655)));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/markets");
+        assert_eq!(requests[1].path, "/markets?next_cursor=MQ==");
+    }
+
+    #[tokio::test]
+    async fn test_get_rewards() {
+        let rewards_page = |data: Vec<serde_json::Value>| {
+            let page = serde_json::json!({
+                "limit": 100,
+                "count": data.len(),
+                "next_cursor": END_CURSOR,
+                "data": data,
+            });
+            (200, page.to_string())
+        };
+        let server = MockServer::start(vec![
+            rewards_page(vec![serde_json::json!({
+                "condition_id": "0xa",
+                "rewards_max_spread": 3,
+                "rewards_min_size": 100,
+                "rewards_config": [{
+                    "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                    "start_date": "2024-10-01",
+                    "end_date": "2500-12-31",
+                    "rate_per_day": 12.5,
+                    "total_rewards": 0
+                }]
+            })]),
+            rewards_page(vec![]),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+
+        let config = client.get_rewards(&ConditionId::new("0xa")).await.unwrap();
+        assert_eq!(config.rewards_min_size, dec!(100));
+        assert_eq!(config.rewards_max_spread, dec!(3));
+        assert_eq!(config.daily_rate(), dec!(12.5));
+        assert_eq!(server.requests()[0].path, "/rewards/markets/0xa");
+
+        let err = client
+            .get_rewards(&ConditionId::new("0xb"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_markets_by_token_ids_queries_by_token() {
+        let book = |asset_id: &str, condition_id: &str| {
+            let mut book = book_json(asset_id);
+            book["market"] = condition_id.into();
+            (200, book.to_string())
+        };
+        let server = MockServer::start(vec![
+            book("2", "0xa"),
+            (200, market_json("0xa", ["1", "2"]).to_string()),
+            book("5", "0xc"),
+            (200, market_json("0xc", ["5", "6"]).to_string()),
+            (
+                404,
+                r#"{"error":"No orderbook exists for the requested token id"}"#.to_string(),
+            ),
+            (200, market_json("0xa", ["1", "2"]).to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+
+        let token_ids = [
+            TokenId::new("2"),
+            TokenId::new("1"),
+            TokenId::new("5"),
+            TokenId::new("9"),
+        ];
+        let markets = client.get_markets_by_token_ids(&token_ids).await.unwrap();
+        let ids: Vec<_> = markets.iter().map(|m| m.condition_id.as_str()).collect();
+        assert_eq!(ids, ["0xa", "0xc"]);
+
+        // Token 1 was cached with market 0xa, token 9 has no order book
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/book?token_id=2",
+                "/markets/0xa",
+                "/book?token_id=5",
+                "/markets/0xc",
+                "/book?token_id=9",
+            ]
+        );
+
+        // Cached tokens go straight to their market
+        let markets = client
+            .get_markets_by_token_ids(&[TokenId::new("1")])
+            .await
+            .unwrap();
+        assert_eq!(markets[0].condition_id, "0xa");
+        assert_eq!(server.requests().last().unwrap().path, "/markets/0xa");
+        assert_eq!(server.requests().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_simplified_markets() {
+        let simplified = |condition_id: &str| {
+            let mut market = market_json(condition_id, ["1", "2"]);
+            let full = market.as_object_mut().unwrap();
+            full.retain(|key, _| {
+                matches!(
+                    key.as_str(),
+                    "condition_id"
+                        | "tokens"
+                        | "rewards"
+                        | "active"
+                        | "closed"
+                        | "archived"
+                        | "accepting_orders"
+                )
+            });
+            market
+        };
+        let server = MockServer::start(vec![
+            markets_page(vec![simplified("0xa")], "MQ=="),
+            markets_page(vec![simplified("0xb")], END_CURSOR),
+            markets_page(vec![simplified("0xc")], ""),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+
+        let markets = client.get_all_simplified_markets().await.unwrap();
+        assert_eq!(markets.len(), 2);
+        assert!(markets[0].active);
+        assert_eq!(markets[0].tokens[0].price, Some(dec!(0.345)));
+
+        let sampling = client.get_all_sampling_simplified_markets().await.unwrap();
+        assert_eq!(sampling[0].condition_id, "0xc");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/simplified-markets");
+        assert_eq!(requests[1].path, "/simplified-markets?next_cursor=MQ==");
+        assert_eq!(requests[2].path, "/sampling-simplified-markets");
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book() {
+        let server = MockServer::start(vec![(200, book_json(TOKEN_ID).to_string())]).await;
+        let client = ClobClient::new(server.url());
+
+        let book = client
+            .get_order_book(&TokenId::new(TOKEN_ID))
+            .await
+            .unwrap();
+
+        assert_eq!(book.asset_id, TOKEN_ID);
+        assert_eq!(book.timestamp, 1727379862357);
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.sort_bids()[0].price, dec!(0.49));
+        assert_eq!(book.sort_asks()[0].price, dec!(0.51));
+        assert_eq!(book.sort_asks()[0].size, dec!(10.5));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, format!("/book?token_id={}", TOKEN_ID));
+    }
+
+    #[tokio::test]
+    async fn test_get_order_books() {
+        let response = serde_json::json!([book_json("1"), book_json("2")]).to_string();
+        let server = MockServer::start(vec![(200, response)]).await;
+        let client = ClobClient::new(server.url());
+
+        let params = [
+            BookParams::new("1", Side::Buy),
+            BookParams::new("2", Side::Sell),
+        ];
+        let books = client.get_order_books(&params).await.unwrap();
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].asset_id, "1");
+        assert_eq!(books[1].asset_id, "2");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/books");
+        assert_eq!(
+            request.body,
+            r#"[{"token_id":"1","side":"BUY"},{"token_id":"2","side":"SELL"}]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoints_and_spreads() {
+        let server = MockServer::start(vec![
+            (200, r#"{"1":"0.455","2":"0.545","3":null}"#.to_string()),
+            (200, r#"{"1":"0.01","2":0.02}"#.to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+        let token_ids = [
+            TokenId::new("1"),
+            TokenId::new("2"),
+            TokenId::new("3"),
+            TokenId::new("4"),
+        ];
+
+        let midpoints = client.get_midpoints(&token_ids).await.unwrap();
+        assert_eq!(midpoints.len(), 2);
+        assert_eq!(midpoints["1"], dec!(0.455));
+        assert_eq!(midpoints["2"], dec!(0.545));
+        // Tokens without a book are missing or null
+        assert!(!midpoints.contains_key("3"));
+        assert!(!midpoints.contains_key("4"));
+
+        let spreads = client.get_spreads(&token_ids[..2]).await.unwrap();
+        assert_eq!(spreads.len(), 2);
+        assert_eq!(spreads["2"], dec!(0.02));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/midpoints");
+        assert_eq!(
+            requests[0].body,
+            r#"[{"token_id":"1"},{"token_id":"2"},{"token_id":"3"},{"token_id":"4"}]"#
+        );
+        assert_eq!(requests[1].path, "/spreads");
+        assert_eq!(requests[1].body, r#"[{"token_id":"1"},{"token_id":"2"}]"#);
+    }
+
+    #[tokio::test]
+    async fn test_get_market_params_cached() {
+        let server = MockServer::start(vec![
+            (200, r#"{"minimum_tick_size":0.001}"#.to_string()),
+            (200, r#"{"neg_risk":true}"#.to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+        let token_id = TokenId::new(TOKEN_ID);
+
+        let options = client.get_market_params(&token_id).await.unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.001)));
+        assert_eq!(options.neg_risk, Some(true));
+
+        // Served from the cache: the mock server would answer a third request with a 500
+        let cached = client.get_market_params(&token_id).await.unwrap();
+        assert_eq!(cached.tick_size, Some(dec!(0.001)));
+        assert_eq!(cached.neg_risk, Some(true));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].path,
+            format!("/tick-size?token_id={}", TOKEN_ID)
+        );
+        assert_eq!(requests[1].path, format!("/neg-risk?token_id={}", TOKEN_ID));
+    }
+
+    #[tokio::test]
+    async fn test_condition_token_resolution() {
+        let condition_id = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+        let server = MockServer::start(vec![
+            (200, book_json(TOKEN_ID).to_string()),
+            (200, market_json(condition_id, [TOKEN_ID, "42"]).to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+
+        let yes = client
+            .get_condition_for_token(&TokenId::new(TOKEN_ID))
+            .await
+            .unwrap();
+        assert_eq!(yes.condition_id, condition_id);
+        assert_eq!(yes.outcome, "Yes");
+        assert_eq!(yes.outcome_index, 0);
+
+        // Both directions are now served from the cache
+        let no = client
+            .get_condition_for_token(&TokenId::new("42"))
+            .await
+            .unwrap();
+        assert_eq!(no.outcome, "No");
+        assert_eq!(no.outcome_index, 1);
+        let tokens = client
+            .get_tokens_for_condition(&ConditionId::new(condition_id))
+            .await
+            .unwrap();
+        assert_eq!(tokens, vec![yes, no]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, format!("/book?token_id={}", TOKEN_ID));
+        assert_eq!(requests[1].path, format!("/markets/{}", condition_id));
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoint_and_price() {
+        let server = MockServer::start(vec![
+            (200, r#"{"mid":"0.505"}"#.to_string()),
+            (200, r#"{"price":"0.51"}"#.to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+        let token_id = TokenId::new(TOKEN_ID);
+
+        let midpoint = client.get_midpoint(&token_id).await.unwrap();
+        assert_eq!(midpoint.mid, dec!(0.505));
+
+        let price = client.get_price(&token_id, Side::Buy).await.unwrap();
+        assert_eq!(price.price, dec!(0.51));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, format!("/midpoint?token_id={}", TOKEN_ID));
+        assert_eq!(
+            requests[1].path,
+            format!("/price?token_id={}&side=BUY", TOKEN_ID)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_price_history() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                r#"{"history":[{"t":1700000000,"p":0.42},{"t":1700003600,"p":"0.45"}]}"#
+                    .to_string(),
+            ),
+            (200, r#"{"history":[]}"#.to_string()),
+        ])
+        .await;
+        let client = ClobClient::new(server.url());
+        let token_id = TokenId::new(TOKEN_ID);
+
+        let params = PriceHistoryParams::new(token_id.clone())
+            .with_interval(PriceHistoryInterval::OneDay)
+            .with_fidelity(60);
+        let history = client.get_price_history(&params).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[0].timestamp,
+            Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+        );
+        assert_eq!(history[0].price, dec!(0.42));
+        assert_eq!(history[1].price, dec!(0.45));
+
+        let params = PriceHistoryParams::new(token_id).with_interval(PriceHistoryInterval::Max);
+        assert!(client.get_price_history(&params).await.unwrap().is_empty());
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            format!(
+                "/prices-history?market={}&interval=1d&fidelity=60",
+                TOKEN_ID
+            )
+        );
+        assert_eq!(
+            requests[1].path,
+            format!("/prices-history?market={}&interval=max", TOKEN_ID)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_last_trade_price() {
+        let server =
+            MockServer::start(vec![(200, r#"{"price":"0.57","side":"BUY"}"#.to_string())]).await;
+        let client = ClobClient::new(server.url());
+
+        let price = client
+            .get_last_trade_price(&TokenId::new(TOKEN_ID))
+            .await
+            .unwrap();
+        assert_eq!(price.price, dec!(0.57));
+        assert_eq!(
+            server.requests()[0].path,
+            format!("/last-trade-price?token_id={}", TOKEN_ID)
+        );
+    }
+}
@@ -1,14 +1,25 @@
-use crate::error::Result;
-use crate::http::HttpClient;
-use crate::request::{ActivityQueryParams, TradeQueryParams};
-use crate::types::{Activity, ClosedPosition, Position, PositionValue, Trade};
+use crate::config::{HttpClientConfig, RequestOptions, RetryPolicy};
+use crate::error::{Error, Result};
+use crate::http::{HttpClient, HttpTransport};
+use crate::request::{ActivityQueryParams, PositionQueryParams, QueryBuilder, TradeQueryParams};
+use crate::types::{
+    normalize_address, Activity, ClosedPosition, PortfolioSummary, Position, PositionValue, Trade,
+};
+use futures_util::{stream, Stream, TryStreamExt};
+
+/// Page size used by [`DataClient::positions_stream`] when the params don't set a limit
+const DEFAULT_PAGE_SIZE: u32 = 100;
 
 /// Client for accessing position and portfolio data
 ///
 /// This client provides access to user positions and portfolio values.
 /// It does not require authentication.
-pub struct DataClient {
-    http_client: HttpClient,
+///
+/// User addresses are sent in lowercase (see
+/// [`normalize_address`](crate::types::normalize_address)); a malformed
+/// address fails with `Error::InvalidParameter` before any request is made.
+pub struct DataClient<T = HttpClient> {
+    http_client: T,
 }
 
 impl DataClient {
@@ -22,16 +33,148 @@ impl DataClient {
         }
     }
 
-    /// Get all positions for a user
+    /// Create a new DataClient with custom HTTP timeouts
+    ///
+    /// # Arguments
+    /// * `host` - The base URL for the API
+    /// * `config` - HTTP client configuration
+    pub fn with_config(host: impl Into<String>, config: HttpClientConfig) -> Result<Self> {
+        Ok(Self {
+            http_client: HttpClient::with_config(host, config)?,
+        })
+    }
+
+    /// Retry failed GET requests with exponential backoff
+    ///
+    /// Requests are retried on 5xx responses and connection errors, never on 4xx.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry(policy);
+        self
+    }
+
+    /// Limit outgoing requests to `requests_per_second`
+    ///
+    /// Requests wait instead of failing with a 429. A non-positive rate disables limiting.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.http_client = self.http_client.with_rate_limit(requests_per_second);
+        self
+    }
+}
+
+impl<T: HttpTransport> DataClient<T> {
+    /// Create a client that sends its requests through `transport`
+    ///
+    /// Useful to substitute a fake transport in tests.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            http_client: transport,
+        }
+    }
+
+    /// Get positions for a user
+    ///
+    /// Without a limit the API returns a single default-sized page; use
+    /// [`positions_stream`](Self::positions_stream) to fetch every position.
     ///
     /// # Arguments
     /// * `user` - The user's wallet address
+    /// * `params` - Optional query parameters (limit, offset, size_threshold, redeemable, sort_by, sort_direction)
     ///
     /// # Returns
     /// A list of positions owned by the user
-    pub async fn get_positions(&self, user: &str) -> Result<Vec<Position>> {
-        let path = format!("/positions?user={}", user);
-        self.http_client.get(&path, None).await
+    pub async fn get_positions(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+    ) -> Result<Vec<Position>> {
+        self.get_positions_with_options(user, params, &RequestOptions::default())
+            .await
+    }
+
+    /// Get positions for a user with per-request options
+    ///
+    /// Same as [`get_positions`](Self::get_positions), but e.g. with a tighter
+    /// timeout for this call only:
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::client::DataClient;
+    /// # use polymarket_rs::config::RequestOptions;
+    /// # use std::time::Duration;
+    /// # async fn example(client: &DataClient) -> polymarket_rs::Result<()> {
+    /// let options = RequestOptions::new().timeout(Duration::from_secs(2));
+    /// let positions = client
+    ///     .get_positions_with_options("0x56687bf447db6ffa42ffe2204a05edaa20f55839", None, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::Timeout` if the request does not complete within the
+    /// options' timeout.
+    pub async fn get_positions_with_options(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+        options: &RequestOptions,
+    ) -> Result<Vec<Position>> {
+        let query = QueryBuilder::new()
+            .push("user", normalize_address(user)?)
+            .append(params.map(|p| p.to_query()).unwrap_or_default());
+        let path = format!("/positions{}", query.build());
+
+        self.http_client
+            .get_with_options(&path, None, options)
+            .await
+    }
+
+    /// Stream all positions for a user, fetching pages on demand
+    ///
+    /// Starts at the params' offset (or 0) and advances it by the page size,
+    /// which is the params' limit or 100 if unset. The stream ends after the
+    /// first page shorter than the page size.
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    /// * `params` - Optional query parameters; filters and sorting apply to every page
+    pub fn positions_stream<'a>(
+        &'a self,
+        user: &'a str,
+        params: Option<PositionQueryParams>,
+    ) -> impl Stream<Item = Result<Position>> + 'a {
+        let params = params.unwrap_or_default();
+        let page_size = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let start = params.offset.unwrap_or(0);
+
+        stream::try_unfold(Some(start), move |offset| {
+            let params = params.clone();
+            async move {
+                let Some(offset) = offset else {
+                    return Ok::<_, Error>(None);
+                };
+
+                let page = self
+                    .get_positions(user, Some(params.with_limit(page_size).with_offset(offset)))
+                    .await?;
+                let next = (page.len() as u32 >= page_size).then_some(offset + page_size);
+
+                Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Fetch every position of a user and aggregate value and P&L
+    ///
+    /// Pages through all positions (see [`positions_stream`](Self::positions_stream))
+    /// and groups them by market. Redeemable winning positions count at their
+    /// current value; see [`PortfolioSummary`].
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    pub async fn get_portfolio_summary(&self, user: &str) -> Result<PortfolioSummary> {
+        let positions: Vec<Position> = self.positions_stream(user, None).try_collect().await?;
+        Ok(PortfolioSummary::from_positions(&positions))
     }
 
     /// Get the total value of positions for a user
@@ -42,7 +185,12 @@ impl DataClient {
     /// # Returns
     /// A list of position values for the user
     pub async fn get_positions_value(&self, user: &str) -> Result<Vec<PositionValue>> {
-        let path = format!("/value?user={}", user);
+        let path = format!(
+            "/value{}",
+            QueryBuilder::new()
+                .push("user", normalize_address(user)?)
+                .build()
+        );
         self.http_client.get(&path, None).await
     }
 
@@ -59,13 +207,10 @@ impl DataClient {
         user: &str,
         params: Option<TradeQueryParams>,
     ) -> Result<Vec<Trade>> {
-        let mut path = format!("/trades?user={}", user);
-
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
-        }
-
-        println!("{}", path);
+        let query = QueryBuilder::new()
+            .push("user", normalize_address(user)?)
+            .append(params.map(|p| p.to_query()).unwrap_or_default());
+        let path = format!("/trades{}", query.build());
 
         self.http_client.get(&path, None).await
     }
@@ -83,11 +228,10 @@ impl DataClient {
         user: &str,
         params: Option<ActivityQueryParams>,
     ) -> Result<Vec<Activity>> {
-        let mut path = format!("/activity?user={}", user);
-
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
-        }
+        let query = QueryBuilder::new()
+            .push("user", normalize_address(user)?)
+            .append(params.map(|p| p.to_query()).unwrap_or_default());
+        let path = format!("/activity{}", query.build());
 
         self.http_client.get(&path, None).await
     }
@@ -100,7 +244,162 @@ impl DataClient {
     /// # Returns
     /// A list of closed positions for the user
     pub async fn get_closed_positions(&self, user: &str) -> Result<Vec<ClosedPosition>> {
-        let path = format!("/closed-positions?user={}", user);
+        let query = QueryBuilder::new().push("user", normalize_address(user)?);
+        let path = format!("/closed-positions{}", query.build());
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockServer, MockTransport};
+    use futures_util::StreamExt;
+    use rust_decimal::Decimal;
+
+    const USER: &str = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+
+    fn position_json(index: u32) -> serde_json::Value {
+        serde_json::json!({
+            "proxyWallet": "0xabc",
+            "asset": format!("{}", index),
+            "conditionId": "0xdef",
+            "size": 10.0,
+            "avgPrice": 0.5,
+            "initialValue": 5.0,
+            "currentValue": 6.0,
+            "cashPnl": 1.0,
+            "percentPnl": 20.0,
+            "totalBought": 10.0,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 0.6,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Market",
+            "eventId": "1",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "2",
+            "endDate": "2025-01-01",
+            "negativeRisk": false,
+        })
+    }
+
+    fn page(indices: std::ops::Range<u32>) -> (u16, String) {
+        let positions: Vec<_> = indices.map(position_json).collect();
+        (200, serde_json::Value::from(positions).to_string())
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout_fires_on_slow_server() {
+        let slow = || {
+            crate::test_utils::MockResponse::from(page(0..1))
+                .with_delay(std::time::Duration::from_millis(300))
+        };
+        let server = MockServer::start(vec![slow(), slow()]).await;
+        let client = DataClient::new(server.url());
+
+        let options = RequestOptions::new()
+            .timeout(std::time::Duration::from_millis(50))
+            .header("X-Request-Id", "positions-1");
+        let result = client
+            .get_positions_with_options(USER, None, &options)
+            .await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+        assert_eq!(
+            server.requests()[0].header("X-Request-Id"),
+            Some("positions-1")
+        );
+
+        // Other calls keep the client's default timeout
+        let positions = client.get_positions(USER, None).await.unwrap();
+        assert_eq!(positions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_with_mock_transport() {
+        let body = serde_json::json!([position_json(7)]).to_string();
+        let client = DataClient::with_transport(MockTransport::new(vec![body]));
+
+        // Checksummed input is sent lowercase
+        let checksummed = USER
+            .parse::<alloy_primitives::Address>()
+            .unwrap()
+            .to_checksum(None);
+        let params = PositionQueryParams::new().with_limit(5);
+        let positions = client
+            .get_positions(&checksummed, Some(params))
+            .await
+            .unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].asset, "7");
+        assert_eq!(positions[0].outcome_index, 0);
+
+        let requests = client.http_client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            format!("/positions?user={}&limit=5", USER)
+        );
+
+        assert!(matches!(
+            client.get_positions("0xabc", None).await,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert_eq!(client.http_client.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_portfolio_summary() {
+        let mut other_market = position_json(2);
+        other_market["conditionId"] = "0x123".into();
+        let body = serde_json::json!([position_json(0), position_json(1), other_market]);
+        let client = DataClient::with_transport(MockTransport::new(vec![body.to_string()]));
+
+        let summary = client.get_portfolio_summary(USER).await.unwrap();
+        assert_eq!(summary.position_count, 3);
+        assert_eq!(summary.total_value, Decimal::from(18));
+        assert_eq!(summary.total_cost, Decimal::from(15));
+        assert_eq!(summary.by_market.len(), 2);
+        assert_eq!(summary.by_market["0xdef"].size, Decimal::from(20));
+        assert_eq!(summary.by_market["0x123"].position_count, 1);
+
+        let requests = client.http_client.requests();
+        assert_eq!(
+            requests[0].path,
+            format!("/positions?user={}&limit=100&offset=0", USER)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_positions_stream_multiple_pages() {
+        let server = MockServer::start(vec![page(0..3), page(3..6), page(6..7)]).await;
+        let client = DataClient::new(server.url());
+
+        let params = PositionQueryParams::new()
+            .with_limit(3)
+            .with_redeemable(true);
+        let positions: Vec<Position> = client
+            .positions_stream(&USER[2..], Some(params))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let assets: Vec<_> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, ["0", "1", "2", "3", "4", "5", "6"]);
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [0, 3, 6].map(|offset| format!(
+                "/positions?user={}&redeemable=true&limit=3&offset={}",
+                USER, offset
+            ))
+        );
+    }
+}
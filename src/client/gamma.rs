@@ -1,7 +1,12 @@
-use crate::error::Result;
-use crate::http::HttpClient;
-use crate::request::GammaMarketParams;
-use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
+use crate::config::{HttpClientConfig, RetryPolicy};
+use crate::error::{ApiError, Error, Result};
+use crate::http::{HttpClient, HttpTransport};
+use crate::request::{GammaEventParams, GammaMarketParams, GammaSeriesParams};
+use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag, Resolution};
+use futures_util::{stream, Stream, TryStreamExt};
+
+/// Page size used by [`GammaClient::markets_stream`] when the params don't set a limit
+const DEFAULT_PAGE_SIZE: u32 = 100;
 
 /// Client for Gamma API - Market discovery and metadata
 ///
@@ -32,8 +37,8 @@ use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag
 ///     Ok(())
 /// }
 /// ```
-pub struct GammaClient {
-    http_client: HttpClient,
+pub struct GammaClient<T = HttpClient> {
+    http_client: T,
 }
 
 impl GammaClient {
@@ -54,6 +59,57 @@ impl GammaClient {
         }
     }
 
+    /// Create a new GammaClient with custom HTTP timeouts
+    ///
+    /// # Arguments
+    /// * `host` - The base URL for the Gamma API
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    /// ```
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::HttpClientConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = HttpClientConfig {
+    ///     request_timeout: Duration::from_secs(10),
+    ///     ..Default::default()
+    /// };
+    /// let client = GammaClient::with_config("https://gamma-api.polymarket.com", config).unwrap();
+    /// ```
+    pub fn with_config(host: impl Into<String>, config: HttpClientConfig) -> Result<Self> {
+        Ok(Self {
+            http_client: HttpClient::with_config(host, config)?,
+        })
+    }
+
+    /// Retry failed GET requests with exponential backoff
+    ///
+    /// Requests are retried on 5xx responses and connection errors, never on 4xx.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry(policy);
+        self
+    }
+
+    /// Limit outgoing requests to `requests_per_second`
+    ///
+    /// Requests wait instead of failing with a 429. A non-positive rate disables limiting.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.http_client = self.http_client.with_rate_limit(requests_per_second);
+        self
+    }
+}
+
+impl<T: HttpTransport> GammaClient<T> {
+    /// Create a client that sends its requests through `transport`
+    ///
+    /// Useful to substitute a fake transport in tests.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            http_client: transport,
+        }
+    }
+
     /// Get markets with optional filtering and pagination
     ///
     /// # Arguments
@@ -78,9 +134,7 @@ impl GammaClient {
     ///
     /// let markets = client.get_markets(Some(params)).await?;
     /// for market in markets {
-    ///     if let Some(q) = &market.question {
-    ///         println!("{}: {}", market.id, q);
-    ///     }
+    ///     println!("{}: {}", market.id, market.question);
     /// }
     /// # Ok(())
     /// # }
@@ -93,6 +147,56 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Stream all markets matching the params, fetching pages on demand
+    ///
+    /// Starts at the params' offset (or 0) and advances it by the page size,
+    /// which is the params' limit or 100 if unset. The stream ends after the
+    /// first page shorter than the page size.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaMarketParams;
+    /// use polymarket_rs::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = GammaMarketParams::new().with_active(true);
+    ///
+    /// let mut markets = Box::pin(client.markets_stream(Some(params)));
+    /// while let Some(market) = markets.next().await {
+    ///     println!("{}", market?.question);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn markets_stream(
+        &self,
+        params: Option<GammaMarketParams>,
+    ) -> impl Stream<Item = Result<GammaMarket>> + '_ {
+        let params = params.unwrap_or_default();
+        let page_size = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let start = params.offset.unwrap_or(0);
+
+        stream::try_unfold(Some(start), move |offset| {
+            let params = params.clone();
+            async move {
+                let Some(offset) = offset else {
+                    return Ok::<_, Error>(None);
+                };
+
+                let page = self
+                    .get_markets(Some(params.with_limit(page_size).with_offset(offset)))
+                    .await?;
+                let next = (page.len() as u32 >= page_size).then_some(offset + page_size);
+
+                Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten()
+    }
+
     /// Get a specific market by condition ID
     ///
     /// # Arguments
@@ -109,9 +213,7 @@ impl GammaClient {
     /// # async fn main() -> polymarket_rs::Result<()> {
     /// let client = GammaClient::new("https://gamma-api.polymarket.com");
     /// let market = client.get_market("0x123...").await?;
-    /// if let Some(q) = &market.question {
-    ///     println!("Market: {}", q);
-    /// }
+    /// println!("Market: {}", market.question);
     /// # Ok(())
     /// # }
     /// ```
@@ -222,6 +324,63 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get a specific market by its condition ID
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market, as emitted by on-chain events
+    ///
+    /// # Returns
+    /// A single market with full metadata, or an `Error::Api` with status 404 if
+    /// no market has this condition ID
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let market = client.get_market_by_condition_id("0x123...").await?;
+    /// println!("Market: {}", market.question);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_market_by_condition_id(&self, condition_id: &str) -> Result<GammaMarket> {
+        let path = format!("/markets?condition_ids={}", condition_id);
+        let markets: Vec<GammaMarket> = self.http_client.get(&path, None).await?;
+
+        markets.into_iter().next().ok_or_else(|| {
+            Error::Api(ApiError::new(
+                404,
+                format!("No market found for condition_id {}", condition_id),
+            ))
+        })
+    }
+
+    /// Get the resolution state of a market by condition ID
+    ///
+    /// Derived from the market's status and settled outcome prices, see
+    /// [`GammaMarket::resolution`]. Use it to confirm a market resolved, and
+    /// which outcome won, before redeeming.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::GammaClient;
+    /// # async fn example() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let resolution = client.get_resolution("0x123...").await?;
+    /// if let Some(index_set) = resolution.winning_index_set() {
+    ///     println!("Redeem index set {}", index_set);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_resolution(&self, condition_id: &str) -> Result<Resolution> {
+        self.get_market_by_condition_id(condition_id)
+            .await?
+            .resolution()
+    }
+
     /// Get all events
     ///
     /// Events are collections of related markets. This endpoint returns
@@ -246,6 +405,34 @@ impl GammaClient {
         self.http_client.get("/events", None).await
     }
 
+    /// Get events matching the given filters
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters for filtering, ordering, and pagination
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaEventParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = GammaEventParams::new()
+    ///     .with_active(true)
+    ///     .with_order("volume", false)
+    ///     .with_limit(10);
+    ///
+    /// let events = client.get_events_with(params).await?;
+    /// println!("Found {} events", events.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_events_with(&self, params: GammaEventParams) -> Result<Vec<GammaEvent>> {
+        let path = format!("/events{}", params.to_query_string());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get a specific event by its ID
     ///
     /// # Arguments
@@ -295,6 +482,31 @@ impl GammaClient {
         self.http_client.get("/series", None).await
     }
 
+    /// Get series matching the given filters
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters for filtering, ordering, and pagination
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaSeriesParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = GammaSeriesParams::new().with_closed(false).with_limit(10);
+    ///
+    /// let series = client.get_series_with(params).await?;
+    /// println!("Found {} series", series.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_series_with(&self, params: GammaSeriesParams) -> Result<Vec<GammaSeries>> {
+        let path = format!("/series{}", params.to_query_string());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get a specific series by its ID
     ///
     /// # Arguments
@@ -320,3 +532,117 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+    use futures_util::StreamExt;
+
+    fn market_json(id: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id.to_string(),
+            "question": format!("Question {}", id),
+            "description": "",
+            "conditionId": format!("0x{:064x}", id),
+            "slug": format!("market-{}", id),
+        })
+    }
+
+    fn page(ids: std::ops::Range<u32>) -> (u16, String) {
+        let markets: Vec<_> = ids.map(market_json).collect();
+        (200, serde_json::Value::from(markets).to_string())
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_pages_until_short_page() {
+        let server = MockServer::start(vec![page(10..12), page(12..14), page(14..15)]).await;
+        let client = GammaClient::new(server.url());
+
+        let params = GammaMarketParams::new().with_limit(2).with_offset(10);
+        let markets: Vec<GammaMarket> = client
+            .markets_stream(Some(params))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let ids: Vec<_> = markets.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["10", "11", "12", "13", "14"]);
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/markets?limit=2&offset=10",
+                "/markets?limit=2&offset=12",
+                "/markets?limit=2&offset=14",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_stops_on_empty_page() {
+        let server = MockServer::start(vec![page(0..100), page(0..0)]).await;
+        let client = GammaClient::new(server.url());
+
+        let count = client.markets_stream(None).count().await;
+        assert_eq!(count, 100);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].path, "/markets?limit=100&offset=100");
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_yields_error() {
+        let server = MockServer::start(vec![(500, "boom".to_string())]).await;
+        let client = GammaClient::new(server.url());
+
+        let results: Vec<_> = client.markets_stream(None).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(Error::Api(ApiError { status: 500, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_by_condition_id() {
+        let server = MockServer::start(vec![page(7..8), page(0..0)]).await;
+        let client = GammaClient::new(server.url());
+
+        let market = client.get_market_by_condition_id("0xabc").await.unwrap();
+        assert_eq!(market.id, "7");
+        assert_eq!(server.requests()[0].path, "/markets?condition_ids=0xabc");
+
+        let result = client.get_market_by_condition_id("0xdef").await;
+        assert!(matches!(
+            result,
+            Err(Error::Api(ApiError { status: 404, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_events_with_params() {
+        let server =
+            MockServer::start(vec![(200, "[]".to_string()), (200, "[]".to_string())]).await;
+        let client = GammaClient::new(server.url());
+
+        let params = GammaEventParams::new().with_tag_id("2").with_limit(5);
+        assert!(client.get_events_with(params).await.unwrap().is_empty());
+
+        let params = GammaSeriesParams::new().with_order("volume", false);
+        assert!(client.get_series_with(params).await.unwrap().is_empty());
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/events?limit=5&tag_id=2",
+                "/series?order=volume&ascending=false"
+            ]
+        );
+    }
+}
@@ -2,6 +2,7 @@ mod authenticated;
 mod clob;
 mod data;
 mod gamma;
+mod pagination;
 mod trading;
 
 pub use authenticated::AuthenticatedClient;
@@ -9,3 +10,5 @@ pub use clob::ClobClient;
 pub use data::DataClient;
 pub use gamma::GammaClient;
 pub use trading::TradingClient;
+
+pub use crate::http::{HttpClient, HttpTransport};
@@ -0,0 +1,151 @@
+use std::future::Future;
+
+use crate::error::Result;
+use crate::request::END_CURSOR;
+use crate::types::{
+    ClobTrade, Market, MarketsResponse, OpenOrder, OpenOrdersResponse, RewardConfig,
+    RewardConfigsResponse, SimplifiedMarket, SimplifiedMarketsResponse, TradesResponse,
+    UserEarning, UserEarningsResponse,
+};
+
+/// A page from a cursor-paginated CLOB endpoint
+pub(crate) trait CursorPage {
+    type Item;
+
+    /// Split the page into its items and the cursor of the next page
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+/// Cursor to request after a page, or None once the last page was reached
+///
+/// The CLOB marks the last page with [`END_CURSOR`]; an empty or missing
+/// cursor is treated the same way.
+fn next_page_cursor(cursor: Option<String>) -> Option<String> {
+    cursor.filter(|cursor| !cursor.is_empty() && cursor != END_CURSOR)
+}
+
+/// Request pages until the last one, collecting their items in order
+///
+/// `fetch` is called with `initial_cursor` first and then with each page's
+/// `next_cursor`.
+pub(crate) async fn fetch_all_pages<P, F, Fut>(
+    initial_cursor: Option<String>,
+    mut fetch: F,
+) -> Result<Vec<P::Item>>
+where
+    P: CursorPage,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let mut items = Vec::new();
+    let mut cursor = initial_cursor;
+    loop {
+        let (data, next_cursor) = fetch(cursor).await?.into_parts();
+        items.extend(data);
+        cursor = next_page_cursor(next_cursor);
+        if cursor.is_none() {
+            return Ok(items);
+        }
+    }
+}
+
+impl CursorPage for MarketsResponse {
+    type Item = Market;
+
+    fn into_parts(self) -> (Vec<Market>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for SimplifiedMarketsResponse {
+    type Item = SimplifiedMarket;
+
+    fn into_parts(self) -> (Vec<SimplifiedMarket>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for TradesResponse {
+    type Item = ClobTrade;
+
+    fn into_parts(self) -> (Vec<ClobTrade>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for OpenOrdersResponse {
+    type Item = OpenOrder;
+
+    fn into_parts(self) -> (Vec<OpenOrder>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for RewardConfigsResponse {
+    type Item = RewardConfig;
+
+    fn into_parts(self) -> (Vec<RewardConfig>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for UserEarningsResponse {
+    type Item = UserEarning;
+
+    fn into_parts(self) -> (Vec<UserEarning>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestPage {
+        data: Vec<u32>,
+        next_cursor: Option<String>,
+    }
+
+    impl CursorPage for TestPage {
+        type Item = u32;
+
+        fn into_parts(self) -> (Vec<u32>, Option<String>) {
+            (self.data, self.next_cursor)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_pages_stops_at_end_cursor() {
+        let mut requested = Vec::new();
+        let items = fetch_all_pages(None, |cursor: Option<String>| {
+            requested.push(cursor.clone());
+            let page = match cursor.as_deref() {
+                None => TestPage {
+                    data: vec![1, 2],
+                    next_cursor: Some("MQ==".to_string()),
+                },
+                _ => TestPage {
+                    data: vec![3],
+                    next_cursor: Some(END_CURSOR.to_string()),
+                },
+            };
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(requested, vec![None, Some("MQ==".to_string())]);
+    }
+
+    #[test]
+    fn test_next_page_cursor() {
+        assert_eq!(
+            next_page_cursor(Some("MQ==".to_string())),
+            Some("MQ==".to_string())
+        );
+        assert_eq!(next_page_cursor(Some(END_CURSOR.to_string())), None);
+        assert_eq!(next_page_cursor(Some(String::new())), None);
+        assert_eq!(next_page_cursor(None), None);
+    }
+}
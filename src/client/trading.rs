@@ -1,12 +1,19 @@
-use crate::error::Result;
+use super::pagination::fetch_all_pages;
+use crate::config::HttpClientConfig;
+use crate::error::{Error, Result};
 use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
+use crate::orders::{calculate_market_order_price, OrderBuilder};
+use crate::request::QueryBuilder;
 use crate::signing::EthSigner;
 use crate::types::{
-    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
-    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    ApiCreds, CancelOrdersResponse, ClobTrade, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
+    OpenOrder, OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId,
+    OrderType, PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    TradesResponse, UserEarning, UserEarningsResponse,
 };
+use crate::utils::get_current_unix_time_secs;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
 
 /// Client for trading operations
 ///
@@ -45,11 +52,32 @@ impl TradingClient {
         }
     }
 
+    /// Create a new TradingClient with custom HTTP timeouts
+    ///
+    /// Same as [`new`](Self::new), with an additional `config` for the underlying HTTP client.
+    pub fn with_config(
+        host: impl Into<String>,
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+        api_creds: ApiCreds,
+        order_builder: OrderBuilder,
+        config: HttpClientConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: HttpClient::with_config(host, config)?,
+            signer: Box::new(signer),
+            chain_id,
+            api_creds,
+            order_builder,
+        })
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional expiration as Unix seconds (defaults to
+    ///   `order_args.expiration`, or 0 = no expiration)
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     pub fn create_order(
@@ -59,7 +87,7 @@ impl TradingClient {
         extras: Option<&ExtraOrderArgs>,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
-        let expiration = expiration.unwrap_or(0);
+        let expiration = expiration.unwrap_or_else(|| order_args.expiration_secs());
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
 
@@ -67,8 +95,31 @@ impl TradingClient {
             .create_order(self.chain_id, order_args, expiration, extras, options)
     }
 
+    /// Create a limit order, signing without blocking (local operation, not posted)
+    ///
+    /// Same as [`create_order`](Self::create_order), but signs through the async
+    /// signer methods so remote signers work on any runtime.
+    pub async fn create_order_async(
+        &self,
+        order_args: &OrderArgs,
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let expiration = expiration.unwrap_or_else(|| order_args.expiration_secs());
+        let default_extras = ExtraOrderArgs::default();
+        let extras = extras.unwrap_or(&default_extras);
+
+        self.order_builder
+            .create_order_async(self.chain_id, order_args, expiration, extras, options)
+            .await
+    }
+
     /// Create a market order (local operation, not posted)
     ///
+    /// The order price is the worst book level needed to fill `amount`, which is
+    /// collateral (USDC) to spend for BUY orders and shares to sell for SELL orders.
+    ///
     /// # Arguments
     /// * `order_args` - Market order arguments (token_id, amount, side)
     /// * `order_book` - The order book to calculate price from
@@ -84,29 +135,48 @@ impl TradingClient {
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
 
-        // Use asks for BUY (taking from sellers), bids for SELL (taking from buyers)
-        let book_side = match order_args.side {
-            Side::Buy => &order_book.asks,
-            Side::Sell => &order_book.bids,
-        };
-
-        // Calculate market price from order book
-        let price = calculate_market_price(book_side, order_args.amount, order_args.side)?;
+        let price = market_order_price(order_args, order_book)?;
 
         self.order_builder
             .create_market_order(self.chain_id, order_args, price, extras, options)
     }
 
+    /// Create a market order, signing without blocking (local operation, not posted)
+    ///
+    /// Same as [`create_market_order`](Self::create_market_order), but signs
+    /// through the async signer methods so remote signers work on any runtime.
+    pub async fn create_market_order_async(
+        &self,
+        order_args: &MarketOrderArgs,
+        order_book: &OrderBookSummary,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let default_extras = ExtraOrderArgs::default();
+        let extras = extras.unwrap_or(&default_extras);
+
+        let price = market_order_price(order_args, order_book)?;
+
+        self.order_builder
+            .create_market_order_async(self.chain_id, order_args, price, extras, options)
+            .await
+    }
+
     /// Post an order to the exchange
     ///
     /// # Arguments
     /// * `order` - The signed order to post
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidOrder` without posting if a GTD order has no
+    /// expiration or one that is not in the future.
     pub async fn post_order(
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
+        validate_expiration(&order, order_type)?;
         let owner = self.api_creds.api_key.clone();
         let post_order = PostOrder::new(order, owner, order_type);
 
@@ -139,7 +209,14 @@ impl TradingClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidOrder` without posting if any GTD order has no
+    /// expiration or one that is not in the future.
     pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
+        for arg in orders {
+            validate_expiration(&arg.order, arg.order_type)?;
+        }
         let owner = self.api_creds.api_key.clone();
 
         // Build array of PostOrder structs
@@ -163,11 +240,12 @@ impl TradingClient {
 
     /// Create and post an order in one step
     ///
-    /// This is a convenience method that combines create_order and post_order.
+    /// This is a convenience method that combines create_order_async and post_order.
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional expiration as Unix seconds (defaults to
+    ///   `order_args.expiration`, or 0 = no expiration)
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
@@ -179,10 +257,34 @@ impl TradingClient {
         options: CreateOrderOptions,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
-        let order = self.create_order(order_args, expiration, extras, options)?;
+        let order = self
+            .create_order_async(order_args, expiration, extras, options)
+            .await?;
         self.post_order(order, order_type).await
     }
 
+    /// Create and post a market order in one step
+    ///
+    /// The order is posted with `order_args.order_type` (FOK by default).
+    ///
+    /// # Arguments
+    /// * `order_args` - Market order arguments (token_id, amount, side, order_type)
+    /// * `order_book` - The order book to calculate price from
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    pub async fn create_and_post_market_order(
+        &self,
+        order_args: &MarketOrderArgs,
+        order_book: &OrderBookSummary,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<PostOrderResponse> {
+        let order = self
+            .create_market_order_async(order_args, order_book, extras, options)
+            .await?;
+        self.post_order(order, order_args.order_type).await
+    }
+
     /// Get open orders (L2 authentication required)
     ///
     /// # Arguments
@@ -195,20 +297,8 @@ impl TradingClient {
             create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let query = QueryBuilder::new().push_all(params.to_query_params());
+        let request_path = format!("{}{}", base_path, query.build());
 
         self.http_client.get(&request_path, Some(headers)).await
     }
@@ -301,31 +391,93 @@ impl TradingClient {
             .await
     }
 
-    /// Get trade history (L2 authentication required)
+    /// Cancel all open orders on a market
+    ///
+    /// Shorthand for [`cancel_market_orders`](Self::cancel_market_orders) with
+    /// only the market set. Requests go through the client's pooled HTTP
+    /// connection, so a client that has already made a request cancels without
+    /// a new handshake.
+    ///
+    /// # Arguments
+    /// * `market` - The market (condition ID) to cancel orders for
+    pub async fn cancel_orders_by_market(&self, market: &str) -> Result<CancelOrdersResponse> {
+        self.cancel_market_orders(Some(market), None).await
+    }
+
+    /// Cancel all open orders on one asset (outcome token)
+    ///
+    /// Shorthand for [`cancel_market_orders`](Self::cancel_market_orders) with
+    /// only the asset ID set.
+    ///
+    /// # Arguments
+    /// * `asset_id` - The token ID to cancel orders for
+    pub async fn cancel_orders_by_asset(&self, asset_id: &str) -> Result<CancelOrdersResponse> {
+        self.cancel_market_orders(None, Some(asset_id)).await
+    }
+
+    /// Get one page of trade history (L2 authentication required)
+    ///
+    /// Pass the returned `next_cursor` back through `params` to fetch the next
+    /// page; the last page returns [`END_CURSOR`](crate::request::END_CURSOR).
     ///
     /// # Arguments
     /// * `params` - Query parameters to filter trades
-    pub async fn get_trades(&self, params: TradeParams) -> Result<serde_json::Value> {
+    pub async fn get_trades(&self, params: TradeParams) -> Result<TradesResponse> {
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/data/trades";
         let headers =
             create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let query = QueryBuilder::new().push_all(params.to_query_params());
+        let request_path = format!("{}{}", base_path, query.build());
+
+        self.http_client.get(&request_path, Some(headers)).await
+    }
+
+    /// Get the full trade history by following `next_cursor` (L2 authentication required)
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters to filter trades
+    pub async fn get_all_trades(&self, params: TradeParams) -> Result<Vec<ClobTrade>> {
+        fetch_all_pages(params.next_cursor.clone(), |cursor| {
+            let mut params = params.clone();
+            params.next_cursor = cursor;
+            self.get_trades(params)
+        })
+        .await
+    }
+
+    /// Get the liquidity rewards earned on a given day (L2 authentication required)
+    ///
+    /// Earnings are reported for the maker address behind the API key, one
+    /// entry per market; all pages are fetched.
+    ///
+    /// # Arguments
+    /// * `date` - The UTC day to report earnings for
+    pub async fn get_earnings(&self, date: NaiveDate) -> Result<Vec<UserEarning>> {
+        fetch_all_pages(None, |cursor| self.get_earnings_page(date, cursor)).await
+    }
+
+    async fn get_earnings_page(
+        &self,
+        date: NaiveDate,
+        cursor: Option<String>,
+    ) -> Result<UserEarningsResponse> {
+        // IMPORTANT: Sign the base path WITHOUT query parameters
+        let base_path = "/rewards/user";
+        let headers =
+            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
+
+        let mut request_path = format!(
+            "{}?date={}&signature_type={}",
+            base_path,
+            date.format("%Y-%m-%d"),
+            self.order_builder.get_sig_type()
+        );
+        if let Some(cursor) = cursor {
+            request_path.push_str(&format!("&next_cursor={}", cursor));
+        }
 
         self.http_client.get(&request_path, Some(headers)).await
     }
@@ -359,3 +511,378 @@ impl TradingClient {
             .await
     }
 }
+
+/// Price a market order by walking the book
+///
+/// Uses asks for BUY (taking from sellers) and bids for SELL (taking from buyers).
+fn market_order_price(
+    order_args: &MarketOrderArgs,
+    order_book: &OrderBookSummary,
+) -> Result<Decimal> {
+    let book_side = match order_args.side {
+        Side::Buy => &order_book.asks,
+        Side::Sell => &order_book.bids,
+    };
+
+    calculate_market_order_price(book_side, order_args.amount, order_args.side)
+}
+
+/// Check that a GTD order carries an expiration in the future
+///
+/// Other order types are posted as they are.
+fn validate_expiration(order: &SignedOrderRequest, order_type: OrderType) -> Result<()> {
+    if order_type != OrderType::Gtd {
+        return Ok(());
+    }
+
+    let expiration: u64 = order
+        .expiration
+        .parse()
+        .map_err(|_| Error::InvalidOrder(format!("Invalid expiration: {}", order.expiration)))?;
+    if expiration == 0 {
+        return Err(Error::InvalidOrder(
+            "GTD orders require an expiration".to_string(),
+        ));
+    }
+    if expiration <= get_current_unix_time_secs()? {
+        return Err(Error::InvalidOrder(format!(
+            "GTD order expiration {} is not in the future",
+            expiration
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::END_CURSOR;
+    use crate::test_utils::MockServer;
+    use crate::types::OrderStatus;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn trading_client(host: String) -> TradingClient {
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "c2VjcmV0c2VjcmV0c2VjcmV0".to_string(),
+            "pass".to_string(),
+        );
+        let order_builder = OrderBuilder::new(signer.clone(), None, None);
+        TradingClient::new(host, signer, 137, creds, order_builder)
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders() {
+        let response = r#"{"canceled":["0x1"],"not_canceled":{"0x2":"order already matched"}}"#;
+        let server = MockServer::start(vec![(200, response.to_string())]).await;
+        let client = trading_client(server.url());
+
+        let ids = [OrderId::new("0x1"), OrderId::new("0x2")];
+        let result = client.cancel_orders(&ids).await.unwrap();
+
+        assert_eq!(result.canceled, vec![OrderId::new("0x1")]);
+        assert_eq!(
+            result
+                .not_canceled
+                .get(&OrderId::new("0x2"))
+                .map(String::as_str),
+            Some("order already matched")
+        );
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/orders");
+        assert_eq!(request.body, r#"["0x1","0x2"]"#);
+        assert_eq!(request.header("POLY_API_KEY"), Some("key"));
+        assert!(request.header("POLY_SIGNATURE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_empty_response() {
+        let server = MockServer::start(vec![(200, r#"{"canceled":[]}"#.to_string())]).await;
+        let client = trading_client(server.url());
+
+        let result = client.cancel_all().await.unwrap();
+        assert!(result.canceled.is_empty());
+        assert!(result.not_canceled.is_empty());
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/cancel-all");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_by_market_and_asset() {
+        let response = r#"{"canceled":["0x1"]}"#;
+        let server = MockServer::start(vec![
+            (200, response.to_string()),
+            (200, response.to_string()),
+        ])
+        .await;
+        let client = trading_client(server.url());
+
+        let result = client.cancel_orders_by_market("0xmarket").await.unwrap();
+        assert_eq!(result.canceled, vec![OrderId::new("0x1")]);
+        client.cancel_orders_by_asset("123").await.unwrap();
+
+        let requests = server.requests();
+        for request in &requests {
+            assert_eq!(request.method, "DELETE");
+            assert_eq!(request.path, "/cancel-market-orders");
+            assert_eq!(request.header("POLY_API_KEY"), Some("key"));
+            assert_eq!(request.header("POLY_PASSPHRASE"), Some("pass"));
+            assert!(request.header("POLY_SIGNATURE").is_some());
+            assert!(request.header("POLY_TIMESTAMP").is_some());
+        }
+
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "market": "0xmarket", "asset_id": "" })
+        );
+        let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(body, serde_json::json!({ "market": "", "asset_id": "123" }));
+    }
+
+    fn trade_json(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "taker_order_id": "0x06bc63e346ed4ceddce9efd6b3af37c8f8f440c92fe7da6b2d0f9e4ccbc50c42",
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "side": "BUY",
+            "size": "40",
+            "fee_rate_bps": "0",
+            "price": "0.51",
+            "status": "CONFIRMED",
+            "match_time": "1700000000",
+            "last_update": "1700000010",
+            "outcome": "Yes",
+            "bucket_index": 0,
+            "owner": "f4f247b7-4ac7-ff29-a152-04fda0a8755a",
+            "maker_address": "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+            "maker_orders": [{
+                "order_id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+                "owner": "a1b2c3d4-0000-0000-0000-000000000000",
+                "maker_address": "0x9d84ce0306f8551e02efef1680475fc0f1dc1344",
+                "matched_amount": "40",
+                "price": "0.51",
+                "fee_rate_bps": "0",
+                "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+                "outcome": "Yes",
+                "side": "SELL"
+            }],
+            "transaction_hash": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+            "trader_side": "TAKER"
+        })
+    }
+
+    fn trades_page(ids: &[&str], next_cursor: &str) -> (u16, String) {
+        let data: Vec<_> = ids.iter().map(|id| trade_json(id)).collect();
+        let page = serde_json::json!({
+            "limit": 100,
+            "count": data.len(),
+            "next_cursor": next_cursor,
+            "data": data,
+        });
+        (200, page.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_parses_clob_trades() {
+        let server = MockServer::start(vec![trades_page(&["1"], "MTAw")]).await;
+        let client = trading_client(server.url());
+
+        let page = client
+            .get_trades(TradeParams::new().market("0xbd31").after(1699999999))
+            .await
+            .unwrap();
+
+        assert_eq!(page.next_cursor.as_deref(), Some("MTAw"));
+        let trade = &page.data[0];
+        assert_eq!(trade.side, Side::Buy);
+        assert_eq!(trade.price, rust_decimal_macros::dec!(0.51));
+        assert_eq!(trade.match_time, 1700000000);
+        assert_eq!(trade.trader_side, "TAKER");
+        assert_eq!(
+            trade.maker_address,
+            "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5"
+        );
+        assert_eq!(trade.maker_orders.len(), 1);
+        assert_eq!(trade.maker_orders[0].side, Some(Side::Sell));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/data/trades?market=0xbd31&after=1699999999");
+        assert!(request.header("POLY_SIGNATURE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_trades_follows_cursor() {
+        let server = MockServer::start(vec![
+            trades_page(&["1", "2"], "MTAw"),
+            trades_page(&["3"], END_CURSOR),
+        ])
+        .await;
+        let client = trading_client(server.url());
+
+        let trades = client.get_all_trades(TradeParams::new()).await.unwrap();
+
+        let ids: Vec<_> = trades.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/data/trades");
+        assert_eq!(requests[1].path, "/data/trades?next_cursor=MTAw");
+    }
+
+    #[tokio::test]
+    async fn test_get_earnings_follows_cursor() {
+        let earnings_page = |condition_ids: &[&str], next_cursor: &str| {
+            let data: Vec<_> = condition_ids
+                .iter()
+                .map(|condition_id| {
+                    serde_json::json!({
+                        "date": "2024-10-20",
+                        "condition_id": condition_id,
+                        "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                        "maker_address": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                        "earnings": 0.5,
+                        "asset_rate": 1
+                    })
+                })
+                .collect();
+            let page = serde_json::json!({
+                "limit": 100,
+                "count": data.len(),
+                "next_cursor": next_cursor,
+                "data": data,
+            });
+            (200, page.to_string())
+        };
+        let server = MockServer::start(vec![
+            earnings_page(&["0xa", "0xb"], "MTAw"),
+            earnings_page(&["0xc"], END_CURSOR),
+        ])
+        .await;
+        let client = trading_client(server.url());
+
+        let date = NaiveDate::from_ymd_opt(2024, 10, 20).unwrap();
+        let earnings = client.get_earnings(date).await.unwrap();
+
+        assert_eq!(earnings.len(), 3);
+        assert_eq!(earnings[2].condition_id, "0xc");
+        assert_eq!(earnings[0].earnings, rust_decimal_macros::dec!(0.5));
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/rewards/user?date=2024-10-20&signature_type=0"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/rewards/user?date=2024-10-20&signature_type=0&next_cursor=MTAw"
+        );
+        assert!(requests[0].header("POLY_SIGNATURE").is_some());
+    }
+
+    fn gtd_order(
+        client: &TradingClient,
+        expiration: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> SignedOrderRequest {
+        let mut args = OrderArgs::new(
+            "1234",
+            rust_decimal_macros::dec!(0.5),
+            rust_decimal_macros::dec!(10),
+            Side::Buy,
+        );
+        if let Some(expiration) = expiration {
+            args = args.with_expiration(expiration);
+        }
+        let options = CreateOrderOptions::new()
+            .tick_size(rust_decimal_macros::dec!(0.01))
+            .neg_risk(false);
+        client.create_order(&args, None, None, options).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_gtd_order_with_expiration() {
+        let response = r#"{"errorMsg":"","orderID":"0xabc","status":"live","success":true}"#;
+        let server = MockServer::start(vec![(200, response.to_string())]).await;
+        let client = trading_client(server.url());
+
+        let expiration = chrono::Utc::now() + chrono::Duration::hours(1);
+        let order = gtd_order(&client, Some(expiration));
+        assert_eq!(order.expiration, expiration.timestamp().to_string());
+
+        let result = client.post_order(order, OrderType::Gtd).await.unwrap();
+        assert!(result.success);
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(body["orderType"], "GTD");
+        assert_eq!(body["deferExec"], false);
+        assert_eq!(
+            body["order"]["expiration"],
+            expiration.timestamp().to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_gtd_order_requires_future_expiration() {
+        let server = MockServer::start(Vec::<(u16, String)>::new()).await;
+        let client = trading_client(server.url());
+
+        let missing = gtd_order(&client, None);
+        assert_eq!(missing.expiration, "0");
+        assert!(matches!(
+            client.post_order(missing.clone(), OrderType::Gtd).await,
+            Err(Error::InvalidOrder(_))
+        ));
+
+        let past = gtd_order(
+            &client,
+            Some(chrono::Utc::now() - chrono::Duration::hours(1)),
+        );
+        assert!(matches!(
+            client
+                .post_orders(&[
+                    PostOrderArgs::new(missing, OrderType::Gtc),
+                    PostOrderArgs::new(past, OrderType::Gtd),
+                ])
+                .await,
+            Err(Error::InvalidOrder(_))
+        ));
+
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_post_order_with_taker_and_defer_exec() {
+        let response = r#"{"errorMsg":"","orderID":"0xabc","status":"delayed","success":true}"#;
+        let server = MockServer::start(vec![(200, response.to_string())]).await;
+        let client = trading_client(server.url());
+
+        let taker = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+        let args = OrderArgs::new(
+            "1234",
+            rust_decimal_macros::dec!(0.5),
+            rust_decimal_macros::dec!(10),
+            Side::Buy,
+        );
+        let extras = ExtraOrderArgs::new().taker(taker).fee_rate_bps(25);
+        let options = CreateOrderOptions::new()
+            .tick_size(rust_decimal_macros::dec!(0.01))
+            .neg_risk(false)
+            .defer_exec(true);
+        let order = client
+            .create_order(&args, None, Some(&extras), options)
+            .unwrap();
+
+        let result = client.post_order(order, OrderType::Gtc).await.unwrap();
+        assert_eq!(result.order_status(), OrderStatus::Delayed);
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(body["deferExec"], true);
+        assert_eq!(body["order"]["taker"], taker);
+        assert_eq!(body["order"]["feeRateBps"], "25");
+        assert!(body["order"].get("deferExec").is_none());
+    }
+}
@@ -0,0 +1,90 @@
+//! API and WebSocket hosts for Polymarket services
+//!
+//! Chain-specific lookups return `None` for chains without a known public endpoint.
+
+use super::chains;
+
+/// CLOB API host on Polygon mainnet
+pub const CLOB_HOST: &str = "https://clob.polymarket.com";
+
+/// Gamma (market metadata) API host
+pub const GAMMA_HOST: &str = "https://gamma-api.polymarket.com";
+
+/// Data API host
+pub const DATA_API_HOST: &str = "https://data-api.polymarket.com";
+
+/// Relayer host on Polygon mainnet
+pub const RELAYER_HOST: &str = "https://relayer-v2.polymarket.com";
+
+/// Relayer host on Polygon Amoy testnet
+pub const RELAYER_AMOY_HOST: &str = "https://relayer-v2-staging.polymarket.dev";
+
+/// Market channel WebSocket URL on Polygon mainnet
+pub const MARKET_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+
+/// User channel WebSocket URL on Polygon mainnet
+pub const USER_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
+
+/// Get the CLOB API host for a chain
+pub fn clob_host(chain_id: u64) -> Option<&'static str> {
+    match chain_id {
+        chains::POLYGON_MAINNET => Some(CLOB_HOST),
+        _ => None,
+    }
+}
+
+/// Get the Gamma API host (not chain specific)
+pub fn gamma_host() -> &'static str {
+    GAMMA_HOST
+}
+
+/// Get the data API host (not chain specific)
+pub fn data_api_host() -> &'static str {
+    DATA_API_HOST
+}
+
+/// Get the relayer host for a chain
+pub fn relayer_host(chain_id: u64) -> Option<&'static str> {
+    match chain_id {
+        chains::POLYGON_MAINNET => Some(RELAYER_HOST),
+        chains::POLYGON_AMOY_TESTNET => Some(RELAYER_AMOY_HOST),
+        _ => None,
+    }
+}
+
+/// Get the market channel WebSocket URL for a chain
+pub fn market_ws_url(chain_id: u64) -> Option<&'static str> {
+    match chain_id {
+        chains::POLYGON_MAINNET => Some(MARKET_WS_URL),
+        _ => None,
+    }
+}
+
+/// Get the user channel WebSocket URL for a chain
+pub fn user_ws_url(chain_id: u64) -> Option<&'static str> {
+    match chain_id {
+        chains::POLYGON_MAINNET => Some(USER_WS_URL),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mainnet_hosts() {
+        assert_eq!(clob_host(137), Some("https://clob.polymarket.com"));
+        assert_eq!(relayer_host(137), Some(RELAYER_HOST));
+        assert_eq!(market_ws_url(137), Some(MARKET_WS_URL));
+        assert_eq!(user_ws_url(137), Some(USER_WS_URL));
+    }
+
+    #[test]
+    fn test_amoy_and_unsupported_hosts() {
+        assert_eq!(relayer_host(80002), Some(RELAYER_AMOY_HOST));
+        assert_eq!(clob_host(80002), None);
+        assert_eq!(market_ws_url(80002), None);
+        assert_eq!(relayer_host(1), None);
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+/// Configuration for the underlying HTTP client
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    /// Timeout for establishing a connection
+    pub connect_timeout: Duration,
+    /// Timeout for a whole request, from sending to reading the full response body
+    pub request_timeout: Duration,
+    /// How long idle pooled connections are kept alive
+    pub pool_idle_timeout: Duration,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            pool_idle_timeout: Duration::from_secs(90),
+        }
+    }
+}
+
+/// Per-request overrides of the client configuration
+///
+/// Used by the `*_with_options` methods, for example
+/// [`DataClient::get_positions_with_options`](crate::client::DataClient::get_positions_with_options),
+/// to give a single call its own deadline without reconfiguring the client.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Timeout for this request, replacing [`HttpClientConfig::request_timeout`]
+    pub timeout: Option<Duration>,
+    /// Headers sent in addition to the ones the client sets
+    pub extra_headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up on the request after `timeout`, failing with `Error::Timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send an additional header with the request
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Retry policy for idempotent HTTP requests
+///
+/// Uses the same exponential backoff shape as
+/// [`ReconnectConfig`](crate::websocket::ReconnectConfig).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Maximum delay between retries
+    pub max_delay: Duration,
+    /// Multiplier for exponential backoff
+    pub multiplier: f64,
+    /// Maximum number of attempts, including the initial request
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            max_attempts: 3,
+        }
+    }
+}
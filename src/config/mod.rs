@@ -1,3 +1,6 @@
 mod contracts;
+pub mod hosts;
+mod http;
 
 pub use contracts::{chains, get_contract_config, ContractConfig};
+pub use http::{HttpClientConfig, RequestOptions, RetryPolicy};
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::time::Duration;
 
 /// Result type for polymarket-rs operations
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,7 +10,27 @@ pub enum Error {
     /// HTTP request failed
     Http(reqwest::Error),
 
+    /// HTTP request timed out
+    Timeout(reqwest::Error),
+
+    /// Serializing a request to JSON failed
+    Serialization(serde_json::Error),
+
+    /// A response or message did not match the expected JSON shape
+    ///
+    /// `body` holds the start of the offending payload (see
+    /// [`MAX_ERROR_BODY_LEN`]).
+    Deserialization {
+        error: serde_json::Error,
+        body: String,
+    },
+
     /// JSON serialization/deserialization failed
+    ///
+    /// No longer returned by this crate, which reports
+    /// [`Error::Serialization`] and [`Error::Deserialization`] instead. Kept
+    /// so existing matches keep compiling.
+    #[deprecated(note = "use `Error::Serialization` or `Error::Deserialization`")]
     Json(serde_json::Error),
 
     /// Invalid configuration
@@ -25,7 +46,13 @@ pub enum Error {
     InvalidParameter(String),
 
     /// API error response
-    Api { status: u16, message: String },
+    Api(ApiError),
+
+    /// The relayer rejected a submitted transaction
+    Relayer { status: u16, error: RelayerError },
+
+    /// Rate limited by the API (HTTP 429)
+    RateLimited { retry_after: Option<Duration> },
 
     /// Decimal conversion error
     Decimal(rust_decimal::Error),
@@ -43,24 +70,52 @@ pub enum Error {
     ConnectionClosed,
 
     /// Reconnection failed after multiple attempts
-    ReconnectFailed {
-        attempts: u32,
-        last_error: String,
-    },
+    ReconnectFailed { attempts: u32, last_error: String },
+
+    /// Relayer transaction did not reach the awaited state in time
+    TransactionTimeout { transaction_id: String, polls: u32 },
+
+    /// An identical transaction was already submitted and its outcome is unknown
+    ///
+    /// Returned instead of resubmitting while a dedupe window is set; check
+    /// the relayer before retrying.
+    DuplicatePending { nonce: Option<String> },
+
+    /// The relayer accepted a Safe transaction queued behind a nonce that was not used
+    ///
+    /// The transaction cannot execute until a transaction with `nonce` goes
+    /// through. Reported by
+    /// [`redeem_all_positions_concurrent`](crate::relayer::RelayerClient::redeem_all_positions_concurrent)
+    /// when an earlier redemption failed.
+    NonceGap { nonce: u64, transaction_id: String },
+
+    /// Operation was cancelled by the caller
+    Cancelled,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Http(e) => write!(f, "HTTP error: {}", e),
+            Error::Timeout(e) => write!(f, "Request timed out: {}", e),
+            Error::Serialization(e) => write!(f, "Failed to serialize request: {}", e),
+            Error::Deserialization { error, body } => {
+                write!(f, "Failed to parse response: {} (body: {})", error, body)
+            }
+            #[allow(deprecated)]
             Error::Json(e) => write!(f, "JSON error: {}", e),
             Error::Config(msg) => write!(f, "Configuration error: {}", msg),
             Error::AuthRequired(msg) => write!(f, "Authentication required: {}", msg),
             Error::Signing(msg) => write!(f, "Signing error: {}", msg),
             Error::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
-            Error::Api { status, message } => {
-                write!(f, "API error (status {}): {}", status, message)
+            Error::Api(e) => write!(f, "{}", e),
+            Error::Relayer { status, error } => {
+                write!(f, "Relayer error (status {}): {}", status, error)
             }
+            Error::RateLimited { retry_after } => match retry_after {
+                Some(delay) => write!(f, "Rate limited, retry after {:?}", delay),
+                None => write!(f, "Rate limited"),
+            },
             Error::Decimal(e) => write!(f, "Decimal error: {}", e),
             Error::InvalidOrder(msg) => write!(f, "Invalid order: {}", msg),
             Error::MissingField(field) => write!(f, "Missing required field: {}", field),
@@ -74,15 +129,211 @@ impl fmt::Display for Error {
                 "Reconnection failed after {} attempts: {}",
                 attempts, last_error
             ),
+            Error::TransactionTimeout {
+                transaction_id,
+                polls,
+            } => write!(
+                f,
+                "Transaction {} not finished after {} polls",
+                transaction_id, polls
+            ),
+            Error::DuplicatePending { nonce } => write!(
+                f,
+                "An identical transaction (nonce {}) was already submitted and its outcome is unknown",
+                nonce.as_deref().unwrap_or("none")
+            ),
+            Error::NonceGap {
+                nonce,
+                transaction_id,
+            } => write!(
+                f,
+                "Transaction {} is queued behind unused nonce {}",
+                transaction_id, nonce
+            ),
+            Error::Cancelled => write!(f, "Operation cancelled"),
+        }
+    }
+}
+
+/// Maximum number of characters of a payload kept in [`Error::Deserialization`]
+pub const MAX_ERROR_BODY_LEN: usize = 200;
+
+impl Error {
+    /// Create an [`Error::Deserialization`] for a payload that failed to parse
+    ///
+    /// The body is truncated to [`MAX_ERROR_BODY_LEN`] characters.
+    pub fn deserialization(error: serde_json::Error, body: &str) -> Self {
+        let mut snippet: String = body.chars().take(MAX_ERROR_BODY_LEN).collect();
+        if snippet.len() < body.len() {
+            snippet.push_str("...");
+        }
+        Error::Deserialization {
+            error,
+            body: snippet,
         }
     }
 }
 
+/// Error response returned by a Polymarket API
+///
+/// JSON bodies such as `{"error": "not enough balance"}` are parsed into `error`
+/// and `code`; other bodies are kept as-is in `error`. The unparsed body is
+/// always available in `raw`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// HTTP status code
+    pub status: u16,
+    /// Error message
+    pub error: String,
+    /// Error code, if the API sent one
+    pub code: Option<String>,
+    /// Raw response body
+    pub raw: String,
+}
+
+impl ApiError {
+    /// Create an error that did not come from a response body
+    pub fn new(status: u16, error: impl Into<String>) -> Self {
+        let error = error.into();
+        Self {
+            status,
+            raw: error.clone(),
+            error,
+            code: None,
+        }
+    }
+
+    /// Parse an error response body
+    pub fn from_body(status: u16, body: impl Into<String>) -> Self {
+        let raw = body.into();
+
+        let json = match serde_json::from_str::<serde_json::Value>(&raw) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => {
+                return Self {
+                    status,
+                    error: raw.trim().to_string(),
+                    code: None,
+                    raw,
+                }
+            }
+        };
+
+        let field = |keys: &[&str]| {
+            keys.iter()
+                .filter_map(|key| json.get(*key))
+                .find(|value| !value.is_null())
+                .map(|value| match value {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+        };
+
+        Self {
+            status,
+            error: field(&["error", "errorMsg", "message"]).unwrap_or_else(|| raw.clone()),
+            code: field(&["code", "errorCode"]),
+            raw,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error (status {}): {}", self.status, self.error)?;
+        if let Some(ref code) = self.code {
+            write!(f, " (code {})", code)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Reason the relayer gave for rejecting a transaction submission
+///
+/// Known failure modes are recognized from the message and code of the
+/// relayer's error body; anything else is kept as [`RelayerError::Raw`].
+/// Each known variant holds the relayer's message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayerError {
+    /// The nonce was already used; fetch a fresh nonce and sign again
+    NonceTooLow(String),
+    /// The signature does not match the transaction or its signer
+    InvalidSignature(String),
+    /// The Safe wallet must be deployed before it can execute transactions
+    SafeNotDeployed(String),
+    /// The relayer has no budget left to pay for the transaction
+    InsufficientBudget(String),
+    /// Unrecognized error, with the raw response body
+    Raw(String),
+}
+
+impl RelayerError {
+    /// Parse a relayer error response body
+    pub fn from_body(body: &str) -> Self {
+        let parsed = ApiError::from_body(0, body);
+        let reason = format!(
+            "{} {}",
+            parsed.error,
+            parsed.code.as_deref().unwrap_or_default()
+        )
+        .to_lowercase()
+        .replace('_', " ");
+
+        // A missing Safe also fails signature checks, so test for it first
+        if reason.contains("not deployed") || reason.contains("safe does not exist") {
+            RelayerError::SafeNotDeployed(parsed.error)
+        } else if is_stale_nonce(&reason) {
+            RelayerError::NonceTooLow(parsed.error)
+        } else if reason.contains("signature") {
+            RelayerError::InvalidSignature(parsed.error)
+        } else if reason.contains("budget") || reason.contains("quota") {
+            RelayerError::InsufficientBudget(parsed.error)
+        } else {
+            RelayerError::Raw(parsed.raw)
+        }
+    }
+}
+
+/// Whether a lowercased relayer error says the nonce was already used
+///
+/// Other nonce problems, e.g. a nonce that is too high or malformed, are not
+/// fixed by refetching the nonce and stay [`RelayerError::Raw`].
+fn is_stale_nonce(reason: &str) -> bool {
+    reason.contains("nonce")
+        && ["too low", "already used", "already been used", "nonce used"]
+            .iter()
+            .any(|wording| reason.contains(wording))
+}
+
+impl fmt::Display for RelayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelayerError::NonceTooLow(msg) => write!(f, "nonce too low: {}", msg),
+            RelayerError::InvalidSignature(msg) => write!(f, "invalid signature: {}", msg),
+            RelayerError::SafeNotDeployed(msg) => write!(f, "Safe not deployed: {}", msg),
+            RelayerError::InsufficientBudget(msg) => {
+                write!(f, "insufficient relayer budget: {}", msg)
+            }
+            RelayerError::Raw(body) => write!(f, "{}", body.trim()),
+        }
+    }
+}
+
+impl std::error::Error for RelayerError {}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::Http(e) => Some(e),
+            Error::Timeout(e) => Some(e),
+            Error::Serialization(e) => Some(e),
+            Error::Deserialization { error, .. } => Some(error),
+            #[allow(deprecated)]
             Error::Json(e) => Some(e),
+            Error::Api(e) => Some(e),
+            Error::Relayer { error, .. } => Some(error),
             Error::Decimal(e) => Some(e),
             _ => None,
         }
@@ -91,13 +342,17 @@ impl std::error::Error for Error {
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        Error::Http(err)
+        if err.is_timeout() {
+            Error::Timeout(err)
+        } else {
+            Error::Http(err)
+        }
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Self {
-        Error::Json(err)
+        Error::Serialization(err)
     }
 }
 
@@ -113,8 +368,282 @@ impl From<alloy_signer::Error> for Error {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl From<tokio_tungstenite::tungstenite::Error> for Error {
     fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
         Error::WebSocket(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_from_json_body() {
+        let body = r#"{"error":"not enough balance / allowance","code":"INSUFFICIENT_BALANCE"}"#;
+        let error = ApiError::from_body(400, body);
+
+        assert_eq!(error.status, 400);
+        assert_eq!(error.error, "not enough balance / allowance");
+        assert_eq!(error.code.as_deref(), Some("INSUFFICIENT_BALANCE"));
+        assert_eq!(error.raw, body);
+    }
+
+    #[test]
+    fn test_api_error_from_alternate_json_shapes() {
+        let error = ApiError::from_body(400, r#"{"errorMsg":"invalid order","code":1001}"#);
+        assert_eq!(error.error, "invalid order");
+        assert_eq!(error.code.as_deref(), Some("1001"));
+
+        let error = ApiError::from_body(429, r#"{"message":"Too Many Requests"}"#);
+        assert_eq!(error.error, "Too Many Requests");
+        assert_eq!(error.code, None);
+
+        // JSON without a known message field keeps the whole body
+        let error = ApiError::from_body(500, r#"{"detail":"oops"}"#);
+        assert_eq!(error.error, r#"{"detail":"oops"}"#);
+    }
+
+    #[test]
+    fn test_api_error_from_plain_text_body() {
+        let error = ApiError::from_body(502, "Bad Gateway\n");
+
+        assert_eq!(error.error, "Bad Gateway");
+        assert_eq!(error.code, None);
+        assert_eq!(error.raw, "Bad Gateway\n");
+        assert_eq!(
+            Error::Api(error).to_string(),
+            "API error (status 502): Bad Gateway"
+        );
+    }
+
+    #[test]
+    fn test_relayer_error_from_body() {
+        let cases = [
+            (
+                r#"{"error":"invalid nonce: nonce too low"}"#,
+                RelayerError::NonceTooLow("invalid nonce: nonce too low".to_string()),
+            ),
+            (
+                r#"{"error":"rejected","code":"NONCE_ALREADY_USED"}"#,
+                RelayerError::NonceTooLow("rejected".to_string()),
+            ),
+            (
+                r#"{"error":"invalid nonce format"}"#,
+                RelayerError::Raw(r#"{"error":"invalid nonce format"}"#.to_string()),
+            ),
+            (
+                r#"{"error":"nonce too high"}"#,
+                RelayerError::Raw(r#"{"error":"nonce too high"}"#.to_string()),
+            ),
+            (
+                r#"{"error":"invalid signature"}"#,
+                RelayerError::InvalidSignature("invalid signature".to_string()),
+            ),
+            (
+                r#"{"message":"Signature does not match signer"}"#,
+                RelayerError::InvalidSignature("Signature does not match signer".to_string()),
+            ),
+            (
+                r#"{"error":"safe not deployed"}"#,
+                RelayerError::SafeNotDeployed("safe not deployed".to_string()),
+            ),
+            (
+                r#"{"error":"insufficient relayer budget for builder"}"#,
+                RelayerError::InsufficientBudget(
+                    "insufficient relayer budget for builder".to_string(),
+                ),
+            ),
+            (
+                r#"{"error":"daily quota exceeded"}"#,
+                RelayerError::InsufficientBudget("daily quota exceeded".to_string()),
+            ),
+            (
+                r#"{"error":"safe already deployed"}"#,
+                RelayerError::Raw(r#"{"error":"safe already deployed"}"#.to_string()),
+            ),
+            (
+                "Bad Gateway\n",
+                RelayerError::Raw("Bad Gateway\n".to_string()),
+            ),
+        ];
+
+        for (body, expected) in cases {
+            assert_eq!(RelayerError::from_body(body), expected, "{}", body);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let cases = [
+            (
+                Error::Config("bad host".to_string()),
+                "Configuration error: bad host",
+            ),
+            (
+                Error::AuthRequired("API credentials".to_string()),
+                "Authentication required: API credentials",
+            ),
+            (
+                Error::Signing("no key".to_string()),
+                "Signing error: no key",
+            ),
+            (
+                Error::InvalidParameter("limit".to_string()),
+                "Invalid parameter: limit",
+            ),
+            (
+                Error::MissingField("tick_size".to_string()),
+                "Missing required field: tick_size",
+            ),
+            (
+                Error::RateLimited {
+                    retry_after: Some(Duration::from_secs(2)),
+                },
+                "Rate limited, retry after 2s",
+            ),
+            (Error::ConnectionClosed, "WebSocket connection closed"),
+            (
+                Error::ReconnectFailed {
+                    attempts: 3,
+                    last_error: "refused".to_string(),
+                },
+                "Reconnection failed after 3 attempts: refused",
+            ),
+            (
+                Error::TransactionTimeout {
+                    transaction_id: "tx1".to_string(),
+                    polls: 5,
+                },
+                "Transaction tx1 not finished after 5 polls",
+            ),
+            (
+                Error::DuplicatePending {
+                    nonce: Some("4".to_string()),
+                },
+                "An identical transaction (nonce 4) was already submitted and its outcome is unknown",
+            ),
+            (
+                Error::NonceGap {
+                    nonce: 6,
+                    transaction_id: "tx2".to_string(),
+                },
+                "Transaction tx2 is queued behind unused nonce 6",
+            ),
+            (Error::Cancelled, "Operation cancelled"),
+            (
+                Error::Relayer {
+                    status: 400,
+                    error: RelayerError::NonceTooLow("nonce too low".to_string()),
+                },
+                "Relayer error (status 400): nonce too low: nonce too low",
+            ),
+            (
+                Error::Relayer {
+                    status: 502,
+                    error: RelayerError::Raw("Bad Gateway\n".to_string()),
+                },
+                "Relayer error (status 502): Bad Gateway",
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_source_chains_to_wrapped_error() {
+        use std::error::Error as _;
+
+        let json_error = serde_json::from_str::<u32>("\"x\"").unwrap_err();
+        let message = json_error.to_string();
+        let error = Error::deserialization(json_error, "\"x\"");
+        assert!(matches!(error, Error::Deserialization { .. }));
+        assert_eq!(error.source().unwrap().to_string(), message);
+
+        let error = Error::Api(ApiError::new(500, "oops"));
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "API error (status 500): oops"
+        );
+
+        assert!(Error::Config("x".to_string()).source().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reqwest_error_converts_with_question_mark() {
+        use std::error::Error as _;
+
+        async fn send() -> Result<reqwest::Response> {
+            Ok(reqwest::get("not a url").await?)
+        }
+
+        let error = send().await.unwrap_err();
+        assert!(matches!(error, Error::Http(_)));
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+    }
+
+    #[test]
+    fn test_deserialization_error_keeps_body_and_field() {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Price {
+            price: f64,
+        }
+
+        let body = r#"{"price":{"value":"0.5"}}"#;
+        let error = serde_json::from_str::<Price>(body)
+            .map_err(|e| Error::deserialization(e, body))
+            .unwrap_err();
+
+        match &error {
+            Error::Deserialization { error, body: kept } => {
+                assert_eq!(kept, body);
+                assert!(error.is_data());
+                assert_eq!(error.line(), 1);
+            }
+            other => panic!("expected Deserialization, got {:?}", other),
+        }
+        assert!(error
+            .to_string()
+            .starts_with("Failed to parse response: invalid type"));
+
+        let long = "x".repeat(MAX_ERROR_BODY_LEN + 50);
+        let json_error = serde_json::from_str::<Price>(&long).unwrap_err();
+        match Error::deserialization(json_error, &long) {
+            Error::Deserialization { body, .. } => {
+                assert_eq!(body.len(), MAX_ERROR_BODY_LEN + 3);
+                assert!(body.ends_with("..."));
+            }
+            other => panic!("expected Deserialization, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_serialization_error_from_question_mark() {
+        fn encode() -> Result<String> {
+            let mut map = std::collections::HashMap::new();
+            map.insert(vec![1u8], 1);
+            Ok(serde_json::to_string(&map)?)
+        }
+
+        let error = encode().unwrap_err();
+        assert!(matches!(error, Error::Serialization(_)));
+        assert!(error.to_string().starts_with("Failed to serialize request"));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_json_variant_still_usable() {
+        use std::error::Error as _;
+
+        let json_error = serde_json::from_str::<u32>("x").unwrap_err();
+        let message = json_error.to_string();
+        let error = Error::Json(json_error);
+        assert_eq!(error.to_string(), format!("JSON error: {}", message));
+        assert_eq!(error.source().unwrap().to_string(), message);
+    }
+}
@@ -1,30 +1,95 @@
-use crate::error::{Error, Result};
-use reqwest::{Client, Response};
+use crate::config::{HttpClientConfig, RequestOptions, RetryPolicy};
+use crate::error::{ApiError, Error, Result};
+use crate::http::rate_limit::RateLimiter;
+use crate::utils::sleep;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// HTTP client wrapper for making API requests
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
     base_url: String,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl HttpClient {
+    /// Create a new HttpClient with the default configuration
+    ///
+    /// See [`HttpClientConfig::default`] for the default timeouts.
     pub fn new(base_url: impl Into<String>) -> Self {
+        let client = build_client(&HttpClientConfig::default())
+            // Only fails if the TLS backend cannot be initialized, same as `Client::new`
+            .unwrap_or_default();
+
         Self {
-            client: Client::new(),
+            client,
             base_url: base_url.into(),
+            retry_policy: None,
+            rate_limiter: None,
         }
     }
 
+    /// Create a new HttpClient with custom timeouts
+    pub fn with_config(base_url: impl Into<String>, config: HttpClientConfig) -> Result<Self> {
+        let client = build_client(&config)?;
+
+        Ok(Self {
+            client,
+            base_url: base_url.into(),
+            retry_policy: None,
+            rate_limiter: None,
+        })
+    }
+
+    /// Retry GET requests according to the given policy
+    ///
+    /// Only GET requests are retried, on 5xx responses and connection errors.
+    /// POST and DELETE requests are never retried since they are not idempotent.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Limit outgoing requests to `requests_per_second`
+    ///
+    /// Requests wait for the limiter instead of failing. The limiter is shared
+    /// with all clones of this client. A non-positive rate disables limiting.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.rate_limiter =
+            (requests_per_second > 0.0).then(|| RateLimiter::new(requests_per_second));
+        self
+    }
+
     /// Make a GET request
     pub async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.get_with_options(path, headers, &RequestOptions::default())
+            .await
+    }
+
+    /// Make a GET request with per-request options
+    ///
+    /// The timeout in `options` replaces the client's request timeout for this
+    /// request only, and applies to each retry attempt.
+    pub async fn get_with_options<T>(
+        &self,
+        path: &str,
+        headers: Option<HashMap<&str, String>>,
+        options: &RequestOptions,
+    ) -> Result<T>
     where
         T: DeserializeOwned,
     {
         let url = format!("{}{}", self.base_url, path);
+        log::debug!("GET {}", url);
         let mut request = self.client.get(&url);
 
         if let Some(headers) = headers {
@@ -32,11 +97,69 @@ impl HttpClient {
                 request = request.header(key, value);
             }
         }
+        for (key, value) in &options.extra_headers {
+            request = request.header(key, value);
+        }
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
 
-        let response = request.send().await?;
+        let response = match &self.retry_policy {
+            Some(policy) => self.send_with_retry(request, policy).await?,
+            None => self.send(request).await?,
+        };
         self.handle_response(response).await
     }
 
+    /// Send a request once the rate limiter allows it
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+        Ok(request.send().await?)
+    }
+
+    /// Send a request, retrying on 5xx responses and connection errors
+    async fn send_with_retry(
+        &self,
+        request: RequestBuilder,
+        policy: &RetryPolicy,
+    ) -> Result<Response> {
+        let mut delay = policy.initial_delay;
+        let mut attempt = 1;
+
+        loop {
+            // Requests with streaming bodies cannot be cloned, send them once
+            let Some(attempt_request) = request.try_clone() else {
+                return self.send(request).await;
+            };
+
+            let result = self.send(attempt_request).await;
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(Error::Http(e)) => is_connect_error(e),
+                Err(_) => false,
+            };
+
+            if !retryable || attempt >= policy.max_attempts {
+                return result;
+            }
+
+            log::warn!(
+                "Request failed (attempt {}/{}), retrying in {:?}",
+                attempt,
+                policy.max_attempts,
+                delay
+            );
+            sleep(delay).await;
+
+            // A huge, negative or NaN multiplier can't be a Duration; cap it instead
+            delay = Duration::try_from_secs_f64(delay.as_secs_f64() * policy.multiplier)
+                .map_or(policy.max_delay, |next| next.min(policy.max_delay));
+            attempt += 1;
+        }
+    }
+
     /// Make a POST request with JSON body
     pub async fn post<T, B>(
         &self,
@@ -49,6 +172,7 @@ impl HttpClient {
         B: Serialize,
     {
         let url = format!("{}{}", self.base_url, path);
+        log::debug!("POST {}", url);
         let mut request = self.client.post(&url).json(body);
 
         if let Some(headers) = headers {
@@ -57,7 +181,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
@@ -67,6 +191,7 @@ impl HttpClient {
         T: DeserializeOwned,
     {
         let url = format!("{}{}", self.base_url, path);
+        log::debug!("DELETE {}", url);
         let mut request = self.client.delete(&url);
 
         if let Some(headers) = headers {
@@ -75,7 +200,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
@@ -91,6 +216,7 @@ impl HttpClient {
         B: Serialize,
     {
         let url = format!("{}{}", self.base_url, path);
+        log::debug!("DELETE {}", url);
         let mut request = self.client.delete(&url).json(body);
 
         if let Some(headers) = headers {
@@ -99,7 +225,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
@@ -110,18 +236,283 @@ impl HttpClient {
     {
         let status = response.status();
 
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            // Only the delay-seconds form of Retry-After is supported
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+
+            return Err(Error::RateLimited { retry_after });
+        }
+
         if status.is_success() {
-            response.json().await.map_err(|e| e.into())
+            json_body(response).await
         } else {
-            let message = response
+            let body = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
 
-            Err(Error::Api {
-                status: status.as_u16(),
-                message,
+            Err(Error::Api(ApiError::from_body(status.as_u16(), body)))
+        }
+    }
+}
+
+/// Read a response body and parse it as JSON
+///
+/// Parse failures become [`Error::Deserialization`] carrying the start of the
+/// body, so unexpected server payloads can be told apart from transport errors.
+pub(crate) async fn json_body<T>(response: Response) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let body = response.text().await?;
+    serde_json::from_str(&body).map_err(|e| Error::deserialization(e, &body))
+}
+
+/// Build the underlying `reqwest::Client` with the configured timeouts
+///
+/// In the browser the fetch API manages connections and timeouts, so the
+/// configuration is not applied on wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+fn build_client(config: &HttpClientConfig) -> reqwest::Result<Client> {
+    Client::builder()
+        .connect_timeout(config.connect_timeout)
+        .timeout(config.request_timeout)
+        .pool_idle_timeout(config.pool_idle_timeout)
+        .build()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn build_client(_config: &HttpClientConfig) -> reqwest::Result<Client> {
+    Client::builder().build()
+}
+
+/// Returns true if the request failed before reaching the server
+fn is_connect_error(error: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    return error.is_connect();
+    #[cfg(target_arch = "wasm32")]
+    return error.is_request();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockResponse, MockServer};
+    use std::time::Instant;
+    use tokio::net::TcpListener;
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            multiplier: 2.0,
+            max_attempts: 3,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_on_server_error() {
+        let server = MockServer::start(vec![
+            (503, "unavailable".to_string()),
+            (503, "unavailable".to_string()),
+            (200, r#"{"ok":true}"#.to_string()),
+        ])
+        .await;
+        let client = HttpClient::new(server.url()).with_retry(fast_retry());
+
+        let result: serde_json::Value = client.get("/markets", None).await.unwrap();
+        assert_eq!(result["ok"], true);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests
+            .iter()
+            .all(|r| r.method == "GET" && r.path == "/markets"));
+    }
+
+    #[tokio::test]
+    async fn test_get_gives_up_after_max_attempts() {
+        let server = MockServer::start(vec![(502, "bad gateway".to_string()); 4]).await;
+        let client = HttpClient::new(server.url()).with_retry(fast_retry());
+
+        let result: Result<serde_json::Value> = client.get("/markets", None).await;
+        assert!(matches!(
+            result,
+            Err(Error::Api(ApiError { status: 502, .. }))
+        ));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_retry_survives_unbounded_multiplier() {
+        for multiplier in [f64::MAX, f64::INFINITY, f64::NAN, -1.0] {
+            let server = MockServer::start(vec![
+                (503, "unavailable".to_string()),
+                (503, "unavailable".to_string()),
+                (200, r#"{"ok":true}"#.to_string()),
+            ])
+            .await;
+            let client = HttpClient::new(server.url()).with_retry(RetryPolicy {
+                multiplier,
+                ..fast_retry()
+            });
+
+            let result: serde_json::Value = client.get("/markets", None).await.unwrap();
+            assert_eq!(result["ok"], true);
+            assert_eq!(server.requests().len(), 3);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_does_not_retry_client_error() {
+        let server = MockServer::start(vec![
+            (404, "not found".to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+        let client = HttpClient::new(server.url()).with_retry(fast_retry());
+
+        let result: Result<serde_json::Value> = client.get("/markets", None).await;
+        assert!(matches!(
+            result,
+            Err(Error::Api(ApiError { status: 404, .. }))
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_api_error_body_is_parsed() {
+        let body = r#"{"error":"not enough balance / allowance"}"#;
+        let server = MockServer::start(vec![(400, body.to_string())]).await;
+        let client = HttpClient::new(server.url());
+
+        let result: Result<serde_json::Value> =
+            client.post("/order", &serde_json::json!({}), None).await;
+        match result {
+            Err(Error::Api(e)) => {
+                assert_eq!(e.status, 400);
+                assert_eq!(e.error, "not enough balance / allowance");
+                assert_eq!(e.raw, body);
+            }
+            other => panic!("expected an API error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unexpected_json_maps_to_deserialization_error() {
+        let body = r#"{"mid":["0.5"]}"#;
+        let server = MockServer::start(vec![(200, body.to_string())]).await;
+        let client = HttpClient::new(server.url());
+
+        let result: Result<crate::types::MidpointResponse> = client.get("/midpoint", None).await;
+        match result {
+            Err(Error::Deserialization { error, body: kept }) => {
+                assert!(error.is_data());
+                assert_eq!(kept, body);
+            }
+            other => panic!("expected a deserialization error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried() {
+        let server = MockServer::start(vec![
+            (503, "unavailable".to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+        let client = HttpClient::new(server.url()).with_retry(fast_retry());
+
+        let result: Result<serde_json::Value> =
+            client.post("/order", &serde_json::json!({}), None).await;
+        assert!(matches!(
+            result,
+            Err(Error::Api(ApiError { status: 503, .. }))
+        ));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].header("content-type"), Some("application/json"));
+        assert_eq!(requests[0].body, "{}");
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_maps_to_rate_limited() {
+        let server = MockServer::start(vec![
+            MockResponse::from((429, "slow down".to_string())).with_header("Retry-After", "7"),
+            MockResponse::from((429, "slow down".to_string())),
+        ])
+        .await;
+        let client = HttpClient::new(server.url());
+
+        let result: Result<serde_json::Value> = client.get("/markets", None).await;
+        assert!(matches!(
+            result,
+            Err(Error::RateLimited {
+                retry_after: Some(d)
+            }) if d == Duration::from_secs(7)
+        ));
+
+        let result: Result<serde_json::Value> = client.get("/markets", None).await;
+        assert!(matches!(
+            result,
+            Err(Error::RateLimited { retry_after: None })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_shared_across_clones() {
+        let server = MockServer::start(vec![(200, "{}".to_string()); 30]).await;
+        let client_a = HttpClient::new(server.url()).with_rate_limit(20.0);
+        let client_b = client_a.clone();
+        let start = Instant::now();
+
+        // A burst of 30 requests from two clones: 20 pass immediately, 10 are paced
+        let handles: Vec<_> = (0..30)
+            .map(|i| {
+                let client = if i % 2 == 0 {
+                    client_a.clone()
+                } else {
+                    client_b.clone()
+                };
+                tokio::spawn(async move {
+                    let _: serde_json::Value = client.get("/markets", None).await.unwrap();
+                })
             })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
         }
+
+        assert!(start.elapsed() >= Duration::from_millis(450));
+        assert_eq!(server.requests().len(), 30);
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_maps_to_timeout_error() {
+        // Accept connections but never respond
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let config = HttpClientConfig {
+            request_timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let client = HttpClient::with_config(format!("http://{}", addr), config).unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/", None).await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
     }
 }
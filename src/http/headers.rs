@@ -1,9 +1,9 @@
 use crate::error::Result;
-use crate::signing::{sign_clob_auth_message, EthSigner};
+use crate::signing::{sign_clob_auth_message_async, EthSigner};
 use crate::types::ApiCreds;
 use crate::utils::{build_hmac_signature, get_current_unix_time_secs};
 use alloy_primitives::hex::encode_prefixed;
-use alloy_primitives::U256;
+use alloy_primitives::{Address, U256};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -19,15 +19,17 @@ pub type Headers = HashMap<&'static str, String>;
 /// Create L1 headers for authentication (EIP-712 based)
 ///
 /// These headers are used for operations that require wallet signature,
-/// such as creating API keys.
-pub fn create_l1_headers<S: EthSigner>(
+/// such as creating API keys. The signature goes through the async signer
+/// methods, so remote signers never block the runtime.
+pub async fn create_l1_headers<S: EthSigner + ?Sized>(
     signer: &S,
     chain_id: u64,
     nonce: Option<U256>,
 ) -> Result<Headers> {
     let timestamp = get_current_unix_time_secs()?.to_string();
     let nonce = nonce.unwrap_or(U256::ZERO);
-    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce, chain_id)?;
+    let signature =
+        sign_clob_auth_message_async(signer, timestamp.clone(), nonce, chain_id).await?;
     let address = encode_prefixed(signer.address().as_slice());
 
     Ok(HashMap::from([
@@ -52,14 +54,34 @@ pub fn create_l2_headers<S: EthSigner, T>(
 where
     T: ?Sized + Serialize,
 {
-    let address = encode_prefixed(signer.address().as_slice());
     let timestamp = get_current_unix_time_secs()?;
+    build_l2_headers(
+        api_creds,
+        timestamp,
+        method,
+        req_path,
+        body,
+        signer.address(),
+    )
+}
 
+/// Build L2 headers for a fixed timestamp and wallet address
+pub fn build_l2_headers<T>(
+    api_creds: &ApiCreds,
+    timestamp: u64,
+    method: &str,
+    req_path: &str,
+    body: Option<&T>,
+    address: Address,
+) -> Result<Headers>
+where
+    T: ?Sized + Serialize,
+{
     let hmac_signature =
         build_hmac_signature(&api_creds.secret, timestamp, method, req_path, body)?;
 
     Ok(HashMap::from([
-        (POLY_ADDR_HEADER, address),
+        (POLY_ADDR_HEADER, encode_prefixed(address.as_slice())),
         (POLY_SIG_HEADER, hmac_signature),
         (POLY_TS_HEADER, timestamp.to_string()),
         (POLY_API_KEY_HEADER, api_creds.api_key.clone()),
@@ -71,6 +93,82 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_l2_headers_with_body() {
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "c2VjcmV0c2VjcmV0c2VjcmV0".to_string(),
+            "pass".to_string(),
+        );
+        let body = serde_json::json!({ "orderID": "0xabc" });
+
+        let headers = create_l2_headers(&signer, &creds, "DELETE", "/order", Some(&body)).unwrap();
+
+        assert_eq!(headers.len(), 5);
+        assert_eq!(
+            headers[POLY_ADDR_HEADER],
+            encode_prefixed(signer.address().as_slice())
+        );
+        assert_eq!(headers[POLY_API_KEY_HEADER], "key");
+        assert_eq!(headers[POLY_PASS_HEADER], "pass");
+
+        let timestamp: u64 = headers[POLY_TS_HEADER].parse().unwrap();
+        let expected =
+            build_hmac_signature(&creds.secret, timestamp, "DELETE", "/order", Some(&body))
+                .unwrap();
+        assert_eq!(headers[POLY_SIG_HEADER], expected);
+    }
+
+    #[test]
+    fn test_api_creds_l2_headers_known_vector() {
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string(),
+            "pass".to_string(),
+        );
+        let address: Address = "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5"
+            .parse()
+            .unwrap();
+        let body = HashMap::from([("hash", "0x123")]);
+
+        let headers = creds
+            .l2_headers_at(1000000, "test-sign", "/orders", Some(&body), address)
+            .unwrap();
+
+        // Message: 1000000test-sign/orders{"hash":"0x123"}
+        assert_eq!(
+            headers[POLY_SIG_HEADER],
+            "4gJVbox-R6XlDK4nlaicig0_ANVL1qdcahiL8CXfXLM="
+        );
+        assert_eq!(
+            headers[POLY_ADDR_HEADER],
+            "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5"
+        );
+        assert_eq!(headers[POLY_TS_HEADER], "1000000");
+        assert_eq!(headers[POLY_API_KEY_HEADER], "key");
+        assert_eq!(headers[POLY_PASS_HEADER], "pass");
+    }
+
+    #[test]
+    fn test_api_creds_l2_headers_without_body() {
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string(),
+            "pass".to_string(),
+        );
+
+        let headers = creds
+            .l2_headers_at::<()>(1000000, "GET", "/data/orders", None, Address::ZERO)
+            .unwrap();
+
+        // Message: 1000000GET/data/orders
+        assert_eq!(
+            headers[POLY_SIG_HEADER],
+            "9-I3DmVY9ObJ6EVf_KvrHpUWMOQJXiAjR5z7fO8qnPw="
+        );
+    }
+
     #[test]
     fn test_header_constants() {
         assert_eq!(POLY_ADDR_HEADER, "POLY_ADDRESS");
@@ -1,5 +1,10 @@
 mod client;
 mod headers;
+mod rate_limit;
+mod transport;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use client::json_body;
 pub use client::HttpClient;
-pub use headers::{create_l1_headers, create_l2_headers};
+pub use headers::{build_l2_headers, create_l1_headers, create_l2_headers};
+pub use transport::HttpTransport;
@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::utils::sleep;
+
+/// Longest single wait before the bucket is checked again
+///
+/// Keeps tiny rates from asking the timer for a sleep it can't represent.
+const MAX_WAIT: Duration = Duration::from_secs(3600);
+
+/// Token bucket rate limiter
+///
+/// Cloning the limiter shares the underlying bucket, so all clones are
+/// governed by the same limit.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    refill_rate: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_second` requests, with bursts of up to one second
+    pub fn new(requests_per_second: f64) -> Self {
+        let capacity = requests_per_second.max(1.0);
+        Self {
+            bucket: Arc::new(Mutex::new(TokenBucket {
+                tokens: capacity,
+                capacity,
+                refill_rate: requests_per_second,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Wait until a request is allowed
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+                bucket.refill();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+
+                // A tiny or zero rate can't be a Duration; wait the longest instead
+                Duration::try_from_secs_f64((1.0 - bucket.tokens) / bucket.refill_rate)
+                    .map_or(MAX_WAIT, |wait| wait.min(MAX_WAIT))
+            };
+
+            sleep(wait).await;
+        }
+    }
+}
+
+impl TokenBucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_within_capacity_is_immediate() {
+        let limiter = RateLimiter::new(10.0);
+        let start = Instant::now();
+
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_burst_over_capacity_is_paced() {
+        let limiter = RateLimiter::new(20.0);
+        let start = Instant::now();
+
+        // 20 requests are allowed immediately, the next 10 take 0.5s
+        for _ in 0..30 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(450));
+    }
+
+    #[tokio::test]
+    async fn test_tiny_rate_waits_instead_of_panicking() {
+        for rate in [1e-20, 0.0] {
+            let limiter = RateLimiter::new(rate);
+            limiter.acquire().await;
+
+            let second = tokio::time::timeout(Duration::from_millis(50), limiter.acquire());
+            assert!(second.await.is_err(), "rate {}", rate);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_bucket() {
+        let limiter = RateLimiter::new(20.0);
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..30)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(450));
+    }
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use super::client::HttpClient;
+use crate::config::RequestOptions;
+use crate::error::Result;
+
+/// Sends API requests and decodes their JSON responses
+///
+/// [`HttpClient`] is the network implementation. The public data clients
+/// ([`GammaClient`](crate::client::GammaClient) and
+/// [`DataClient`](crate::client::DataClient)) are generic over this trait, so a
+/// fake transport returning canned responses can stand in for the network in
+/// tests.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait HttpTransport: Send + Sync {
+    /// Make a GET request
+    async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned;
+
+    /// Make a GET request with per-request options
+    ///
+    /// The default implementation sends the extra headers through
+    /// [`get`](Self::get) and ignores the timeout; [`HttpClient`] applies both.
+    async fn get_with_options<T>(
+        &self,
+        path: &str,
+        headers: Option<HashMap<&str, String>>,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut headers = headers.unwrap_or_default();
+        for (key, value) in &options.extra_headers {
+            headers.insert(key.as_str(), value.clone());
+        }
+        self.get(path, (!headers.is_empty()).then_some(headers))
+            .await
+    }
+
+    /// Make a POST request with a JSON body
+    async fn post<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + Sync;
+
+    /// Make a DELETE request
+    async fn delete<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HttpTransport for HttpClient {
+    async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        HttpClient::get(self, path, headers).await
+    }
+
+    async fn get_with_options<T>(
+        &self,
+        path: &str,
+        headers: Option<HashMap<&str, String>>,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        HttpClient::get_with_options(self, path, headers, options).await
+    }
+
+    async fn post<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + Sync,
+    {
+        HttpClient::post(self, path, body, headers).await
+    }
+
+    async fn delete<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        HttpClient::delete(self, path, headers).await
+    }
+}
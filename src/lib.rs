@@ -19,39 +19,61 @@
 //! - **Decimal Precision**: Accurate decimal math for prices and amounts
 //! - **Relayer Client**: Gasless transactions through Polymarket's relayer infrastructure
 //!
+//! ## Logging
+//!
+//! Diagnostics go through the [`log`](https://docs.rs/log) facade and are silent
+//! unless the application installs a logger (e.g. `env_logger`). Request URLs,
+//! including user addresses in query strings, are logged at `debug` level;
+//! retries and reconnects at `warn`.
+//!
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 requires the `wasm` feature");
 
 // Public modules
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
 pub mod client;
 pub mod config;
 pub mod error;
 pub mod orders;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod relayer;
 pub mod request;
 pub mod signing;
 pub mod types;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod websocket;
 
 // Internal modules
 mod http;
 mod utils;
 
+#[cfg(test)]
+mod test_utils;
+
 // Re-export commonly used types
-pub use alloy_primitives::Address;
+pub use alloy_primitives::{Address, U256};
 pub use alloy_signer::k256;
 pub use alloy_signer_local::PrivateKeySigner;
-pub use error::{Error, Result};
+pub use error::{ApiError, Error, RelayerError, Result};
 pub use types::{
     ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
     OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
 };
 
+// Re-export HTTP client configuration
+pub use config::{HttpClientConfig, RequestOptions, RetryPolicy};
+
 // Re-export clients
 pub use client::{AuthenticatedClient, ClobClient, DataClient, GammaClient, TradingClient};
 
 // Re-export websocket clients
+#[cfg(not(target_arch = "wasm32"))]
 pub use websocket::{MarketWsClient, UserWsClient};
 
 // Re-export relayer client
+#[cfg(not(target_arch = "wasm32"))]
 pub use relayer::{BuilderApiCreds, RelayerClient};
 
 // Re-export order builder
@@ -59,6 +81,8 @@ pub use orders::OrderBuilder;
 
 // Re-export signer trait
 pub use signing::EthSigner;
+#[cfg(not(target_arch = "wasm32"))]
+pub use signing::RemoteSigner;
 
 // Re-export stream extension traits
 pub use futures_util::StreamExt;
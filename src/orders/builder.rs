@@ -2,16 +2,19 @@ use super::rounding::{decimal_to_token_u64, ROUNDING_CONFIG};
 use crate::config::get_contract_config;
 use crate::error::{Error, Result};
 use crate::orders::RoundConfig;
-use crate::signing::{sign_order_message, EthSigner, Order};
+use crate::signing::{
+    order_signing_hash, sign_order_message, sign_order_message_async, EthSigner, Order,
+};
 use crate::types::{
     CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, Side, SignatureType,
     SignedOrderRequest,
 };
 use crate::utils::get_current_unix_time_secs;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, B256, U256};
 use rand::{thread_rng, Rng};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::ToZero;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Generate a random seed for order salt
@@ -28,11 +31,27 @@ pub struct OrderBuilder {
     signer: Box<dyn EthSigner>,
     sig_type: SignatureType,
     funder: Address,
+    exchanges: HashMap<u64, ExchangeAddresses>,
+}
+
+/// Standard and neg risk exchange contracts for one chain
+///
+/// The exchange address is the `verifyingContract` of the EIP-712 domain
+/// orders are signed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeAddresses {
+    pub exchange: Address,
+    pub neg_risk_exchange: Address,
 }
 
 impl OrderBuilder {
     /// Create a new OrderBuilder
     ///
+    /// Orders are always signed over their EIP-712 hash by `signer`, whatever the
+    /// signature type. For `PolyProxy` and `PolyGnosisSafe` the exchange checks that
+    /// the signer owns the `funder` wallet, so `funder` must be set to the proxy or
+    /// Safe address holding the funds.
+    ///
     /// # Arguments
     /// * `signer` - The Ethereum signer to use for signing orders
     /// * `sig_type` - The signature type (defaults to EOA if None)
@@ -50,9 +69,37 @@ impl OrderBuilder {
             signer: Box::new(signer),
             sig_type,
             funder,
+            exchanges: HashMap::new(),
         }
     }
 
+    /// Sign orders for `chain_id` against the given exchange contracts
+    ///
+    /// Overrides the addresses from [`get_contract_config`], e.g. for a chain
+    /// without built-in configuration or a redeployed exchange.
+    pub fn with_exchange_addresses(mut self, chain_id: u64, addresses: ExchangeAddresses) -> Self {
+        self.exchanges.insert(chain_id, addresses);
+        self
+    }
+
+    /// Exchange contract that orders for `chain_id` are signed against
+    ///
+    /// Neg risk markets trade on their own exchange, so `neg_risk` selects
+    /// between the standard and neg risk contracts.
+    pub fn exchange_address(&self, chain_id: u64, neg_risk: bool) -> Result<Address> {
+        if let Some(addresses) = self.exchanges.get(&chain_id) {
+            return Ok(if neg_risk {
+                addresses.neg_risk_exchange
+            } else {
+                addresses.exchange
+            });
+        }
+
+        let contract_config = get_contract_config(chain_id, neg_risk)?;
+        Address::from_str(&contract_config.exchange)
+            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))
+    }
+
     /// Get the signature type as u8
     pub fn get_sig_type(&self) -> u8 {
         self.sig_type.to_u8()
@@ -63,6 +110,7 @@ impl OrderBuilder {
     /// For buy orders:
     /// - maker_amount (USDC) supports max 2 decimals
     /// - taker_amount (outcome tokens) supports max 4 decimals
+    ///
     /// For sell orders:
     /// - maker_amount (outcome tokens) supports max 4 decimals
     /// - taker_amount (USDC) supports max 2 decimals
@@ -103,6 +151,7 @@ impl OrderBuilder {
     /// For market buy orders:
     /// - maker_amount (USDC) supports max 2 decimals
     /// - taker_amount (outcome tokens) supports max 4 decimals
+    ///
     /// For market sell orders:
     /// - maker_amount (outcome tokens) supports max 4 decimals
     /// - taker_amount (USDC) supports max 2 decimals
@@ -149,6 +198,129 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        let (order, exchange) = self.market_order(chain_id, order_args, price, extras, &options)?;
+        let signature = sign_order_message(&self.signer, order.clone(), chain_id, exchange)?;
+
+        Ok(self.signed_order_request(order, &order_args.token_id, signature, &options))
+    }
+
+    /// Create a market order, signing without blocking
+    ///
+    /// Same order as [`create_market_order`](Self::create_market_order), signed
+    /// through the async signer methods so remote signers work on any runtime.
+    pub async fn create_market_order_async(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let (order, exchange) = self.market_order(chain_id, order_args, price, extras, &options)?;
+        let signature =
+            sign_order_message_async(&*self.signer, order.clone(), chain_id, exchange).await?;
+
+        Ok(self.signed_order_request(order, &order_args.token_id, signature, &options))
+    }
+
+    /// Create a limit order
+    ///
+    /// Limit orders are executed at a specific price or better.
+    pub fn create_order(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let (order, exchange) =
+            self.limit_order(chain_id, order_args, expiration, extras, &options)?;
+        let signature = sign_order_message(&self.signer, order.clone(), chain_id, exchange)?;
+
+        Ok(self.signed_order_request(order, &order_args.token_id, signature, &options))
+    }
+
+    /// Create a limit order, signing without blocking
+    ///
+    /// Same order as [`create_order`](Self::create_order), signed through the
+    /// async signer methods so remote signers work on any runtime.
+    pub async fn create_order_async(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let (order, exchange) =
+            self.limit_order(chain_id, order_args, expiration, extras, &options)?;
+        let signature =
+            sign_order_message_async(&*self.signer, order.clone(), chain_id, exchange).await?;
+
+        Ok(self.signed_order_request(order, &order_args.token_id, signature, &options))
+    }
+
+    /// Compute the EIP-712 hash of a signed order
+    ///
+    /// This is the digest covered by `order.signature` and the order hash used
+    /// by the exchange.
+    ///
+    /// # Arguments
+    /// * `order` - The signed order
+    /// * `chain_id` - The chain ID the order was signed for
+    /// * `neg_risk` - Whether the order targets the neg risk exchange
+    pub fn order_hash(
+        &self,
+        order: &SignedOrderRequest,
+        chain_id: u64,
+        neg_risk: bool,
+    ) -> Result<B256> {
+        let exchange_address = self.exchange_address(chain_id, neg_risk)?;
+
+        let parse_address = |field: &str, value: &str| {
+            Address::from_str(value)
+                .map_err(|e| Error::InvalidParameter(format!("Invalid {}: {}", field, e)))
+        };
+        let parse_u256 = |field: &str, value: &str| {
+            U256::from_str_radix(value, 10)
+                .map_err(|e| Error::InvalidParameter(format!("Invalid {}: {}", field, e)))
+        };
+        let side = match order.side.as_str() {
+            "BUY" => Side::Buy,
+            "SELL" => Side::Sell,
+            other => {
+                return Err(Error::InvalidParameter(format!("Invalid side: {}", other)));
+            }
+        };
+
+        let order = Order {
+            salt: U256::from(order.salt),
+            maker: parse_address("maker", &order.maker)?,
+            signer: parse_address("signer", &order.signer)?,
+            taker: parse_address("taker", &order.taker)?,
+            tokenId: parse_u256("token_id", &order.token_id)?,
+            makerAmount: parse_u256("maker_amount", &order.maker_amount)?,
+            takerAmount: parse_u256("taker_amount", &order.taker_amount)?,
+            expiration: parse_u256("expiration", &order.expiration)?,
+            nonce: parse_u256("nonce", &order.nonce)?,
+            feeRateBps: parse_u256("fee_rate_bps", &order.fee_rate_bps)?,
+            side: side.to_u8(),
+            signatureType: order.signature_type,
+        };
+
+        Ok(order_signing_hash(&order, chain_id, exchange_address))
+    }
+
+    /// Compute the unsigned market order and the exchange it is signed for
+    fn market_order(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: &CreateOrderOptions,
+    ) -> Result<(Order, Address)> {
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -164,34 +336,30 @@ impl OrderBuilder {
         let (maker_amount, taker_amount) =
             self.get_market_order_amounts(order_args.side, order_args.amount, price, round_config);
 
-        let contract_config = get_contract_config(chain_id, neg_risk)?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = self.exchange_address(chain_id, neg_risk)?;
 
-        self.build_signed_order(
-            order_args.token_id.clone(),
+        let order = self.build_order(
+            &order_args.token_id,
             order_args.side,
-            chain_id,
-            exchange_address,
             maker_amount,
             taker_amount,
             0, // Market orders have 0 expiration
             extras,
-        )
+            options,
+        )?;
+
+        Ok((order, exchange_address))
     }
 
-    /// Create a limit order
-    ///
-    /// Limit orders are executed at a specific price or better.
-    pub fn create_order(
+    /// Compute the unsigned limit order and the exchange it is signed for
+    fn limit_order(
         &self,
         chain_id: u64,
         order_args: &OrderArgs,
         expiration: u64,
         extras: &ExtraOrderArgs,
-        options: CreateOrderOptions,
-    ) -> Result<SignedOrderRequest> {
+        options: &CreateOrderOptions,
+    ) -> Result<(Order, Address)> {
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -211,44 +379,53 @@ impl OrderBuilder {
             round_config,
         );
 
-        let contract_config = get_contract_config(chain_id, neg_risk)?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = self.exchange_address(chain_id, neg_risk)?;
 
-        self.build_signed_order(
-            order_args.token_id.clone(),
+        let order = self.build_order(
+            &order_args.token_id,
             order_args.side,
-            chain_id,
-            exchange_address,
             maker_amount,
             taker_amount,
             expiration,
             extras,
-        )
+            options,
+        )?;
+
+        Ok((order, exchange_address))
     }
 
-    /// Build and sign an order
+    /// Build an unsigned order
     #[allow(clippy::too_many_arguments)]
-    fn build_signed_order(
+    fn build_order(
         &self,
-        token_id: String,
+        token_id: &str,
         side: Side,
-        chain_id: u64,
-        exchange: Address,
         maker_amount: u64,
         taker_amount: u64,
         expiration: u64,
         extras: &ExtraOrderArgs,
-    ) -> Result<SignedOrderRequest> {
-        let seed = generate_seed()?;
+        options: &CreateOrderOptions,
+    ) -> Result<Order> {
+        if let Some(max_fee_rate_bps) = options.max_fee_rate_bps {
+            if extras.fee_rate_bps > max_fee_rate_bps {
+                return Err(Error::InvalidOrder(format!(
+                    "Fee rate {} bps exceeds the market maximum of {} bps",
+                    extras.fee_rate_bps, max_fee_rate_bps
+                )));
+            }
+        }
+
+        let seed = match options.salt {
+            Some(salt) => salt,
+            None => generate_seed()?,
+        };
         let taker_address = Address::from_str(&extras.taker)
             .map_err(|e| Error::InvalidParameter(format!("Invalid taker address: {}", e)))?;
 
-        let u256_token_id = U256::from_str_radix(&token_id, 10)
+        let u256_token_id = U256::from_str_radix(token_id, 10)
             .map_err(|e| Error::InvalidParameter(format!("Invalid token_id: {}", e)))?;
 
-        let order = Order {
+        Ok(Order {
             salt: U256::from(seed),
             maker: self.funder,
             signer: self.signer.address(),
@@ -261,25 +438,39 @@ impl OrderBuilder {
             feeRateBps: U256::from(extras.fee_rate_bps),
             side: side.to_u8(),
             signatureType: self.sig_type.to_u8(),
+        })
+    }
+
+    /// Serialize a signed order for the API
+    fn signed_order_request(
+        &self,
+        order: Order,
+        token_id: &str,
+        signature: String,
+        options: &CreateOrderOptions,
+    ) -> SignedOrderRequest {
+        let side = if order.side == Side::Buy.to_u8() {
+            Side::Buy
+        } else {
+            Side::Sell
         };
 
-        let signature = sign_order_message(&self.signer, order, chain_id, exchange)?;
-
-        Ok(SignedOrderRequest {
-            salt: seed,
-            maker: self.funder.to_checksum(None),
-            signer: self.signer.address().to_checksum(None),
-            taker: taker_address.to_checksum(None),
-            token_id,
-            maker_amount: maker_amount.to_string(),
-            taker_amount: taker_amount.to_string(),
-            expiration: expiration.to_string(),
-            nonce: extras.nonce.to_string(),
-            fee_rate_bps: extras.fee_rate_bps.to_string(),
+        SignedOrderRequest {
+            salt: order.salt.to::<u64>(),
+            maker: order.maker.to_checksum(None),
+            signer: order.signer.to_checksum(None),
+            taker: order.taker.to_checksum(None),
+            token_id: token_id.to_string(),
+            maker_amount: order.makerAmount.to_string(),
+            taker_amount: order.takerAmount.to_string(),
+            expiration: order.expiration.to_string(),
+            nonce: order.nonce.to_string(),
+            fee_rate_bps: order.feeRateBps.to_string(),
             side: side.as_str().to_string(),
-            signature_type: self.sig_type.to_u8(),
+            signature_type: order.signatureType,
             signature,
-        })
+            defer_exec: options.defer_exec,
+        }
     }
 }
 
@@ -326,4 +517,405 @@ mod tests {
         assert_eq!(maker_amount, 30_000_000);
         assert_eq!(taker_amount, 27_000_000);
     }
+
+    fn market_amounts(tick_size: &str, side: Side, amount: &str, price: &str) -> (u64, u64) {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let round_config = ROUNDING_CONFIG
+            .get(&Decimal::from_str(tick_size).unwrap())
+            .unwrap();
+
+        builder.get_market_order_amounts(
+            side,
+            Decimal::from_str(amount).unwrap(),
+            Decimal::from_str(price).unwrap(),
+            round_config,
+        )
+    }
+
+    #[test]
+    fn test_market_buy_rounding_tick_0_1() {
+        // Price 0.56 rounds down to 0.5, $10.129 rounds down to $10.12
+        // 10.12 / 0.5 = 20.24 shares
+        assert_eq!(
+            market_amounts("0.1", Side::Buy, "10.129", "0.56"),
+            (10_120_000, 20_240_000)
+        );
+    }
+
+    #[test]
+    fn test_market_buy_rounding_tick_0_01() {
+        // Price 0.567 rounds down to 0.56, 10 / 0.56 = 17.857142... -> 17.8571 shares
+        assert_eq!(
+            market_amounts("0.01", Side::Buy, "10", "0.567"),
+            (10_000_000, 17_857_100)
+        );
+    }
+
+    #[test]
+    fn test_market_buy_rounding_tick_0_001() {
+        // Price 0.5678 rounds down to 0.567, 10 / 0.567 = 17.636684... -> 17.6366 shares
+        assert_eq!(
+            market_amounts("0.001", Side::Buy, "10", "0.5678"),
+            (10_000_000, 17_636_600)
+        );
+    }
+
+    #[test]
+    fn test_market_sell_rounding() {
+        // Sell 12.34567 shares -> 12.3456 shares
+        // tick 0.1: 12.3456 * 0.5 = 6.1728 -> $6.17
+        assert_eq!(
+            market_amounts("0.1", Side::Sell, "12.34567", "0.56"),
+            (12_345_600, 6_170_000)
+        );
+        // tick 0.01: 12.3456 * 0.56 = 6.913536 -> $6.91
+        assert_eq!(
+            market_amounts("0.01", Side::Sell, "12.34567", "0.567"),
+            (12_345_600, 6_910_000)
+        );
+        // tick 0.001: 12.3456 * 0.567 = 6.9999552 -> $6.99
+        assert_eq!(
+            market_amounts("0.001", Side::Sell, "12.34567", "0.5678"),
+            (12_345_600, 6_990_000)
+        );
+    }
+
+    // Well-known development key (anvil/hardhat account #0)
+    const TEST_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const EXCHANGE: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+    const TOKEN_ID: &str =
+        "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+    fn fixed_order(signer: Address) -> Order {
+        Order {
+            salt: U256::from(479249096354u64),
+            maker: signer,
+            signer,
+            taker: Address::ZERO,
+            tokenId: U256::from_str_radix(TOKEN_ID, 10).unwrap(),
+            makerAmount: U256::from(50_000_000u64),
+            takerAmount: U256::from(100_000_000u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: Side::Buy.to_u8(),
+            signatureType: SignatureType::Eoa.to_u8(),
+        }
+    }
+
+    fn word(value: impl Into<U256>) -> [u8; 32] {
+        value.into().to_be_bytes()
+    }
+
+    fn address_word(address: Address) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(address.as_slice());
+        word
+    }
+
+    #[test]
+    fn test_order_hash_matches_eip712_encoding() {
+        use alloy_primitives::keccak256;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let exchange = Address::from_str(EXCHANGE).unwrap();
+        let order = fixed_order(signer.address());
+
+        // Encode the digest by hand from the type strings so that a change in
+        // field order, field types or domain shows up as a mismatch
+        let type_hash = keccak256(
+            "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,\
+             uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,\
+             uint256 feeRateBps,uint8 side,uint8 signatureType)",
+        );
+        let mut encoded = type_hash.to_vec();
+        encoded.extend(word(order.salt));
+        encoded.extend(address_word(order.maker));
+        encoded.extend(address_word(order.signer));
+        encoded.extend(address_word(order.taker));
+        encoded.extend(word(order.tokenId));
+        encoded.extend(word(order.makerAmount));
+        encoded.extend(word(order.takerAmount));
+        encoded.extend(word(order.expiration));
+        encoded.extend(word(order.nonce));
+        encoded.extend(word(order.feeRateBps));
+        encoded.extend(word(U256::from(order.side)));
+        encoded.extend(word(U256::from(order.signatureType)));
+        let struct_hash = keccak256(&encoded);
+
+        let mut domain = keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+        )
+        .to_vec();
+        domain.extend(keccak256("Polymarket CTF Exchange"));
+        domain.extend(keccak256("1"));
+        domain.extend(word(U256::from(137)));
+        domain.extend(address_word(exchange));
+        let domain_separator = keccak256(&domain);
+
+        let mut digest = vec![0x19, 0x01];
+        digest.extend(domain_separator);
+        digest.extend(struct_hash);
+
+        assert_eq!(
+            order_signing_hash(&order, 137, exchange),
+            keccak256(&digest)
+        );
+    }
+
+    #[test]
+    fn test_order_signature_regression() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let exchange = Address::from_str(EXCHANGE).unwrap();
+        let order = fixed_order(signer.address());
+        let hash = order_signing_hash(&order, 137, exchange);
+
+        // Signatures are deterministic (RFC 6979), so any change here means the
+        // signed payload changed
+        let signature = sign_order_message(&signer, order, 137, exchange).unwrap();
+        assert_eq!(
+            signature,
+            "0x4e4a18de9ac827f073445bb64331b74a5f57feed1b86424cfaa61db51ae0c0de\
+             291110ad3c3541ac576a93bfd35adca6f9e4861ce3d46123e56eeadf3e55fd0c1c"
+        );
+
+        let recovered = alloy_primitives::Signature::from_str(&signature)
+            .unwrap()
+            .recover_address_from_prehash(&hash)
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn test_sign_typed_data_hash_matches_order_signature() {
+        use crate::signing::{order_domain_separator, sign_typed_data_hash, typed_data_digest};
+        use alloy_sol_types::SolStruct;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let exchange = Address::from_str(EXCHANGE).unwrap();
+        let order = fixed_order(signer.address());
+        let domain_separator = order_domain_separator(137, exchange);
+        let struct_hash = order.eip712_hash_struct();
+
+        assert_eq!(
+            typed_data_digest(domain_separator, struct_hash),
+            order_signing_hash(&order, 137, exchange)
+        );
+        // Same vector as test_order_signature_regression: no v-value adjustment
+        assert_eq!(
+            sign_typed_data_hash(&signer, domain_separator, struct_hash).unwrap(),
+            "0x4e4a18de9ac827f073445bb64331b74a5f57feed1b86424cfaa61db51ae0c0de\
+             291110ad3c3541ac576a93bfd35adca6f9e4861ce3d46123e56eeadf3e55fd0c1c"
+        );
+    }
+
+    #[test]
+    fn test_order_hash_of_signed_order() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let address = signer.address();
+        let builder = OrderBuilder::new(signer, None, None);
+        let args = OrderArgs::new(
+            TOKEN_ID,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("100").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+
+        let order = builder
+            .create_order(137, &args, 0, &ExtraOrderArgs::default(), options)
+            .unwrap();
+        let hash = builder.order_hash(&order, 137, false).unwrap();
+
+        let recovered = alloy_primitives::Signature::from_str(&order.signature)
+            .unwrap()
+            .recover_address_from_prehash(&hash)
+            .unwrap();
+        assert_eq!(recovered, address);
+    }
+
+    #[test]
+    fn test_fixed_salt_produces_identical_orders() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let builder = OrderBuilder::new(signer, None, None);
+        let args = OrderArgs::new(
+            TOKEN_ID,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("100").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false)
+            .salt(479249096354);
+
+        let create = |args: &OrderArgs| {
+            builder
+                .create_order(137, args, 0, &ExtraOrderArgs::default(), options.clone())
+                .unwrap()
+        };
+
+        let first = create(&args);
+        let second = create(&args);
+        assert_eq!(first.salt, 479249096354);
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+
+        // Same salt, different price: a different order
+        let mut other_args = args.clone();
+        other_args.price = Decimal::from_str("0.6").unwrap();
+        let other = create(&other_args);
+        assert_eq!(other.salt, first.salt);
+        assert_ne!(other.signature, first.signature);
+    }
+
+    #[tokio::test]
+    async fn test_create_order_async_with_remote_signer() {
+        use crate::signing::RemoteSigner;
+        use alloy_signer::Signer;
+
+        let key: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let remote_key = key.clone();
+        let remote = RemoteSigner::new(key.address(), move |hash| {
+            let key = remote_key.clone();
+            async move {
+                tokio::task::yield_now().await;
+                key.sign_hash(&hash).await
+            }
+        });
+        let args = OrderArgs::new(
+            TOKEN_ID,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("100").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false)
+            .salt(479249096354);
+
+        // The current-thread runtime would reject a blocking remote signature
+        let order = OrderBuilder::new(remote, None, None)
+            .create_order_async(137, &args, 0, &ExtraOrderArgs::default(), options.clone())
+            .await
+            .unwrap();
+        let expected = OrderBuilder::new(key, None, None)
+            .create_order(137, &args, 0, &ExtraOrderArgs::default(), options)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&order).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fee_rate_is_signed_and_checked() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let builder = OrderBuilder::new(signer, None, None);
+        let args = OrderArgs::new(
+            TOKEN_ID,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("100").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false)
+            .salt(479249096354)
+            .max_fee_rate_bps(100);
+
+        let create =
+            |extras: &ExtraOrderArgs| builder.create_order(137, &args, 0, extras, options.clone());
+
+        let no_fee = create(&ExtraOrderArgs::default()).unwrap();
+        let with_fee = create(&ExtraOrderArgs::new().fee_rate_bps(100)).unwrap();
+        assert_eq!(with_fee.fee_rate_bps, "100");
+        assert_ne!(
+            builder.order_hash(&no_fee, 137, false).unwrap(),
+            builder.order_hash(&with_fee, 137, false).unwrap()
+        );
+
+        assert!(matches!(
+            create(&ExtraOrderArgs::new().fee_rate_bps(101)),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_neg_risk_selects_exchange_domain() {
+        use crate::signing::order_domain_separator;
+
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        for chain_id in [137, 80002] {
+            let standard = builder.exchange_address(chain_id, false).unwrap();
+            let neg_risk = builder.exchange_address(chain_id, true).unwrap();
+            assert_ne!(standard, neg_risk);
+            assert_ne!(
+                order_domain_separator(chain_id, standard),
+                order_domain_separator(chain_id, neg_risk)
+            );
+        }
+
+        // The neg_risk option changes the digest the order is signed over
+        let args = OrderArgs::new(
+            TOKEN_ID,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("100").unwrap(),
+            Side::Buy,
+        );
+        let create = |neg_risk: bool| {
+            let options = CreateOrderOptions::new()
+                .tick_size(Decimal::from_str("0.01").unwrap())
+                .neg_risk(neg_risk)
+                .salt(479249096354);
+            builder
+                .create_order(137, &args, 0, &ExtraOrderArgs::default(), options)
+                .unwrap()
+        };
+        let standard = create(false);
+        let neg_risk = create(true);
+        assert_ne!(standard.signature, neg_risk.signature);
+
+        let recover = |neg_risk_domain: bool| {
+            let hash = builder.order_hash(&neg_risk, 137, neg_risk_domain).unwrap();
+            alloy_primitives::Signature::from_str(&neg_risk.signature)
+                .unwrap()
+                .recover_address_from_prehash(&hash)
+                .unwrap()
+        };
+        assert_eq!(recover(true), builder.signer.address());
+        assert_ne!(recover(false), builder.signer.address());
+    }
+
+    #[test]
+    fn test_exchange_address_override() {
+        let addresses = ExchangeAddresses {
+            exchange: Address::repeat_byte(0x11),
+            neg_risk_exchange: Address::repeat_byte(0x22),
+        };
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_exchange_addresses(1, addresses);
+
+        assert_eq!(
+            builder.exchange_address(1, false).unwrap(),
+            addresses.exchange
+        );
+        assert_eq!(
+            builder.exchange_address(1, true).unwrap(),
+            addresses.neg_risk_exchange
+        );
+        // Other chains keep their built-in addresses
+        assert_eq!(
+            builder.exchange_address(137, false).unwrap(),
+            Address::from_str(EXCHANGE).unwrap()
+        );
+        assert!(matches!(
+            builder.exchange_address(999, false),
+            Err(Error::Config(_))
+        ));
+    }
 }
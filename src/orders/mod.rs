@@ -2,6 +2,6 @@ mod builder;
 mod price;
 mod rounding;
 
-pub use builder::OrderBuilder;
-pub use price::calculate_market_price;
+pub use builder::{ExchangeAddresses, OrderBuilder};
+pub use price::{calculate_market_order_price, calculate_market_price};
 pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
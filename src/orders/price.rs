@@ -41,12 +41,12 @@ pub fn calculate_market_price(
     let positions = match side {
         Side::Buy => {
             let mut asks = positions.to_vec();
-            asks.sort_by(|a, b| a.price.cmp(&b.price));
+            asks.sort_by_key(|a| a.price);
             asks
         }
         Side::Sell => {
             let mut bids = positions.to_vec();
-            bids.sort_by(|a, b| b.price.cmp(&a.price));
+            bids.sort_by_key(|b| std::cmp::Reverse(b.price));
             bids
         }
     };
@@ -67,6 +67,50 @@ pub fn calculate_market_price(
     )))
 }
 
+/// Calculate the limit price needed to fill a market order of the given amount
+///
+/// This walks the order book from the best price and returns the price of the
+/// last level needed to fill the order. Using it as the order price lets the
+/// order match every level up to that one.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through (asks for BUY, bids for SELL)
+/// * `amount` - Collateral (USDC) to spend for BUY orders, shares to sell for SELL orders
+/// * `side` - The side of the market order
+///
+/// # Returns
+/// The marginal price at which the market order is fully filled, or an error if there's insufficient liquidity
+pub fn calculate_market_order_price(
+    positions: &[PriceLevel],
+    amount: Decimal,
+    side: Side,
+) -> Result<Decimal> {
+    let mut positions = positions.to_vec();
+    match side {
+        Side::Buy => positions.sort_by_key(|a| a.price),
+        Side::Sell => positions.sort_by_key(|b| std::cmp::Reverse(b.price)),
+    }
+
+    let mut matched = Decimal::ZERO;
+    for p in positions {
+        matched += match side {
+            // Buy amounts are in collateral
+            Side::Buy => p.size * p.price,
+            // Sell amounts are in shares
+            Side::Sell => p.size,
+        };
+
+        if matched >= amount {
+            return Ok(p.price);
+        }
+    }
+
+    Err(Error::InvalidOrder(format!(
+        "Not enough liquidity to create market order with amount {}",
+        amount
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +153,34 @@ mod tests {
         let result = calculate_market_price(&positions, dec!(20), Side::Buy);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_market_order_price_buy_uses_collateral() {
+        // 10 shares @ 0.50 ($5), 20 shares @ 0.55 ($11)
+        let positions = vec![order(dec!(0.55), dec!(20)), order(dec!(0.50), dec!(10))];
+
+        // $5 fills at the best level
+        let price = calculate_market_order_price(&positions, dec!(5), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.50));
+
+        // $10 needs the second level
+        let price = calculate_market_order_price(&positions, dec!(10), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.55));
+
+        // $17 is more than the book holds
+        assert!(calculate_market_order_price(&positions, dec!(17), Side::Buy).is_err());
+    }
+
+    #[test]
+    fn test_market_order_price_sell_uses_shares() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+
+        let price = calculate_market_order_price(&positions, dec!(20), Side::Sell).unwrap();
+        assert_eq!(price, dec!(0.55));
+
+        let price = calculate_market_order_price(&positions, dec!(25), Side::Sell).unwrap();
+        assert_eq!(price, dec!(0.50));
+
+        assert!(calculate_market_order_price(&positions, dec!(31), Side::Sell).is_err());
+    }
 }
@@ -0,0 +1,271 @@
+use reqwest::Client;
+use std::time::Duration;
+
+use crate::config::{chains, hosts};
+use crate::error::{Error, Result};
+use crate::signing::EthSigner;
+use crate::types::SignatureType;
+
+use super::client::RelayerClient;
+use super::nonce::NonceManager;
+use super::types::{BuilderApiCreds, RelayerContractConfig};
+
+/// Builder for [`RelayerClient`]
+///
+/// Unset values fall back to defaults: Polygon mainnet, the chain's public
+/// relayer host, a new `reqwest::Client`, no signer, no Builder API
+/// credentials, no RPC endpoint, the Safe wallet type, no duplicate
+/// submission guard and no nonce manager.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::relayer::{BuilderApiCreds, RelayerClient};
+/// use alloy_signer_local::PrivateKeySigner;
+/// use std::str::FromStr;
+///
+/// # fn example() -> polymarket_rs::Result<()> {
+/// let signer = PrivateKeySigner::from_str("your-private-key").unwrap();
+/// let creds = BuilderApiCreds::new(
+///     "your-api-key".to_string(),
+///     "your-secret".to_string(),
+///     "your-passphrase".to_string(),
+/// );
+///
+/// let client = RelayerClient::builder()
+///     .chain_id(137)
+///     .signer(signer)
+///     .builder_creds(creds)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct RelayerClientBuilder {
+    relayer_url: Option<String>,
+    chain_id: Option<u64>,
+    signer: Option<Box<dyn EthSigner>>,
+    builder_creds: Option<BuilderApiCreds>,
+    http_client: Option<Client>,
+    rpc_url: Option<String>,
+    signature_type: Option<SignatureType>,
+    dedupe_window: Option<Duration>,
+    nonce_manager: Option<NonceManager>,
+    contract_config: Option<RelayerContractConfig>,
+}
+
+impl RelayerClientBuilder {
+    /// Create a builder with nothing set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the relayer API URL (defaults to the chain's public relayer host)
+    pub fn relayer_url(mut self, relayer_url: impl Into<String>) -> Self {
+        self.relayer_url = Some(relayer_url.into());
+        self
+    }
+
+    /// Set the chain ID (defaults to Polygon mainnet)
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Set the signer used for transaction signing
+    pub fn signer(mut self, signer: impl EthSigner + 'static) -> Self {
+        self.signer = Some(Box::new(signer));
+        self
+    }
+
+    /// Set the Builder API credentials used to authenticate submissions
+    pub fn builder_creds(mut self, builder_creds: BuilderApiCreds) -> Self {
+        self.builder_creds = Some(builder_creds);
+        self
+    }
+
+    /// Use an existing `reqwest::Client` (see [`RelayerClient::with_http_client`])
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Set the JSON-RPC endpoint used for on-chain reads (see [`RelayerClient::with_rpc_url`])
+    pub fn rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Set the wallet type used by the convenience methods (see [`RelayerClient::with_signature_type`])
+    ///
+    /// [`build`](Self::build) fails for `SignatureType::Eoa`.
+    pub fn signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = Some(signature_type);
+        self
+    }
+
+    /// Refuse identical resubmissions within a window (see [`RelayerClient::with_dedupe_window`])
+    pub fn dedupe_window(mut self, window: Duration) -> Self {
+        self.dedupe_window = Some(window);
+        self
+    }
+
+    /// Take Safe nonces from a local counter (see [`RelayerClient::with_nonce_manager`])
+    pub fn nonce_manager(mut self, nonce_manager: NonceManager) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    /// Use custom contract addresses (see [`RelayerClient::with_contract_config`])
+    ///
+    /// Required for chains other than Polygon and Amoy.
+    pub fn contract_config(mut self, contract_config: RelayerContractConfig) -> Self {
+        self.contract_config = Some(contract_config);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if the chain is unsupported and no contract
+    /// config was set, if the contract config has a malformed address, or if
+    /// no relayer URL was set and the chain has no known public relayer host,
+    /// or if the signature type is `SignatureType::Eoa`.
+    pub fn build(self) -> Result<RelayerClient> {
+        let chain_id = self.chain_id.unwrap_or(chains::POLYGON_MAINNET);
+        let relayer_url = match self.relayer_url {
+            Some(url) => url,
+            None => hosts::relayer_host(chain_id)
+                .ok_or_else(|| {
+                    Error::Config(format!("No default relayer URL for chain_id: {}", chain_id))
+                })?
+                .to_string(),
+        };
+
+        let mut client = RelayerClient::from_parts(
+            self.http_client.unwrap_or_default(),
+            relayer_url,
+            chain_id,
+            self.contract_config,
+            self.signer,
+            self.builder_creds,
+        )?;
+        if let Some(rpc_url) = self.rpc_url {
+            client = client.with_rpc_url(rpc_url);
+        }
+        if let Some(signature_type) = self.signature_type {
+            client = client.with_signature_type(signature_type)?;
+        }
+        if let Some(window) = self.dedupe_window {
+            client = client.with_dedupe_window(window);
+        }
+        if let Some(nonce_manager) = self.nonce_manager {
+            client = client.with_nonce_manager(nonce_manager);
+        }
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[test]
+    fn test_builder_defaults() {
+        let client = RelayerClient::builder().build().unwrap();
+        assert_eq!(client.chain_id(), chains::POLYGON_MAINNET);
+        assert_eq!(client.signature_type(), SignatureType::PolyGnosisSafe);
+        // No signer configured
+        assert!(client.get_expected_safe().is_err());
+    }
+
+    #[test]
+    fn test_builder_matches_new() {
+        let signer = PrivateKeySigner::random();
+        let from_new =
+            RelayerClient::new("http://localhost", 80002, Some(signer.clone()), None).unwrap();
+        let from_builder = RelayerClient::builder()
+            .relayer_url("http://localhost")
+            .chain_id(80002)
+            .signer(signer)
+            .signature_type(SignatureType::PolyGnosisSafe)
+            .build()
+            .unwrap();
+
+        assert_eq!(from_builder.chain_id(), from_new.chain_id());
+        assert_eq!(
+            from_builder.get_expected_safe().unwrap(),
+            from_new.get_expected_safe().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_unsupported_chain() {
+        assert!(matches!(
+            RelayerClient::builder().chain_id(1).build(),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            RelayerClient::builder()
+                .chain_id(1)
+                .relayer_url("http://localhost")
+                .build(),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_custom_contract_config_on_local_chain() {
+        // Contracts deployed on a local Anvil fork
+        let config = RelayerContractConfig {
+            safe_factory: "0x5FbDB2315678afecb367f032d93F642f64180aa3".to_string(),
+            ctf: "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512".to_string(),
+            collateral: "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0".to_string(),
+            ..crate::relayer::mainnet_relayer_config()
+        };
+        let signer = PrivateKeySigner::random();
+
+        let client = RelayerClient::with_contract_config(
+            config.clone(),
+            "http://localhost:8545",
+            31337,
+            Some(signer.clone()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(client.chain_id(), 31337);
+        assert_eq!(client.contract_config().ctf, config.ctf);
+
+        // The Safe address is derived from the custom factory
+        let mainnet =
+            RelayerClient::new("http://localhost", 137, Some(signer.clone()), None).unwrap();
+        assert_ne!(
+            client.get_expected_safe().unwrap(),
+            mainnet.get_expected_safe().unwrap()
+        );
+
+        let from_builder = RelayerClient::builder()
+            .relayer_url("http://localhost:8545")
+            .chain_id(31337)
+            .signer(signer)
+            .contract_config(config.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            from_builder.get_expected_safe().unwrap(),
+            client.get_expected_safe().unwrap()
+        );
+
+        let malformed = RelayerContractConfig {
+            ctf: "0x1234".to_string(),
+            ..config
+        };
+        let result = RelayerClient::builder()
+            .relayer_url("http://localhost:8545")
+            .chain_id(31337)
+            .contract_config(malformed)
+            .build();
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.contains("ctf")));
+    }
+}
@@ -3,18 +3,61 @@
 //! This module provides a client for interacting with Polymarket's Polygon relayer
 //! infrastructure, enabling gasless transactions for Safe wallets.
 
-use crate::error::{Error, Result};
+use crate::error::{ApiError, Error, RelayerError, Result};
+use crate::http::json_body;
+use crate::request::{PositionQueryParams, PositionSortBy, QueryBuilder, SortDirection};
 use crate::signing::EthSigner;
-use alloy_primitives::{hex, keccak256, B256};
-use hmac::{Hmac, Mac};
+use crate::types::{normalize_address, validate_address, IndexSet, SignatureType};
+use alloy_primitives::{hex, keccak256, Address, Bytes, B256, U256};
+use alloy_sol_types::{sol, SolCall};
+use futures_util::stream::{self, StreamExt};
 use reqwest::Client;
-use sha2::Sha256;
-use std::time::{SystemTime, UNIX_EPOCH};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
 
-use super::ctf::CtfEncoder;
+use super::builder::RelayerClientBuilder;
+use super::ctf::{parse_uint256, CtfEncoder, BINARY_PARTITION};
+use super::dedupe::{SubmissionCache, SubmissionCheck};
+use super::nonce::NonceManager;
 use super::types::*;
 
-type HmacSha256 = Hmac<Sha256>;
+// Batching ABI of the Polymarket proxy wallet
+sol! {
+    struct ProxyCall {
+        uint8 typeCode;
+        address to;
+        uint256 value;
+        bytes data;
+    }
+
+    function proxy(ProxyCall[] calls);
+
+    // ERC20 reads used to check funds before splitting
+    function balanceOf(address account) returns (uint256);
+    function allowance(address owner, address spender) returns (uint256);
+}
+
+/// Wallet that relayer transactions execute through
+///
+/// Only contract wallets go through the relayer, so unlike [`SignatureType`]
+/// there is no EOA variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RelayerWallet {
+    Safe,
+    Proxy,
+}
+
+impl RelayerWallet {
+    fn signature_type(self) -> SignatureType {
+        match self {
+            RelayerWallet::Safe => SignatureType::PolyGnosisSafe,
+            RelayerWallet::Proxy => SignatureType::PolyProxy,
+        }
+    }
+}
 
 /// Relayer Client for Safe wallet transactions
 ///
@@ -24,6 +67,10 @@ type HmacSha256 = Hmac<Sha256>;
 /// - CTF operations (split, merge, redeem positions)
 /// - Token approvals
 /// - Custom transaction execution
+///
+/// Convenience methods (split, merge, redeem, approvals) run through the wallet
+/// selected by [`with_signature_type`](Self::with_signature_type), which is the
+/// Safe wallet by default.
 pub struct RelayerClient {
     http_client: Client,
     relayer_url: String,
@@ -31,6 +78,10 @@ pub struct RelayerClient {
     signer: Option<Box<dyn EthSigner>>,
     builder_creds: Option<BuilderApiCreds>,
     contract_config: RelayerContractConfig,
+    wallet: RelayerWallet,
+    rpc_url: Option<String>,
+    submissions: Option<SubmissionCache>,
+    nonce_manager: Option<NonceManager>,
 }
 
 impl RelayerClient {
@@ -47,10 +98,93 @@ impl RelayerClient {
         signer: Option<impl EthSigner + 'static>,
         builder_creds: Option<BuilderApiCreds>,
     ) -> Result<Self> {
-        let contract_config = get_relayer_config(chain_id)
-            .ok_or_else(|| Error::Config(format!("Unsupported chain_id: {}", chain_id)))?;
+        Self::with_http_client(Client::new(), relayer_url, chain_id, signer, builder_creds)
+    }
+
+    /// Create a new RelayerClient using an existing `reqwest::Client`
+    ///
+    /// `reqwest::Client` is cheap to clone and shares its connection pool, so
+    /// passing a clone of the same client to many relayer clients avoids
+    /// opening a pool per client.
+    ///
+    /// # Arguments
+    /// * `http_client` - The HTTP client used for all relayer requests
+    /// * `relayer_url` - The relayer API URL (e.g., "https://relayer-v2.polymarket.com")
+    /// * `chain_id` - The chain ID (137 for Polygon, 80002 for Amoy)
+    /// * `signer` - Optional Ethereum signer for transaction signing
+    /// * `builder_creds` - Optional Builder API credentials for authentication
+    pub fn with_http_client(
+        http_client: Client,
+        relayer_url: impl Into<String>,
+        chain_id: u64,
+        signer: Option<impl EthSigner + 'static>,
+        builder_creds: Option<BuilderApiCreds>,
+    ) -> Result<Self> {
+        Self::from_parts(
+            http_client,
+            relayer_url.into(),
+            chain_id,
+            None,
+            signer.map(|s| Box::new(s) as Box<dyn EthSigner>),
+            builder_creds,
+        )
+    }
+
+    /// Create a new RelayerClient with custom contract addresses
+    ///
+    /// Allows any chain ID, e.g. a local Anvil or Hardhat fork (31337), by
+    /// supplying the contracts to use instead of the built-in config of
+    /// Polygon and Amoy.
+    ///
+    /// # Arguments
+    /// * `contract_config` - Contract addresses on the chain
+    /// * `relayer_url` - The relayer API URL
+    /// * `chain_id` - The chain ID, used for signing
+    /// * `signer` - Optional Ethereum signer for transaction signing
+    /// * `builder_creds` - Optional Builder API credentials for authentication
+    ///
+    /// # Errors
+    /// Returns `Error::Config` if an address in `contract_config` is malformed.
+    pub fn with_contract_config(
+        contract_config: RelayerContractConfig,
+        relayer_url: impl Into<String>,
+        chain_id: u64,
+        signer: Option<impl EthSigner + 'static>,
+        builder_creds: Option<BuilderApiCreds>,
+    ) -> Result<Self> {
+        Self::from_parts(
+            Client::new(),
+            relayer_url.into(),
+            chain_id,
+            Some(contract_config),
+            signer.map(|s| Box::new(s) as Box<dyn EthSigner>),
+            builder_creds,
+        )
+    }
+
+    /// Create a [`RelayerClientBuilder`] for configuring a client step by step
+    pub fn builder() -> RelayerClientBuilder {
+        RelayerClientBuilder::new()
+    }
+
+    pub(super) fn from_parts(
+        http_client: Client,
+        relayer_url: String,
+        chain_id: u64,
+        contract_config: Option<RelayerContractConfig>,
+        signer: Option<Box<dyn EthSigner>>,
+        builder_creds: Option<BuilderApiCreds>,
+    ) -> Result<Self> {
+        let contract_config = match contract_config {
+            Some(config) => {
+                config.validate()?;
+                config
+            }
+            None => get_relayer_config(chain_id)
+                .ok_or_else(|| Error::Config(format!("Unsupported chain_id: {}", chain_id)))?,
+        };
 
-        let url = relayer_url.into();
+        let url = relayer_url;
         let url = if url.ends_with('/') {
             url[..url.len() - 1].to_string()
         } else {
@@ -58,62 +192,217 @@ impl RelayerClient {
         };
 
         Ok(Self {
-            http_client: Client::new(),
+            http_client,
             relayer_url: url,
             chain_id,
-            signer: signer.map(|s| Box::new(s) as Box<dyn EthSigner>),
+            signer,
             builder_creds,
             contract_config,
+            wallet: RelayerWallet::Safe,
+            rpc_url: None,
+            submissions: None,
+            nonce_manager: None,
         })
     }
 
+    /// Select the wallet type used by the convenience methods
+    ///
+    /// - `SignatureType::PolyGnosisSafe` (default) executes through the Safe wallet
+    /// - `SignatureType::PolyProxy` executes through the proxy wallet
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] for `SignatureType::Eoa`, which has no relayer
+    /// wallet.
+    pub fn with_signature_type(mut self, signature_type: SignatureType) -> Result<Self> {
+        self.wallet = match signature_type {
+            SignatureType::PolyGnosisSafe => RelayerWallet::Safe,
+            SignatureType::PolyProxy => RelayerWallet::Proxy,
+            SignatureType::Eoa => return Err(eoa_wallet_error()),
+        };
+        Ok(self)
+    }
+
+    /// Set the JSON-RPC endpoint used for on-chain reads
+    ///
+    /// Enables [`get_collateral_balance`](Self::get_collateral_balance),
+    /// [`get_ctf_allowance`](Self::get_ctf_allowance) and
+    /// [`is_deployed_onchain`](Self::is_deployed_onchain). It also makes
+    /// [`split_position`](Self::split_position) check the wallet's balance and
+    /// allowance before submitting, and makes [`deploy`](Self::deploy) and
+    /// [`execute`](Self::execute) check the Safe's deployment on chain instead
+    /// of asking the relayer.
+    ///
+    /// The URL may carry an API key: it is never logged or included in errors.
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Refuse to resubmit an identical transaction within `window`
+    ///
+    /// Each submission is fingerprinted by its from, to, data and nonce. If
+    /// the same fingerprint was submitted within `window`, the relayer's
+    /// earlier response is returned without a new request. If the earlier
+    /// outcome is unknown (e.g. the connection dropped after sending, or a
+    /// gateway answered with a 5xx), `Error::DuplicatePending` is returned
+    /// instead; check the transaction with
+    /// [`get_transaction`](Self::get_transaction) before retrying with a new
+    /// nonce. Submissions the relayer rejected with a 4xx can be retried.
+    ///
+    /// This is best-effort: only the most recent submissions of this client
+    /// are remembered, and nothing is shared between processes.
+    pub fn with_dedupe_window(mut self, window: Duration) -> Self {
+        self.submissions = Some(SubmissionCache::new(window));
+        self
+    }
+
+    /// Take Safe nonces for [`execute`](Self::execute) from a local counter
+    ///
+    /// The nonce is fetched once and then incremented per transaction, so
+    /// consecutive and concurrent executes don't wait on the relayer's nonce
+    /// to catch up. See [`NonceManager`] for how failed executes are handled.
+    pub fn with_nonce_manager(mut self, nonce_manager: NonceManager) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    /// Get the nonce manager set by [`with_nonce_manager`](Self::with_nonce_manager)
+    pub fn nonce_manager(&self) -> Option<&NonceManager> {
+        self.nonce_manager.as_ref()
+    }
+
+    /// Get the wallet type used by the convenience methods
+    pub fn signature_type(&self) -> SignatureType {
+        self.wallet.signature_type()
+    }
+
+    /// Get the expected address of the configured wallet type
+    pub fn get_expected_wallet(&self) -> Result<String> {
+        match self.wallet {
+            RelayerWallet::Safe => self.get_expected_safe(),
+            RelayerWallet::Proxy => self.get_expected_proxy(),
+        }
+    }
+
     /// Get the expected Safe wallet address for the signer
+    ///
+    /// The address is lowercase, matching what the relayer and the TypeScript
+    /// SDK use, so pass this form to relayer endpoints. Use
+    /// [`get_expected_safe_checksummed`](Self::get_expected_safe_checksummed)
+    /// for display or for tools that expect EIP-55 casing.
     pub fn get_expected_safe(&self) -> Result<String> {
         let signer = self.require_signer()?;
         // Normalize address to lowercase hex for consistency with SDK
         let signer_address = format!("0x{}", hex::encode(signer.address().as_slice()));
-        Ok(derive_safe_address(
-            &signer_address,
-            &self.contract_config.safe_factory,
-        ))
+        derive_safe_address(&signer_address, &self.contract_config.safe_factory)
+    }
+
+    /// Get the expected Safe wallet address for the signer with EIP-55 casing
+    ///
+    /// Meant for block explorers and other tools; relayer endpoints get the
+    /// lowercase [`get_expected_safe`](Self::get_expected_safe).
+    pub fn get_expected_safe_checksummed(&self) -> Result<String> {
+        let signer = self.require_signer()?;
+        let signer_address = format!("0x{}", hex::encode(signer.address().as_slice()));
+        derive_safe_address_checksummed(&signer_address, &self.contract_config.safe_factory)
+    }
+
+    /// Get the expected proxy wallet address for the signer
+    pub fn get_expected_proxy(&self) -> Result<String> {
+        let signer = self.require_signer()?;
+        let proxy_factory = self.require_proxy_factory()?;
+        // Normalize address to lowercase hex for consistency with SDK
+        let signer_address = format!("0x{}", hex::encode(signer.address().as_slice()));
+        derive_proxy_address(&signer_address, proxy_factory)
     }
 
     /// Check if a Safe wallet is deployed
     pub async fn get_deployed(&self, safe_address: &str) -> Result<bool> {
+        let safe_address = normalize_address(safe_address)?;
         let url = format!("{}/deployed?address={}", self.relayer_url, safe_address);
-        let response: DeployedResponse = self.http_client.get(&url).send().await?.json().await?;
+        log::debug!("GET {}", url);
+        let response: DeployedResponse =
+            json_body(self.http_client.get(&url).send().await?).await?;
         Ok(response.deployed)
     }
 
+    /// Check if a wallet is deployed by reading its code on chain
+    ///
+    /// A wallet counts as deployed when `eth_getCode` returns non-empty code.
+    /// Unlike [`get_deployed`](Self::get_deployed) this does not depend on the
+    /// relayer's index, which can lag for a short while after deployment, at
+    /// the cost of a request to the RPC endpoint. Requires an RPC URL (see
+    /// [`with_rpc_url`](Self::with_rpc_url)).
+    pub async fn is_deployed_onchain(&self, safe_address: &str) -> Result<bool> {
+        let address = validate_address(safe_address)?;
+        let code = self
+            .rpc_request(
+                "eth_getCode",
+                serde_json::json!([address.to_string(), "latest"]),
+            )
+            .await?;
+        Ok(!code.is_empty())
+    }
+
     /// Get the nonce for signing transactions
     pub async fn get_nonce(&self, address: &str, tx_type: TransactionType) -> Result<String> {
+        let address = normalize_address(address)?;
         let url = format!(
             "{}/nonce?address={}&type={}",
             self.relayer_url,
             address,
             tx_type.as_str()
         );
-        let response: NonceResponse = self.http_client.get(&url).send().await?.json().await?;
+        log::debug!("GET {}", url);
+        let response: NonceResponse = json_body(self.http_client.get(&url).send().await?).await?;
         Ok(response.nonce)
     }
 
+    /// Get the relay address and nonce for signing transactions
+    pub async fn get_relay_payload(
+        &self,
+        address: &str,
+        tx_type: TransactionType,
+    ) -> Result<RelayPayload> {
+        let address = normalize_address(address)?;
+        let url = format!(
+            "{}/relay-payload?address={}&type={}",
+            self.relayer_url,
+            address,
+            tx_type.as_str()
+        );
+        log::debug!("GET {}", url);
+        let response: RelayPayload = json_body(self.http_client.get(&url).send().await?).await?;
+        Ok(response)
+    }
+
     /// Get a transaction by ID
     pub async fn get_transaction(&self, transaction_id: &str) -> Result<Vec<RelayerTransaction>> {
         let url = format!("{}/transaction?id={}", self.relayer_url, transaction_id);
+        log::debug!("GET {}", url);
         let response: Vec<RelayerTransaction> =
-            self.http_client.get(&url).send().await?.json().await?;
+            json_body(self.http_client.get(&url).send().await?).await?;
         Ok(response)
     }
 
     /// Deploy a Safe wallet
     ///
     /// This creates a new Safe wallet for the signer. The wallet must not already be deployed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(chain_id = self.chain_id, safe_address = tracing::field::Empty)
+        )
+    )]
     pub async fn deploy(&self) -> Result<RelayerSubmitResponse> {
         let signer = self.require_signer()?;
         self.require_builder_creds()?;
 
         let safe_address = self.get_expected_safe()?;
-        let deployed = self.get_deployed(&safe_address).await?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("safe_address", safe_address.as_str());
+        let deployed = self.is_safe_deployed(&safe_address).await?;
 
         if deployed {
             return Err(Error::Config(format!(
@@ -122,6 +411,80 @@ impl RelayerClient {
             )));
         }
 
+        self.submit_deploy(signer, safe_address).await
+    }
+
+    /// Make sure the Safe wallet is deployed, deploying it if needed
+    ///
+    /// Returns `Ok(None)` if the Safe is already deployed. Otherwise submits a
+    /// deployment, waits for it to be mined with the default
+    /// [`TransactionWaitConfig`] and returns the deployment transaction.
+    ///
+    /// If the deployment is rejected or fails because the Safe was deployed
+    /// in the meantime (e.g. by a concurrent call), this returns `Ok(None)`
+    /// as well.
+    ///
+    /// # Errors
+    /// Returns the deployment or wait error if the Safe is still not deployed
+    /// afterwards.
+    pub async fn ensure_deployed(&self) -> Result<Option<RelayerTransaction>> {
+        let signer = self.require_signer()?;
+        self.require_builder_creds()?;
+
+        let safe_address = self.get_expected_safe()?;
+        if self.is_safe_deployed(&safe_address).await? {
+            return Ok(None);
+        }
+
+        let result = match self.submit_deploy(signer, safe_address.clone()).await {
+            Ok(response) => {
+                self.wait_for_transaction(
+                    &response.transaction_id,
+                    TransactionWaitConfig::default(),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(tx) => Ok(Some(tx)),
+            // The deployment may have landed between the check and the submit
+            Err(e) => match self.is_safe_deployed(&safe_address).await {
+                Ok(true) => Ok(None),
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Execute transactions through the Safe wallet, deploying it first if needed
+    ///
+    /// Combines [`ensure_deployed`](Self::ensure_deployed) and
+    /// [`execute`](Self::execute).
+    ///
+    /// # Arguments
+    /// * `transactions` - List of transactions to execute
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn execute_or_deploy(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        validate_metadata(metadata)?;
+        if transactions.is_empty() {
+            return Err(Error::InvalidParameter("No transactions provided".into()));
+        }
+
+        self.ensure_deployed().await?;
+        self.execute(transactions, metadata).await
+    }
+
+    /// Sign and submit the SafeCreate transaction for `safe_address`
+    async fn submit_deploy(
+        &self,
+        signer: &dyn EthSigner,
+        safe_address: String,
+    ) -> Result<RelayerSubmitResponse> {
         // Normalize address to lowercase hex for consistency with SDK
         let from_address = format!("0x{}", hex::encode(signer.address().as_slice()));
 
@@ -132,10 +495,12 @@ impl RelayerClient {
             ZERO_ADDRESS,
             "0",
             ZERO_ADDRESS,
-        );
+        )?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(digest = %struct_hash, "Signing SafeCreate digest");
 
         // Sign the struct hash
-        let signature = sign_eip712_struct_hash(signer, &struct_hash)?;
+        let signature = sign_struct_hash(signer, &struct_hash, RelayerWallet::Safe).await?;
 
         let request = TransactionRequest {
             tx_type: TransactionType::SafeCreate.as_str().to_string(),
@@ -155,23 +520,167 @@ impl RelayerClient {
 
     /// Execute transactions through the Safe wallet
     ///
+    /// The Safe nonce is fetched from the relayer, or taken from the
+    /// [`NonceManager`] if one is set.
+    ///
     /// # Arguments
     /// * `transactions` - List of transactions to execute
     /// * `metadata` - Optional metadata (max 500 characters)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(chain_id = self.chain_id, safe_address = tracing::field::Empty)
+        )
+    )]
     pub async fn execute(
         &self,
         transactions: Vec<SafeTransaction>,
         metadata: Option<&str>,
     ) -> Result<RelayerSubmitResponse> {
+        match &self.nonce_manager {
+            Some(nonces) => self.execute_managed(transactions, metadata, nonces).await,
+            None => {
+                let preview = self.execute_preview(transactions, metadata).await?;
+                self.submit_transaction(preview.request).await
+            }
+        }
+    }
+
+    /// Execute through the Safe wallet with a nonce taken from `nonces`
+    async fn execute_managed(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+        nonces: &NonceManager,
+    ) -> Result<RelayerSubmitResponse> {
+        let nonce = self.next_managed_nonce(nonces).await?;
+        self.execute_reserved(transactions, metadata, nonces, nonce)
+            .await
+    }
+
+    /// Execute through the Safe wallet with `nonce`, already taken from `nonces`
+    async fn execute_reserved(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+        nonces: &NonceManager,
+        nonce: u64,
+    ) -> Result<RelayerSubmitResponse> {
+        let preview = match self
+            .safe_transaction_request(transactions, metadata, Some(nonce))
+            .await
+        {
+            Ok(preview) => preview,
+            Err(e) => {
+                // Never submitted, so the nonce is still free
+                nonces.release(nonce);
+                return Err(e);
+            }
+        };
+
+        let result = self.submit_transaction(preview.request).await;
+        match &result {
+            Err(Error::Relayer {
+                error: RelayerError::NonceTooLow(_),
+                ..
+            }) => nonces.resync(),
+            // Rejected by the relayer, so the nonce was not consumed
+            Err(e) if is_definite_rejection(e) => nonces.release(nonce),
+            // A 5xx may hide an accepted transaction, so ask the relayer
+            Err(Error::Relayer { .. }) => nonces.resync(),
+            // Otherwise the relayer may have accepted the transaction
+            _ => {}
+        }
+        result
+    }
+
+    /// Execute transactions through the Safe wallet with a caller-managed nonce
+    ///
+    /// Same as [`execute`](Self::execute), but signs with `nonce` instead of
+    /// fetching it from the relayer. This lets you submit a tight sequence of
+    /// transactions from a local counter while the relayer's nonce lags behind.
+    ///
+    /// The nonce is not checked: a stale or reused nonce is rejected by the
+    /// relayer with [`RelayerError::NonceTooLow`], and a nonce ahead of the
+    /// Safe's leaves the transaction stuck until the gap is filled. Resync the
+    /// counter with [`get_nonce`](Self::get_nonce) after any failed submission.
+    ///
+    /// # Arguments
+    /// * `transactions` - List of transactions to execute
+    /// * `metadata` - Optional metadata (max 500 characters)
+    /// * `nonce` - Safe nonce to sign with
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(chain_id = self.chain_id, safe_address = tracing::field::Empty)
+        )
+    )]
+    pub async fn execute_with_nonce(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+        nonce: u64,
+    ) -> Result<RelayerSubmitResponse> {
+        let preview = self
+            .safe_transaction_request(transactions, metadata, Some(nonce))
+            .await?;
+        self.submit_transaction(preview.request).await
+    }
+
+    /// Build and sign a Safe transaction without submitting it
+    ///
+    /// Does everything [`execute`](Self::execute) does except the final submit,
+    /// which helps to debug why the relayer rejects a transaction. The Safe
+    /// deployment and nonce are still queried from the relayer.
+    ///
+    /// # Arguments
+    /// * `transactions` - List of transactions to execute
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn execute_preview(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+    ) -> Result<TransactionPreview> {
+        self.safe_transaction_request(transactions, metadata, None)
+            .await
+    }
+
+    /// Reserve the next Safe nonce, fetching it if the counter is not set
+    async fn next_managed_nonce(&self, nonces: &NonceManager) -> Result<u64> {
+        if let Some(nonce) = nonces.reserve() {
+            return Ok(nonce);
+        }
+
+        let signer = self.require_signer()?;
+        let from_address = format!("0x{}", hex::encode(signer.address().as_slice()));
+        let nonce = self.get_nonce(&from_address, TransactionType::Safe).await?;
+        let nonce = nonce.parse::<u64>().map_err(|_| {
+            Error::InvalidParameter(format!("Invalid nonce from relayer: {}", nonce))
+        })?;
+        Ok(nonces.reserve_fetched(nonce))
+    }
+
+    /// Build and sign a Safe transaction, fetching the nonce if none is given
+    async fn safe_transaction_request(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+        nonce: Option<u64>,
+    ) -> Result<TransactionPreview> {
         let signer = self.require_signer()?;
         self.require_builder_creds()?;
+        validate_metadata(metadata)?;
 
         if transactions.is_empty() {
             return Err(Error::InvalidParameter("No transactions provided".into()));
         }
 
         let safe_address = self.get_expected_safe()?;
-        let deployed = self.get_deployed(&safe_address).await?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("safe_address", safe_address.as_str());
+        let deployed = self.is_safe_deployed(&safe_address).await?;
 
         if !deployed {
             return Err(Error::Config(format!(
@@ -184,18 +693,23 @@ impl RelayerClient {
         let from_address = format!("0x{}", hex::encode(signer.address().as_slice()));
         // Query nonce using EOA address - the relayer internally derives the Safe
         // and returns the Safe's nonce (matching SDK behavior)
-        let nonce = self.get_nonce(&from_address, TransactionType::Safe).await?;
+        let nonce = match nonce {
+            Some(nonce) => nonce.to_string(),
+            None => self.get_nonce(&from_address, TransactionType::Safe).await?,
+        };
 
         // Aggregate transactions if more than one
-        let (final_tx, operation) = if transactions.len() == 1 {
+        let (mut final_tx, operation) = if transactions.len() == 1 {
             let tx = &transactions[0];
             (tx.clone(), tx.operation)
         } else {
             (
-                aggregate_transactions(&transactions, &self.contract_config.safe_multisend),
+                aggregate_transactions(&transactions, &self.contract_config.safe_multisend)?,
                 OperationType::DelegateCall,
             )
         };
+        // Submit the target in canonical form, however the caller spelled it
+        final_tx.to = normalize_address(&final_tx.to)?;
 
         // Create the struct hash for Safe execution
         let struct_hash = create_safe_struct_hash(
@@ -211,10 +725,12 @@ impl RelayerClient {
             ZERO_ADDRESS,
             ZERO_ADDRESS,
             &nonce,
-        );
+        )?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(digest = %struct_hash, nonce = %nonce, "Signing SafeTx digest");
 
         // Sign the struct hash
-        let signature = sign_eip712_struct_hash(signer, &struct_hash)?;
+        let signature = sign_struct_hash(signer, &struct_hash, RelayerWallet::Safe).await?;
 
         let request = TransactionRequest {
             tx_type: TransactionType::Safe.as_str().to_string(),
@@ -229,6 +745,83 @@ impl RelayerClient {
             metadata: metadata.map(|s| s.to_string()),
         };
 
+        Ok(TransactionPreview {
+            request,
+            digest: struct_hash,
+        })
+    }
+
+    /// Execute transactions through the proxy wallet
+    ///
+    /// This is the proxy wallet counterpart of [`execute`](Self::execute). All
+    /// transactions are batched through the proxy's own `proxy(calls)` function,
+    /// so no multisend aggregation is needed.
+    ///
+    /// # Arguments
+    /// * `transactions` - List of transactions to execute
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn execute_proxy(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        let signer = self.require_signer()?;
+        self.require_builder_creds()?;
+        validate_metadata(metadata)?;
+        let proxy_factory = self.require_proxy_factory()?;
+        let relay_hub = self.contract_config.relay_hub.as_deref().ok_or_else(|| {
+            Error::Config(format!(
+                "Relay hub is not configured for chain_id {}",
+                self.chain_id
+            ))
+        })?;
+
+        if transactions.is_empty() {
+            return Err(Error::InvalidParameter("No transactions provided".into()));
+        }
+
+        let proxy_address = self.get_expected_proxy()?;
+
+        // Normalize address to lowercase hex for consistency with SDK
+        let from_address = format!("0x{}", hex::encode(signer.address().as_slice()));
+        let relay_payload = self
+            .get_relay_payload(&from_address, TransactionType::Proxy)
+            .await?;
+
+        let data = encode_proxy_transactions(&transactions)?;
+        let gas_limit = DEFAULT_PROXY_GAS_LIMIT.to_string();
+
+        let struct_hash = create_proxy_struct_hash(
+            &from_address,
+            proxy_factory,
+            &data,
+            "0",
+            "0",
+            &gas_limit,
+            &relay_payload.nonce,
+            relay_hub,
+            &relay_payload.address,
+        )?;
+
+        let signature = sign_struct_hash(signer, &struct_hash, RelayerWallet::Proxy).await?;
+
+        let request = TransactionRequest {
+            tx_type: TransactionType::Proxy.as_str().to_string(),
+            from: from_address,
+            to: proxy_factory.to_string(),
+            proxy_wallet: proxy_address,
+            data,
+            signature,
+            value: None,
+            nonce: Some(relay_payload.nonce),
+            signature_params: Some(SignatureParams::for_proxy(
+                &gas_limit,
+                relay_hub,
+                &relay_payload.address,
+            )),
+            metadata: metadata.map(|s| s.to_string()),
+        };
+
         self.submit_transaction(request).await
     }
 
@@ -239,7 +832,7 @@ impl RelayerClient {
     /// # Arguments
     /// * `condition_id` - The condition ID of the resolved market
     /// * `index_sets` - The index sets to redeem (typically [1, 2] for YES/NO markets)
-    /// * `metadata` - Optional metadata
+    /// * `metadata` - Optional metadata (max 500 characters)
     pub async fn redeem_positions(
         &self,
         condition_id: &str,
@@ -250,97 +843,384 @@ impl RelayerClient {
             &self.contract_config.collateral,
             condition_id,
             index_sets,
-        );
+        )?;
 
         let tx = SafeTransaction::new(&self.contract_config.ctf, data);
-        self.execute(vec![tx], metadata).await
+        self.execute_for_wallet(vec![tx], metadata).await
     }
 
-    /// Split collateral into conditional tokens
+    /// Redeem positions of several resolved markets in one relayer transaction
+    ///
+    /// Builds one redeem call per condition and executes them together, batched
+    /// through the multisend contract for Safe wallets or the proxy's own batch
+    /// call for proxy wallets. This needs a single signature and submission
+    /// instead of one per market.
     ///
     /// # Arguments
-    /// * `condition_id` - The condition ID
-    /// * `amount` - Amount of collateral to split (in smallest units)
-    /// * `metadata` - Optional metadata
-    pub async fn split_position(
+    /// * `redemptions` - `(condition_id, index_sets)` pairs to redeem
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn redeem_positions_batch(
         &self,
-        condition_id: &str,
-        amount: &str,
+        redemptions: &[(&str, Vec<u32>)],
         metadata: Option<&str>,
     ) -> Result<RelayerSubmitResponse> {
-        let data = CtfEncoder::encode_split_position(
-            &self.contract_config.collateral,
-            condition_id,
-            amount,
-        );
+        if redemptions.is_empty() {
+            return Err(Error::InvalidParameter("No redemptions provided".into()));
+        }
 
-        let tx = SafeTransaction::new(&self.contract_config.ctf, data);
-        self.execute(vec![tx], metadata).await
+        let transactions = redemptions
+            .iter()
+            .map(|(condition_id, index_sets)| {
+                let data = CtfEncoder::encode_redeem_positions(
+                    &self.contract_config.collateral,
+                    condition_id,
+                    index_sets.clone(),
+                )?;
+                Ok(SafeTransaction::new(&self.contract_config.ctf, data))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.execute_for_wallet(transactions, metadata).await
     }
 
-    /// Merge conditional tokens back into collateral
+    /// Redeem positions of a resolved negative-risk market
+    ///
+    /// Negative-risk markets must be redeemed through the NegRiskAdapter; calling
+    /// [`redeem_positions`](Self::redeem_positions) on them reverts.
     ///
     /// # Arguments
-    /// * `condition_id` - The condition ID
-    /// * `amount` - Amount to merge (in smallest units)
-    /// * `metadata` - Optional metadata
-    pub async fn merge_positions(
-        &self,
+    /// * `condition_id` - The condition ID of the resolved market
+    /// * `amounts` - Amount of each outcome token to redeem (in smallest units), ordered [YES, NO]
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn redeem_positions_neg_risk(
+        &self,
+        condition_id: &str,
+        amounts: &[&str],
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        let data = CtfEncoder::encode_neg_risk_redeem(condition_id, amounts)?;
+
+        let tx = SafeTransaction::new(&self.contract_config.neg_risk_adapter, data);
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Convert NO positions of a negative-risk market into YES positions and collateral
+    ///
+    /// Calls `convertPositions` on the NegRiskAdapter. See
+    /// [`CtfEncoder::encode_convert_positions`] for how `index_set` selects
+    /// questions and what the wallet receives.
+    ///
+    /// # Arguments
+    /// * `market_id` - The negative-risk market ID (`negRiskMarketID` in the Gamma API)
+    /// * `index_set` - Bitmask of the questions whose NO positions are converted
+    /// * `amount` - Amount of each NO position to convert (in smallest units)
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn convert_positions(
+        &self,
+        market_id: &str,
+        index_set: U256,
+        amount: &str,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        let data = CtfEncoder::encode_convert_positions(market_id, index_set, amount)?;
+
+        let tx = SafeTransaction::new(&self.contract_config.neg_risk_adapter, data);
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Split collateral into conditional tokens
+    ///
+    /// If an RPC URL is configured (see [`with_rpc_url`](Self::with_rpc_url)),
+    /// the wallet's collateral balance and CTF allowance are checked first, and
+    /// an `Error::InvalidParameter` describing the shortfall is returned instead
+    /// of submitting a transaction that would fail on chain.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID
+    /// * `amount` - Amount of collateral to split (in smallest units)
+    /// * `partition` - Optional outcome partition (defaults to [1, 2] for binary markets)
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn split_position(
+        &self,
+        condition_id: &str,
+        amount: &str,
+        partition: Option<&[u32]>,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        if self.rpc_url.is_some() {
+            validate_metadata(metadata)?;
+            self.check_split_funds(parse_uint256(amount)?).await?;
+        }
+
+        let data = CtfEncoder::encode_split_position(
+            &self.contract_config.collateral,
+            condition_id,
+            partition.unwrap_or(&BINARY_PARTITION),
+            amount,
+        )?;
+
+        let tx = SafeTransaction::new(&self.contract_config.ctf, data);
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Merge conditional tokens back into collateral
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID
+    /// * `amount` - Amount to merge (in smallest units)
+    /// * `partition` - Optional outcome partition (defaults to [1, 2] for binary markets)
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn merge_positions(
+        &self,
         condition_id: &str,
         amount: &str,
+        partition: Option<&[u32]>,
         metadata: Option<&str>,
     ) -> Result<RelayerSubmitResponse> {
         let data = CtfEncoder::encode_merge_positions(
             &self.contract_config.collateral,
             condition_id,
+            partition.unwrap_or(&BINARY_PARTITION),
             amount,
-        );
+        )?;
 
         let tx = SafeTransaction::new(&self.contract_config.ctf, data);
-        self.execute(vec![tx], metadata).await
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Approve a spender to transfer an ERC20 token
+    ///
+    /// # Arguments
+    /// * `token` - The ERC20 token address
+    /// * `spender` - The address to approve
+    /// * `amount` - Amount to approve (in smallest units)
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn approve_token(
+        &self,
+        token: &str,
+        spender: &str,
+        amount: U256,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        validate_address(token)?;
+        let data = CtfEncoder::encode_approve(spender, amount)?;
+
+        let tx = SafeTransaction::new(token, data);
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Approve a spender to transfer an unlimited amount of an ERC20 token
+    ///
+    /// # Arguments
+    /// * `token` - The ERC20 token address
+    /// * `spender` - The address to approve
+    /// * `metadata` - Optional metadata (max 500 characters)
+    pub async fn approve_token_max(
+        &self,
+        token: &str,
+        spender: &str,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        validate_address(token)?;
+        let data = CtfEncoder::encode_approve_max(spender)?;
+
+        let tx = SafeTransaction::new(token, data);
+        self.execute_for_wallet(vec![tx], metadata).await
+    }
+
+    /// Get the collateral (USDC) balance of an address, in smallest units
+    ///
+    /// Requires an RPC URL (see [`with_rpc_url`](Self::with_rpc_url)).
+    pub async fn get_collateral_balance(&self, address: &str) -> Result<U256> {
+        let account = validate_address(address)?;
+        let call = balanceOfCall { account };
+        let output = self
+            .eth_call(&self.contract_config.collateral, call.abi_encode())
+            .await?;
+        balanceOfCall::abi_decode_returns(&output)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid balanceOf response: {}", e)))
+    }
+
+    /// Get the collateral (USDC) amount `owner` allows `spender` to transfer
+    ///
+    /// Pass the CTF contract as `spender` to check the allowance needed by
+    /// [`split_position`](Self::split_position). Requires an RPC URL (see
+    /// [`with_rpc_url`](Self::with_rpc_url)).
+    pub async fn get_ctf_allowance(&self, owner: &str, spender: &str) -> Result<U256> {
+        let call = allowanceCall {
+            owner: validate_address(owner)?,
+            spender: validate_address(spender)?,
+        };
+        let output = self
+            .eth_call(&self.contract_config.collateral, call.abi_encode())
+            .await?;
+        allowanceCall::abi_decode_returns(&output)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid allowance response: {}", e)))
+    }
+
+    /// Approve the CTF contract to spend unlimited collateral
+    ///
+    /// Required before [`split_position`](Self::split_position).
+    pub async fn approve_ctf_max(&self, metadata: Option<&str>) -> Result<RelayerSubmitResponse> {
+        self.approve_token_max(
+            &self.contract_config.collateral,
+            &self.contract_config.ctf,
+            metadata,
+        )
+        .await
+    }
+
+    /// Approve the CTF Exchange to spend unlimited collateral
+    ///
+    /// Required before buy orders can be filled.
+    pub async fn approve_exchange_max(
+        &self,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        self.approve_token_max(
+            &self.contract_config.collateral,
+            &self.contract_config.exchange,
+            metadata,
+        )
+        .await
     }
 
-    /// Wait for a transaction to reach a terminal state
+    /// Wait for a transaction to reach a target state
+    ///
+    /// Polls the relayer with exponential backoff until the transaction reaches
+    /// `config.target_state` or a later state (Mined by default; use Confirmed to
+    /// wait for confirmations).
     ///
     /// # Arguments
     /// * `transaction_id` - The transaction ID to wait for
-    /// * `max_polls` - Maximum number of poll attempts (default: 30)
-    /// * `poll_interval_ms` - Interval between polls in milliseconds (default: 2000)
+    /// * `config` - Poll intervals, poll limit and target state
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidParameter` if `config.multiplier` is negative or
+    /// not finite, `Error::Api` if the transaction fails or is invalid, and
+    /// `Error::TransactionTimeout` if it has not reached the target state after
+    /// `config.max_polls` polls.
     pub async fn wait_for_transaction(
         &self,
         transaction_id: &str,
-        max_polls: Option<u32>,
-        poll_interval_ms: Option<u64>,
-    ) -> Result<Option<RelayerTransaction>> {
-        let max_polls = max_polls.unwrap_or(30);
-        let poll_interval = std::time::Duration::from_millis(poll_interval_ms.unwrap_or(2000));
+        config: TransactionWaitConfig,
+    ) -> Result<RelayerTransaction> {
+        if !config.multiplier.is_finite() || config.multiplier < 0.0 {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid poll interval multiplier: {}",
+                config.multiplier
+            )));
+        }
 
-        for _ in 0..max_polls {
+        let mut interval = config.initial_interval;
+
+        for poll in 1..=config.max_polls {
             let transactions = self.get_transaction(transaction_id).await?;
 
             if let Some(tx) = transactions.into_iter().next() {
-                if let Some(state) = tx.get_state() {
-                    if state.is_success() {
-                        return Ok(Some(tx));
-                    }
-                    if state == RelayerTransactionState::Failed
-                        || state == RelayerTransactionState::Invalid
+                if let Some(state) = &tx.state {
+                    if *state == RelayerTransactionState::Failed
+                        || *state == RelayerTransactionState::Invalid
                     {
-                        return Err(Error::Api {
-                            status: 400,
-                            message: format!(
+                        return Err(Error::Api(ApiError::new(
+                            400,
+                            format!(
                                 "Transaction {} failed with state {:?}",
                                 transaction_id, state
                             ),
-                        });
+                        )));
+                    }
+                    if state.has_reached(&config.target_state) {
+                        return Ok(tx);
                     }
                 }
             }
 
-            tokio::time::sleep(poll_interval).await;
+            if poll < config.max_polls {
+                tokio::time::sleep(interval).await;
+                // A huge multiplier overflows Duration; cap it instead
+                interval = Duration::try_from_secs_f64(interval.as_secs_f64() * config.multiplier)
+                    .map_or(config.max_interval, |next| next.min(config.max_interval));
+            }
+        }
+
+        Err(Error::TransactionTimeout {
+            transaction_id: transaction_id.to_string(),
+            polls: config.max_polls,
+        })
+    }
+
+    /// Wait for a transaction to reach a target state, stopping early when
+    /// `cancel` completes
+    ///
+    /// Behaves like [`wait_for_transaction`](Self::wait_for_transaction) but
+    /// gives up as soon as `cancel` resolves, e.g. on a shutdown signal.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::relayer::{RelayerClient, TransactionWaitConfig};
+    ///
+    /// # async fn example(client: RelayerClient) -> polymarket_rs::Result<()> {
+    /// let tx = client
+    ///     .wait_for_transaction_with_cancel("tx-id", TransactionWaitConfig::default(), async {
+    ///         let _ = tokio::signal::ctrl_c().await;
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::Cancelled` if `cancel` completes first, otherwise the
+    /// errors of [`wait_for_transaction`](Self::wait_for_transaction).
+    pub async fn wait_for_transaction_with_cancel(
+        &self,
+        transaction_id: &str,
+        config: TransactionWaitConfig,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<RelayerTransaction> {
+        tokio::select! {
+            result = self.wait_for_transaction(transaction_id, config) => result,
+            _ = cancel => Err(Error::Cancelled),
         }
+    }
 
-        Ok(None)
+    /// Get the on-chain receipt of a mined transaction
+    ///
+    /// Requires an RPC URL (see [`with_rpc_url`](Self::with_rpc_url)). Use the
+    /// `transaction_hash` of a [`RelayerTransaction`] once it is mined, and
+    /// [`decode_ctf_events`](super::decode_ctf_events) to read the amounts a
+    /// redeem or split moved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::relayer::{decode_ctf_events, RelayerClient};
+    ///
+    /// # async fn example(client: RelayerClient, tx_hash: &str) -> polymarket_rs::Result<()> {
+    /// if let Some(receipt) = client.get_transaction_receipt(tx_hash).await? {
+    ///     let events = decode_ctf_events(&receipt, &client.contract_config().ctf)?;
+    ///     println!("status {}, events {:?}", receipt.status, events);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    /// The receipt, or None if the transaction is not mined yet
+    pub async fn get_transaction_receipt(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<Option<TransactionReceipt>> {
+        let hash: B256 = transaction_hash.parse().map_err(|e| {
+            Error::InvalidParameter(format!(
+                "Invalid transaction hash {}: {}",
+                transaction_hash, e
+            ))
+        })?;
+        self.rpc_result("eth_getTransactionReceipt", serde_json::json!([hash]))
+            .await
     }
 
     /// Get redeemable positions for a user from the data API
@@ -350,7 +1230,8 @@ impl RelayerClient {
     ///
     /// # Arguments
     /// * `data_api_url` - The data API URL (e.g., "https://data-api.polymarket.com")
-    /// * `user_address` - The user's wallet address (Safe wallet address)
+    /// * `user_address` - The user's wallet address (Safe or proxy wallet address)
+    /// * `params` - Query options (defaults to RedeemableParams::default())
     ///
     /// # Returns
     /// A list of redeemable positions with their condition IDs and sizes
@@ -358,17 +1239,40 @@ impl RelayerClient {
         &self,
         data_api_url: &str,
         user_address: &str,
+        params: Option<RedeemableParams>,
     ) -> Result<Vec<RedeemablePosition>> {
-        let url = format!(
-            "{}/positions?user={}&redeemable=true&sizeThreshold=0.1&limit=100&offset=0&sortBy=CURRENT&sortDirection=DESC",
-            data_api_url, user_address
-        );
-        let response: Vec<PositionData> = self.http_client.get(&url).send().await?.json().await?;
+        let params = params.unwrap_or_default();
+        let page_size = params.limit.unwrap_or(REDEEMABLE_PAGE_SIZE);
+        let user_address = normalize_address(user_address)?;
 
-        let redeemable: Vec<RedeemablePosition> = response
+        let mut positions = Vec::new();
+        loop {
+            let query = PositionQueryParams::new()
+                .with_redeemable(true)
+                .with_size_threshold(params.size_threshold)
+                .with_limit(page_size)
+                .with_offset(positions.len() as u32)
+                .with_sort_by(PositionSortBy::Current)
+                .with_sort_direction(SortDirection::Desc);
+            let query = QueryBuilder::new()
+                .push("user", &user_address)
+                .append(query.to_query());
+            let url = format!("{}/positions{}", data_api_url, query.build());
+            log::debug!("GET {}", url);
+            let page: Vec<PositionData> =
+                json_body(self.http_client.get(&url).send().await?).await?;
+
+            let done = params.limit.is_some() || page.len() < page_size as usize;
+            positions.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        let redeemable: Vec<RedeemablePosition> = positions
             .into_iter()
-            // Only include positions with currentValue > 0 (winning positions worth redeeming)
-            .filter(|p| p.current_value > 0.0)
+            // By default only include positions with currentValue > 0 (winning positions worth redeeming)
+            .filter(|p| params.min_value.is_none_or(|min| p.current_value > min))
             .map(|p| RedeemablePosition {
                 condition_id: p.condition_id,
                 asset: p.asset,
@@ -383,13 +1287,92 @@ impl RelayerClient {
         Ok(redeemable)
     }
 
-    /// Redeem all redeemable positions for the current Safe wallet
+    /// Get positions that can be merged back into collateral
+    ///
+    /// Complementary positions (both outcomes of a binary market) are paired by
+    /// condition ID, and the mergeable amount is the smaller of the two sizes.
+    ///
+    /// # Arguments
+    /// * `data_api_url` - The data API URL (e.g., "https://data-api.polymarket.com")
+    /// * `user_address` - The user's wallet address (Safe or proxy wallet address)
+    pub async fn get_mergeable_positions(
+        &self,
+        data_api_url: &str,
+        user_address: &str,
+    ) -> Result<Vec<MergeablePosition>> {
+        let user_address = normalize_address(user_address)?;
+        let mut positions: Vec<PositionData> = Vec::new();
+        loop {
+            let query = PositionQueryParams::new()
+                .with_size_threshold(0.0)
+                .with_limit(REDEEMABLE_PAGE_SIZE)
+                .with_offset(positions.len() as u32);
+            let query = QueryBuilder::new()
+                .push("user", &user_address)
+                .append(query.to_query());
+            let url = format!("{}/positions{}", data_api_url, query.build());
+            log::debug!("GET {}", url);
+            let page: Vec<PositionData> =
+                json_body(self.http_client.get(&url).send().await?).await?;
+
+            let done = page.len() < REDEEMABLE_PAGE_SIZE as usize;
+            positions.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        pair_mergeable_positions(positions)
+    }
+
+    /// Merge all mergeable positions for the configured wallet
+    ///
+    /// This is the merge counterpart of [`redeem_all_positions`](Self::redeem_all_positions).
+    /// Negative risk markets are skipped, since their positions are not backed
+    /// by collateral directly. Merges are submitted one at a time, and a failed
+    /// merge does not stop the others; each one's result is recorded in the
+    /// returned summary.
+    ///
+    /// # Arguments
+    /// * `data_api_url` - The data API URL
+    ///
+    /// # Errors
+    /// Errors fetching the mergeable positions are returned directly.
+    pub async fn merge_all_positions(&self, data_api_url: &str) -> Result<MergeSummary> {
+        let wallet_address = self.get_expected_wallet()?;
+        let mergeable = self
+            .get_mergeable_positions(data_api_url, &wallet_address)
+            .await?;
+
+        let mut results = Vec::new();
+
+        for position in mergeable.into_iter().filter(|p| !p.negative_risk) {
+            let result = self
+                .merge_positions(
+                    &position.condition_id,
+                    &position.amount,
+                    None,
+                    Some(&format!("Merge: {}", position.title)),
+                )
+                .await;
+
+            results.push((position.condition_id, result));
+        }
+
+        Ok(MergeSummary { results })
+    }
+
+    /// Redeem all redeemable positions for the configured wallet
     ///
     /// This is a convenience method that:
-    /// 1. Gets the Safe wallet address
-    /// 2. Fetches all redeemable positions
+    /// 1. Gets the wallet address (Safe or proxy, see [`with_signature_type`](Self::with_signature_type))
+    /// 2. Fetches all redeemable positions with a positive value, across all pages
     /// 3. Redeems each position
     ///
+    /// Each position is a separate relayer transaction; use
+    /// [`redeem_positions_batch`](Self::redeem_positions_batch) to redeem many
+    /// markets with a single transaction.
+    ///
     /// # Arguments
     /// * `data_api_url` - The data API URL
     ///
@@ -399,19 +1382,19 @@ impl RelayerClient {
         &self,
         data_api_url: &str,
     ) -> Result<Vec<(String, RelayerSubmitResponse)>> {
-        let safe_address = self.get_expected_safe()?;
+        let wallet_address = self.get_expected_wallet()?;
         let redeemable = self
-            .get_redeemable_positions(data_api_url, &safe_address)
+            .get_redeemable_positions(
+                data_api_url,
+                &wallet_address,
+                Some(RedeemableParams::new().with_limit(None)),
+            )
             .await?;
 
         let mut results = Vec::new();
 
         for position in redeemable {
-            // Calculate the correct index set based on outcome_index
-            // index_set is a bitmask: 1 << outcome_index
-            // outcome_index 0 (YES) -> index_set 1 (binary: 01)
-            // outcome_index 1 (NO)  -> index_set 2 (binary: 10)
-            let index_set = 1u32 << position.outcome_index;
+            let index_set = outcome_index_set(position.outcome_index)?;
 
             let result = self
                 .redeem_positions(
@@ -427,6 +1410,143 @@ impl RelayerClient {
         Ok(results)
     }
 
+    /// Redeem all redeemable positions for the configured wallet, several at a time
+    ///
+    /// Like [`redeem_all_positions`](Self::redeem_all_positions), but runs up to
+    /// `max_concurrency` redemptions at once. A failed redemption does not stop
+    /// the others; each one's result is recorded in the returned summary.
+    ///
+    /// Safe redemptions take their nonces from the client's [`NonceManager`]
+    /// (see [`with_nonce_manager`](Self::with_nonce_manager)), or from one
+    /// created for this call, so concurrent submissions never share a nonce.
+    /// Proxy wallets fetch their nonce for every transaction, so their
+    /// redemptions run one at a time whatever `max_concurrency` is.
+    ///
+    /// A Safe executes its transactions in nonce order, so a redemption whose
+    /// nonce was not used holds up every later one. If a redemption fails
+    /// while redemptions with later nonces are accepted, those are reported as
+    /// [`Error::NonceGap`] rather than as successes: the relayer holds them,
+    /// but they only execute once a transaction with the missing nonce goes
+    /// through. A failed submission whose outcome is unknown (e.g. a timeout)
+    /// counts as missing too, since it may not have been accepted. The nonce
+    /// counter is then resynced, so the next execute signs with the relayer's
+    /// nonce and fills the gap.
+    ///
+    /// # Arguments
+    /// * `data_api_url` - The data API URL
+    /// * `max_concurrency` - Maximum number of redemptions in flight
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidParameter` if `max_concurrency` is zero. Errors
+    /// fetching the redeemable positions are returned directly.
+    pub async fn redeem_all_positions_concurrent(
+        &self,
+        data_api_url: &str,
+        max_concurrency: usize,
+    ) -> Result<RedeemSummary> {
+        if max_concurrency == 0 {
+            return Err(Error::InvalidParameter(
+                "max_concurrency must be at least 1".into(),
+            ));
+        }
+
+        let wallet_address = self.get_expected_wallet()?;
+        let redeemable = self
+            .get_redeemable_positions(
+                data_api_url,
+                &wallet_address,
+                Some(RedeemableParams::new().with_limit(None)),
+            )
+            .await?;
+
+        let local_nonces;
+        let nonces = match &self.nonce_manager {
+            Some(nonces) => nonces,
+            None => {
+                local_nonces = NonceManager::new();
+                &local_nonces
+            }
+        };
+        let max_concurrency = match self.wallet {
+            RelayerWallet::Safe => max_concurrency,
+            RelayerWallet::Proxy => 1,
+        };
+
+        let mut results: Vec<_> = stream::iter(redeemable)
+            .map(|position| async move {
+                let index_set = match outcome_index_set(position.outcome_index) {
+                    Ok(index_set) => index_set,
+                    Err(e) => return (position.condition_id, None, Err(e)),
+                };
+                let metadata = format!("Redeem: {}", position.title);
+                let (nonce, result) = match self.wallet {
+                    RelayerWallet::Safe => {
+                        self.redeem_with_nonces(
+                            &position.condition_id,
+                            index_set,
+                            &metadata,
+                            nonces,
+                        )
+                        .await
+                    }
+                    RelayerWallet::Proxy => {
+                        let result = self
+                            .redeem_positions(
+                                &position.condition_id,
+                                vec![index_set],
+                                Some(&metadata),
+                            )
+                            .await;
+                        (None, result)
+                    }
+                };
+                (position.condition_id, nonce, result)
+            })
+            .buffer_unordered(max_concurrency)
+            .collect()
+            .await;
+
+        if mark_nonce_gaps(&mut results) {
+            // Let the next execute fill the gap with the relayer's nonce
+            nonces.resync();
+        }
+        let results = results
+            .into_iter()
+            .map(|(condition_id, _, result)| (condition_id, result))
+            .collect();
+        Ok(RedeemSummary { results })
+    }
+
+    /// Redeem one outcome through the Safe wallet with a nonce from `nonces`
+    ///
+    /// Returns the nonce the redemption was signed with, if one was taken.
+    async fn redeem_with_nonces(
+        &self,
+        condition_id: &str,
+        index_set: u32,
+        metadata: &str,
+        nonces: &NonceManager,
+    ) -> (Option<u64>, Result<RelayerSubmitResponse>) {
+        let data = match CtfEncoder::encode_redeem_positions(
+            &self.contract_config.collateral,
+            condition_id,
+            vec![index_set],
+        ) {
+            Ok(data) => data,
+            Err(e) => return (None, Err(e)),
+        };
+        let nonce = match self.next_managed_nonce(nonces).await {
+            Ok(nonce) => nonce,
+            Err(e) => return (None, Err(e)),
+        };
+
+        let tx = SafeTransaction::new(&self.contract_config.ctf, data);
+        let result = self
+            .execute_reserved(vec![tx], Some(metadata), nonces, nonce)
+            .await;
+        (Some(nonce), result)
+    }
+
     /// Get the contract configuration
     pub fn contract_config(&self) -> &RelayerContractConfig {
         &self.contract_config
@@ -439,6 +1559,17 @@ impl RelayerClient {
 
     // Private helper methods
 
+    async fn execute_for_wallet(
+        &self,
+        transactions: Vec<SafeTransaction>,
+        metadata: Option<&str>,
+    ) -> Result<RelayerSubmitResponse> {
+        match self.wallet {
+            RelayerWallet::Safe => self.execute(transactions, metadata).await,
+            RelayerWallet::Proxy => self.execute_proxy(transactions, metadata).await,
+        }
+    }
+
     fn require_signer(&self) -> Result<&dyn EthSigner> {
         self.signer
             .as_ref()
@@ -446,28 +1577,182 @@ impl RelayerClient {
             .ok_or_else(|| Error::AuthRequired("Signer is required for this operation".into()))
     }
 
+    fn require_proxy_factory(&self) -> Result<&str> {
+        self.contract_config
+            .proxy_factory
+            .as_deref()
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Proxy wallets are not supported on chain_id {}",
+                    self.chain_id
+                ))
+            })
+    }
+
     fn require_builder_creds(&self) -> Result<&BuilderApiCreds> {
         self.builder_creds.as_ref().ok_or_else(|| {
             Error::AuthRequired("Builder credentials are required for this operation".into())
         })
     }
 
+    async fn check_split_funds(&self, amount: U256) -> Result<()> {
+        let wallet = self.get_expected_wallet()?;
+
+        let balance = self.get_collateral_balance(&wallet).await?;
+        if balance < amount {
+            return Err(Error::InvalidParameter(format!(
+                "Insufficient collateral balance for split: wallet {} holds {}, needs {}",
+                wallet, balance, amount
+            )));
+        }
+
+        let allowance = self
+            .get_ctf_allowance(&wallet, &self.contract_config.ctf)
+            .await?;
+        if allowance < amount {
+            return Err(Error::InvalidParameter(format!(
+                "Insufficient collateral allowance for split: wallet {} allows the CTF {}, needs {} (see approve_ctf_max)",
+                wallet, allowance, amount
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn is_safe_deployed(&self, safe_address: &str) -> Result<bool> {
+        if self.rpc_url.is_some() {
+            self.is_deployed_onchain(safe_address).await
+        } else {
+            self.get_deployed(safe_address).await
+        }
+    }
+
+    async fn eth_call(&self, to: &str, data: Vec<u8>) -> Result<Vec<u8>> {
+        let call = serde_json::json!({ "to": to, "data": format!("0x{}", hex::encode(data)) });
+        self.rpc_request("eth_call", serde_json::json!([call, "latest"]))
+            .await
+    }
+
+    /// Send a JSON-RPC request whose result is hex data
+    async fn rpc_request(&self, method: &str, params: serde_json::Value) -> Result<Vec<u8>> {
+        let result: String = self
+            .rpc_result(method, params)
+            .await?
+            .ok_or_else(|| Error::MissingField("result".to_string()))?;
+        hex::decode(result.trim_start_matches("0x"))
+            .map_err(|e| Error::InvalidParameter(format!("Invalid {} result: {}", method, e)))
+    }
+
+    /// Send a JSON-RPC request, returning its result (None if it is null)
+    async fn rpc_result<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>> {
+        let rpc_url = self
+            .rpc_url
+            .as_ref()
+            .ok_or_else(|| Error::Config("RPC URL required for on-chain reads".to_string()))?;
+
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        // RPC URLs often embed an API key, so neither the log nor the error
+        // may mention it
+        log::debug!("RPC {}", method);
+        let response = self
+            .http_client
+            .post(rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(reqwest::Error::without_url)?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Api(ApiError::from_body(status, body)));
+        }
+
+        let response: JsonRpcResponse<T> = json_body(response).await?;
+        if let Some(error) = response.error {
+            return Err(Error::Api(ApiError::from_body(200, error.to_string())));
+        }
+        Ok(response.result)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                chain_id = self.chain_id,
+                safe_address = %request.proxy_wallet,
+                nonce = request.nonce.as_deref(),
+                tx_type = %request.tx_type,
+                transaction_id = tracing::field::Empty,
+            )
+        )
+    )]
     async fn submit_transaction(
         &self,
         request: TransactionRequest,
     ) -> Result<RelayerSubmitResponse> {
         let builder_creds = self.require_builder_creds()?;
 
-        let body = serde_json::to_string(&request)?;
-        let headers = generate_builder_headers(builder_creds, "POST", "/submit", Some(&body))?;
+        let Some(submissions) = &self.submissions else {
+            let result = self.post_submission(builder_creds, &request).await;
+            #[cfg(feature = "tracing")]
+            record_transaction_id(&result);
+            return result;
+        };
+        let fingerprint = match submissions.check(&request) {
+            SubmissionCheck::New(fingerprint) => fingerprint,
+            SubmissionCheck::Accepted(response) => {
+                log::debug!(
+                    "Skipping duplicate submission of transaction {}",
+                    response.transaction_id
+                );
+                return Ok(response);
+            }
+            SubmissionCheck::Pending => {
+                return Err(Error::DuplicatePending {
+                    nonce: request.nonce.clone(),
+                });
+            }
+        };
 
-        let response = self
-            .http_client
-            .post(format!("{}/submit", self.relayer_url))
-            .header("POLY_BUILDER_API_KEY", &headers.api_key)
-            .header("POLY_BUILDER_SIGNATURE", &headers.signature)
-            .header("POLY_BUILDER_TIMESTAMP", &headers.timestamp)
-            .header("POLY_BUILDER_PASSPHRASE", &headers.passphrase)
+        let result = self.post_submission(builder_creds, &request).await;
+        match &result {
+            Ok(response) => submissions.accepted(fingerprint, response),
+            Err(e) if is_definite_rejection(e) => submissions.rejected(fingerprint),
+            // A gateway error or dropped connection may hide an accepted transaction
+            Err(_) => {}
+        }
+        #[cfg(feature = "tracing")]
+        record_transaction_id(&result);
+        result
+    }
+
+    async fn post_submission(
+        &self,
+        builder_creds: &BuilderApiCreds,
+        request: &TransactionRequest,
+    ) -> Result<RelayerSubmitResponse> {
+        let body = serde_json::to_string(request)?;
+        let headers = builder_creds.sign_request("POST", "/submit", Some(&body))?;
+
+        let url = format!("{}/submit", self.relayer_url);
+        log::debug!("POST {}", url);
+        let mut request = self.http_client.post(&url);
+        for (name, value) in headers.iter() {
+            request = request.header(name, value);
+        }
+        let response = request
             .header("Content-Type", "application/json")
             .body(body)
             .send()
@@ -475,106 +1760,260 @@ impl RelayerClient {
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_default();
-            return Err(Error::Api { status, message });
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Relayer {
+                status,
+                error: RelayerError::from_body(&body),
+            });
         }
 
-        let result: RelayerSubmitResponse = response.json().await?;
-        Ok(result)
+        json_body(response).await
     }
 }
 
-// Helper structs and functions
+// Helper functions
 
-struct BuilderHeaders {
-    api_key: String,
-    signature: String,
-    timestamp: String,
-    passphrase: String,
+/// Check that metadata fits the relayer's length limit
+fn validate_metadata(metadata: Option<&str>) -> Result<()> {
+    match metadata {
+        Some(metadata) if metadata.chars().count() > MAX_METADATA_LEN => {
+            Err(Error::InvalidParameter(format!(
+                "Metadata is {} characters, the maximum is {}",
+                metadata.chars().count(),
+                MAX_METADATA_LEN
+            )))
+        }
+        _ => Ok(()),
+    }
 }
 
-fn generate_builder_headers(
-    creds: &BuilderApiCreds,
-    method: &str,
-    path: &str,
-    body: Option<&str>,
-) -> Result<BuilderHeaders> {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| Error::Signing(e.to_string()))?
-        .as_secs();
-
-    let timestamp_str = timestamp.to_string();
-    let body_str = body.unwrap_or("");
-    let message = format!("{}{}{}{}", timestamp_str, method, path, body_str);
-
-    // Use STANDARD base64 decoding for the secret (matching TypeScript SDK)
-    // TypeScript uses Buffer.from(secret, "base64") which is standard base64
-    let secret_bytes =
-        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &creds.secret)
-            .or_else(|_| {
-                // Fallback: try URL-safe if standard fails (for flexibility)
-                base64::Engine::decode(&base64::engine::general_purpose::URL_SAFE, &creds.secret)
-            })
-            .map_err(|e| Error::Signing(format!("Failed to decode secret: {}", e)))?;
+/// Whether the relayer refused a submission, so the transaction was not accepted
+///
+/// Only 4xx answers count. A 5xx usually comes from a gateway in front of the
+/// relayer (e.g. a 504 timeout), which may have passed the transaction on.
+fn is_definite_rejection(error: &Error) -> bool {
+    matches!(error, Error::Relayer { status, .. } if (400..500).contains(status))
+}
 
-    let mut mac = HmacSha256::new_from_slice(&secret_bytes)
-        .map_err(|e| Error::Signing(format!("HMAC error: {}", e)))?;
-    mac.update(message.as_bytes());
+/// Index set redeeming a single position's outcome
+fn outcome_index_set(outcome_index: u32) -> Result<u32> {
+    IndexSet::from_outcome_index(outcome_index).ok_or_else(|| {
+        Error::InvalidParameter(format!(
+            "Outcome index {} does not fit in an index set",
+            outcome_index
+        ))
+    })
+}
 
-    // Use URL-safe base64 encoding for the signature (matching TypeScript SDK)
-    // TypeScript converts '+' to '-' and '/' to '_' but keeps '=' padding
-    let signature = base64::Engine::encode(
-        &base64::engine::general_purpose::STANDARD,
-        mac.finalize().into_bytes(),
-    );
+/// Turn accepted Safe transactions queued behind an unused nonce into errors
+///
+/// Each entry holds the nonce a transaction was signed with, if any. A failed
+/// transaction leaves its nonce unused unless the relayer reported it as
+/// already used, or another transaction in `results` was accepted with it.
+/// Returns true if a nonce was left unused.
+fn mark_nonce_gaps(results: &mut [(String, Option<u64>, Result<RelayerSubmitResponse>)]) -> bool {
+    let accepted: Vec<u64> = results
+        .iter()
+        .filter(|(_, _, result)| result.is_ok())
+        .filter_map(|(_, nonce, _)| *nonce)
+        .collect();
+    let gap = results
+        .iter()
+        .filter_map(|(_, nonce, result)| match (nonce, result) {
+            (
+                _,
+                Err(Error::Relayer {
+                    error: RelayerError::NonceTooLow(_),
+                    ..
+                }),
+            ) => None,
+            (Some(nonce), Err(_)) if !accepted.contains(nonce) => Some(*nonce),
+            _ => None,
+        })
+        .min();
+    let Some(gap) = gap else {
+        return false;
+    };
 
-    // Convert to URL-safe: '+' -> '-', '/' -> '_'
-    let signature = signature.replace('+', "-").replace('/', "_");
+    for (_, nonce, result) in results.iter_mut() {
+        if let (Some(nonce), Ok(response)) = (*nonce, &*result) {
+            if nonce > gap {
+                *result = Err(Error::NonceGap {
+                    nonce: gap,
+                    transaction_id: response.transaction_id.clone(),
+                });
+            }
+        }
+    }
 
-    Ok(BuilderHeaders {
-        api_key: creds.key.clone(),
-        signature,
-        timestamp: timestamp_str,
-        passphrase: creds.passphrase.clone(),
-    })
+    true
+}
+
+/// Pair complementary mergeable positions by condition ID
+///
+/// Only conditions holding both outcomes of a binary market are returned, with
+/// the smaller size converted to smallest units (6 decimals, rounded down).
+fn pair_mergeable_positions(positions: Vec<PositionData>) -> Result<Vec<MergeablePosition>> {
+    let mut pairs: Vec<(PositionData, [Option<Decimal>; 2])> = Vec::new();
+
+    for position in positions.into_iter().filter(|p| p.mergeable) {
+        let index = position.outcome_index as usize;
+        if index > 1 {
+            continue;
+        }
+        let size = Decimal::from_str(&position.size).map_err(|e| {
+            Error::InvalidParameter(format!("Invalid position size {}: {}", position.size, e))
+        })?;
+
+        match pairs
+            .iter_mut()
+            .find(|(p, _)| p.condition_id == position.condition_id)
+        {
+            Some((_, sizes)) => sizes[index] = Some(size),
+            None => {
+                let mut sizes = [None, None];
+                sizes[index] = Some(size);
+                pairs.push((position, sizes));
+            }
+        }
+    }
+
+    Ok(pairs
+        .into_iter()
+        .filter_map(|(position, sizes)| {
+            let amount = sizes[0]?.min(sizes[1]?) * Decimal::from(1_000_000);
+            let amount = amount.trunc();
+            if amount.is_zero() {
+                return None;
+            }
+            Some(MergeablePosition {
+                condition_id: position.condition_id,
+                title: position.title,
+                amount: amount.to_string(),
+                negative_risk: position.negative_risk,
+            })
+        })
+        .collect())
 }
 
 /// Derive Safe wallet address from signer address
-pub fn derive_safe_address(address: &str, safe_factory: &str) -> String {
-    let address = address.to_lowercase();
-    let address = if address.starts_with("0x") {
-        &address[2..]
-    } else {
-        &address
-    };
+///
+/// Returns lowercase hex for parity with the TypeScript SDK; see
+/// [`derive_safe_address_checksummed`] for EIP-55 casing.
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `address` or `safe_factory` is not a
+/// valid address.
+pub fn derive_safe_address(address: &str, safe_factory: &str) -> Result<String> {
+    Ok(format!(
+        "0x{}",
+        hex::encode(safe_address(address, safe_factory)?)
+    ))
+}
 
-    // Encode the address for salt calculation: keccak256(abi.encode(address))
-    let mut padded_address = vec![0u8; 12]; // 12 bytes of padding
-    padded_address.extend(hex::decode(address).unwrap_or_default());
-    let salt = keccak256(&padded_address);
-
-    // CREATE2 address calculation
-    let factory = safe_factory.to_lowercase();
-    let factory = if factory.starts_with("0x") {
-        &factory[2..]
-    } else {
-        &factory
-    };
+/// Derive Safe wallet address from signer address with EIP-55 casing
+pub fn derive_safe_address_checksummed(address: &str, safe_factory: &str) -> Result<String> {
+    Ok(safe_address(address, safe_factory)?.to_checksum(None))
+}
 
-    let init_code_hash = if SAFE_INIT_CODE_HASH.starts_with("0x") {
-        &SAFE_INIT_CODE_HASH[2..]
-    } else {
-        SAFE_INIT_CODE_HASH
-    };
+/// CREATE2 address of the Safe, salted with keccak256(abi.encode(address))
+fn safe_address(address: &str, safe_factory: &str) -> Result<Address> {
+    let address = validate_address(address)?;
+    let factory = validate_address(safe_factory)?;
+    let salt = keccak256(address.into_word());
+
+    Ok(factory.create2(salt, init_code_hash(SAFE_INIT_CODE_HASH)?))
+}
+
+/// Derive proxy wallet address from signer address
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `address` or `proxy_factory` is not a
+/// valid address.
+pub fn derive_proxy_address(address: &str, proxy_factory: &str) -> Result<String> {
+    let address = validate_address(address)?;
+    let factory = validate_address(proxy_factory)?;
+
+    // Salt is keccak256(abi.encodePacked(address))
+    let salt = keccak256(address.as_slice());
+    let proxy = factory.create2(salt, init_code_hash(PROXY_INIT_CODE_HASH)?);
+
+    Ok(format!("0x{}", hex::encode(proxy)))
+}
+
+fn init_code_hash(value: &str) -> Result<B256> {
+    B256::from_str(value).map_err(|e| Error::Config(format!("Invalid init code hash: {}", e)))
+}
+
+/// Create struct hash for proxy wallet transaction
+///
+/// Hash = keccak256("rlx:" || from || to || data || txFee || gasPrice || gasLimit || nonce || relayHub || relay)
+#[allow(clippy::too_many_arguments)]
+fn create_proxy_struct_hash(
+    from: &str,
+    to: &str,
+    data: &str,
+    tx_fee: &str,
+    gas_price: &str,
+    gas_limit: &str,
+    nonce: &str,
+    relay_hub: &str,
+    relay: &str,
+) -> Result<B256> {
+    let mut hash_data = b"rlx:".to_vec();
+    hash_data.extend(validate_address(from)?.as_slice());
+    hash_data.extend(validate_address(to)?.as_slice());
+    hash_data.extend(decode_data(data)?);
+    hash_data.extend(encode_uint256(tx_fee)?);
+    hash_data.extend(encode_uint256(gas_price)?);
+    hash_data.extend(encode_uint256(gas_limit)?);
+    hash_data.extend(encode_uint256(nonce)?);
+    hash_data.extend(validate_address(relay_hub)?.as_slice());
+    hash_data.extend(validate_address(relay)?.as_slice());
+    Ok(keccak256(&hash_data))
+}
+
+/// Encode transactions as a call to the proxy wallet's `proxy(calls)` function
+fn encode_proxy_transactions(transactions: &[SafeTransaction]) -> Result<String> {
+    let calls = transactions
+        .iter()
+        .map(|tx| {
+            let to = tx.to.parse::<Address>().map_err(|e| {
+                Error::InvalidParameter(format!("Invalid transaction address {}: {}", tx.to, e))
+            })?;
+            let value = U256::from_str_radix(&tx.value, 10).map_err(|e| {
+                Error::InvalidParameter(format!("Invalid transaction value {}: {}", tx.value, e))
+            })?;
+            let data = decode_data(&tx.data)?;
 
-    let mut data = vec![0xff];
-    data.extend(hex::decode(factory).unwrap_or_default());
-    data.extend(salt.as_slice());
-    data.extend(hex::decode(init_code_hash).unwrap_or_default());
+            // Proxy call types: 1 = CALL, 2 = DELEGATECALL
+            let type_code = match tx.operation {
+                OperationType::Call => 1,
+                OperationType::DelegateCall => 2,
+            };
 
-    let hash = keccak256(&data);
-    format!("0x{}", hex::encode(&hash[12..]))
+            Ok(ProxyCall {
+                typeCode: type_code,
+                to,
+                value,
+                data: Bytes::from(data),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let encoded = proxyCall { calls }.abi_encode();
+    Ok(format!("0x{}", hex::encode(encoded)))
+}
+
+/// Record the relayer's transaction ID, or the failure, on the current span
+#[cfg(feature = "tracing")]
+fn record_transaction_id(result: &Result<RelayerSubmitResponse>) {
+    match result {
+        Ok(response) => {
+            tracing::Span::current().record("transaction_id", response.transaction_id.as_str());
+        }
+        Err(e) => tracing::warn!(error = %e, "Relayer submission failed"),
+    }
 }
 
 /// Create struct hash for Safe creation
@@ -584,17 +2023,17 @@ fn create_safe_create_struct_hash(
     payment_token: &str,
     payment: &str,
     payment_receiver: &str,
-) -> B256 {
+) -> Result<B256> {
     // CreateProxy type hash
     let type_hash =
         keccak256(b"CreateProxy(address paymentToken,uint256 payment,address paymentReceiver)");
 
     // Encode payment token
-    let payment_token_bytes = encode_address(payment_token);
+    let payment_token_bytes = encode_address(payment_token)?;
     // Encode payment
-    let payment_bytes = encode_uint256(payment);
+    let payment_bytes = encode_uint256(payment)?;
     // Encode payment receiver
-    let payment_receiver_bytes = encode_address(payment_receiver);
+    let payment_receiver_bytes = encode_address(payment_receiver)?;
 
     // struct hash = keccak256(typeHash || encoded_values)
     let mut struct_data = type_hash.to_vec();
@@ -604,16 +2043,17 @@ fn create_safe_create_struct_hash(
     let struct_hash = keccak256(&struct_data);
 
     // Domain separator
-    let domain_separator = make_domain_separator(SAFE_FACTORY_NAME, safe_factory, chain_id);
+    let domain_separator = make_domain_separator(SAFE_FACTORY_NAME, safe_factory, chain_id)?;
 
     // Final hash = keccak256(0x19 || 0x01 || domainSeparator || structHash)
     let mut final_data = vec![0x19, 0x01];
     final_data.extend(domain_separator.as_slice());
     final_data.extend(struct_hash.as_slice());
-    keccak256(&final_data)
+    Ok(keccak256(&final_data))
 }
 
 /// Create struct hash for Safe transaction
+#[allow(clippy::too_many_arguments)]
 fn create_safe_struct_hash(
     chain_id: u64,
     safe: &str,
@@ -627,89 +2067,76 @@ fn create_safe_struct_hash(
     gas_token: &str,
     refund_receiver: &str,
     nonce: &str,
-) -> B256 {
+) -> Result<B256> {
     // SafeTx type hash
     let type_hash = keccak256(
         b"SafeTx(address to,uint256 value,bytes data,uint8 operation,uint256 safeTxGas,uint256 baseGas,uint256 gasPrice,address gasToken,address refundReceiver,uint256 nonce)",
     );
 
     // Encode data hash
-    let data_bytes = if data.starts_with("0x") {
-        hex::decode(&data[2..]).unwrap_or_default()
-    } else {
-        hex::decode(data).unwrap_or_default()
-    };
-    let data_hash = keccak256(&data_bytes);
+    let data_hash = keccak256(decode_data(data)?);
 
     // Build struct hash
     let mut struct_data = type_hash.to_vec();
-    struct_data.extend(encode_address(to));
-    struct_data.extend(encode_uint256(value));
+    struct_data.extend(encode_address(to)?);
+    struct_data.extend(encode_uint256(value)?);
     struct_data.extend(data_hash.as_slice());
     struct_data.extend(encode_uint8(operation as u8));
-    struct_data.extend(encode_uint256(safe_tx_gas));
-    struct_data.extend(encode_uint256(base_gas));
-    struct_data.extend(encode_uint256(gas_price));
-    struct_data.extend(encode_address(gas_token));
-    struct_data.extend(encode_address(refund_receiver));
-    struct_data.extend(encode_uint256(nonce));
+    struct_data.extend(encode_uint256(safe_tx_gas)?);
+    struct_data.extend(encode_uint256(base_gas)?);
+    struct_data.extend(encode_uint256(gas_price)?);
+    struct_data.extend(encode_address(gas_token)?);
+    struct_data.extend(encode_address(refund_receiver)?);
+    struct_data.extend(encode_uint256(nonce)?);
 
     let struct_hash = keccak256(&struct_data);
 
     // Domain separator for Safe (no name, just chainId and verifyingContract)
-    let domain_separator = make_safe_domain_separator(safe, chain_id);
+    let domain_separator = make_safe_domain_separator(safe, chain_id)?;
 
     // Final hash
     let mut final_data = vec![0x19, 0x01];
     final_data.extend(domain_separator.as_slice());
     final_data.extend(struct_hash.as_slice());
-    keccak256(&final_data)
+    Ok(keccak256(&final_data))
 }
 
-fn make_domain_separator(name: &str, verifying_contract: &str, chain_id: u64) -> B256 {
+fn make_domain_separator(name: &str, verifying_contract: &str, chain_id: u64) -> Result<B256> {
     let type_hash =
         keccak256(b"EIP712Domain(string name,address verifyingContract,uint256 chainId)");
     let name_hash = keccak256(name.as_bytes());
 
     let mut data = type_hash.to_vec();
     data.extend(name_hash.as_slice());
-    data.extend(encode_address(verifying_contract));
-    data.extend(encode_uint256(&chain_id.to_string()));
+    data.extend(encode_address(verifying_contract)?);
+    data.extend(U256::from(chain_id).to_be_bytes::<32>());
 
-    keccak256(&data)
+    Ok(keccak256(&data))
 }
 
-fn make_safe_domain_separator(safe: &str, chain_id: u64) -> B256 {
+fn make_safe_domain_separator(safe: &str, chain_id: u64) -> Result<B256> {
     // Safe uses a domain separator with just chainId and verifyingContract (no name)
     let type_hash = keccak256(b"EIP712Domain(uint256 chainId,address verifyingContract)");
 
     let mut data = type_hash.to_vec();
-    data.extend(encode_uint256(&chain_id.to_string()));
-    data.extend(encode_address(safe));
+    data.extend(U256::from(chain_id).to_be_bytes::<32>());
+    data.extend(encode_address(safe)?);
 
-    keccak256(&data)
+    Ok(keccak256(&data))
 }
 
-fn encode_address(addr: &str) -> [u8; 32] {
-    let addr = if addr.starts_with("0x") {
-        &addr[2..]
-    } else {
-        addr
-    };
+fn encode_address(addr: &str) -> Result<[u8; 32]> {
+    Ok(validate_address(addr)?.into_word().0)
+}
 
-    let mut result = [0u8; 32];
-    let bytes = hex::decode(addr).unwrap_or_default();
-    if bytes.len() <= 20 {
-        result[32 - bytes.len()..].copy_from_slice(&bytes);
-    }
-    result
+/// Decode `0x`-prefixed transaction calldata
+fn decode_data(data: &str) -> Result<Vec<u8>> {
+    hex::decode(data.strip_prefix("0x").unwrap_or(data))
+        .map_err(|e| Error::InvalidParameter(format!("Invalid transaction data {}: {}", data, e)))
 }
 
-fn encode_uint256(value: &str) -> [u8; 32] {
-    let value = value.parse::<u128>().unwrap_or(0);
-    let mut result = [0u8; 32];
-    result[16..].copy_from_slice(&value.to_be_bytes());
-    result
+fn encode_uint256(value: &str) -> Result<[u8; 32]> {
+    Ok(parse_uint256(value)?.to_be_bytes())
 }
 
 fn encode_uint8(value: u8) -> [u8; 32] {
@@ -718,88 +2145,2074 @@ fn encode_uint8(value: u8) -> [u8; 32] {
     result
 }
 
-fn sign_eip712_struct_hash(signer: &dyn EthSigner, hash: &B256) -> Result<String> {
-    // Sign the EIP-712 hash using signMessage (eth_sign style)
-    // This adds EIP-191 prefix internally: keccak256("\x19Ethereum Signed Message:\n32" + hash)
-    // Safe contract expects v >= 31 for eth_sign style signatures
+fn eoa_wallet_error() -> Error {
+    Error::Config(
+        "EOA signers have no relayer wallet; use SignatureType::PolyGnosisSafe or PolyProxy".into(),
+    )
+}
+
+/// Sign a relayer struct hash for the given wallet type
+///
+/// The hash is signed with `signMessage` (eth_sign style), which applies the
+/// EIP-191 prefix: keccak256("\x19Ethereum Signed Message:\n32" + hash).
+/// The v-value then depends on who verifies the signature:
+///
+/// - Safe: the Safe contract treats v >= 31 as an eth_sign signature and
+///   recovers with v - 4, so v is shifted to 31/32.
+/// - Proxy: the relay hub recovers the prefixed hash with plain ecrecover, so
+///   v stays 27/28.
+///
+/// Standard EIP-712 signatures over the `0x19 0x01` digest, without the prefix
+/// or v shift, are produced by [`sign_typed_data_hash`](crate::signing::sign_typed_data_hash).
+async fn sign_struct_hash(
+    signer: &dyn EthSigner,
+    hash: &B256,
+    wallet: RelayerWallet,
+) -> Result<String> {
     let signature = signer
-        .sign_message_sync(hash.as_slice())
+        .sign_message(hash.as_slice())
+        .await
         .map_err(|e| Error::Signing(e.to_string()))?;
 
-    // Adjust v-value for Safe contract's eth_sign verification
-    // Safe contract: when v >= 31, it computes: ecrecover(keccak256("\x19Ethereum..." + dataHash), v - 4, r, s)
-    // This matches the EIP-191 prefix that signMessage already added
     let mut sig_bytes = signature.as_bytes().to_vec();
     let v = sig_bytes[64];
-    sig_bytes[64] = match v {
-        0 => 31,    // 0 -> 31 (for eth_sign)
-        1 => 32,    // 1 -> 32 (for eth_sign)
-        27 => 31,   // 27 -> 31 (27 + 4 = 31)
-        28 => 32,   // 28 -> 32 (28 + 4 = 32)
-        _ => v + 4, // Generic case
+    sig_bytes[64] = match wallet {
+        RelayerWallet::Safe => match v {
+            0 | 1 => v + 31,
+            _ => v + 4,
+        },
+        RelayerWallet::Proxy => match v {
+            0 | 1 => v + 27,
+            _ => v,
+        },
     };
 
     Ok(format!("0x{}", hex::encode(sig_bytes)))
 }
 
 /// Aggregate multiple transactions into a single multisend transaction
+///
+/// Fails with [`Error::InvalidParameter`] naming the index of the first
+/// transaction with a malformed `to`, `value`, or `data`.
 fn aggregate_transactions(
     transactions: &[SafeTransaction],
     multisend_address: &str,
-) -> SafeTransaction {
+) -> Result<SafeTransaction> {
     // Encode each transaction for multisend
     let mut encoded_txs = Vec::new();
 
-    for tx in transactions {
-        // operation (1 byte) + to (20 bytes) + value (32 bytes) + dataLength (32 bytes) + data
-        let to_bytes = hex::decode(tx.to.trim_start_matches("0x")).unwrap_or_default();
-        let value: u128 = tx.value.parse().unwrap_or(0);
-        let data_bytes = hex::decode(tx.data.trim_start_matches("0x")).unwrap_or_default();
+    for (index, tx) in transactions.iter().enumerate() {
+        let invalid = |what: &str, value: &str| {
+            Error::InvalidParameter(format!(
+                "Invalid {} in transaction {}: {}",
+                what, index, value
+            ))
+        };
+
+        // Require a 0x-prefixed, 20-byte hex address
+        let to = tx
+            .to
+            .strip_prefix("0x")
+            .filter(|hex| hex.len() == 40)
+            .and_then(|hex| hex.parse::<Address>().ok())
+            .ok_or_else(|| invalid("address", &tx.to))?;
+        let value = U256::from_str_radix(&tx.value, 10).map_err(|_| invalid("value", &tx.value))?;
+        let data_bytes =
+            hex::decode(tx.data.trim_start_matches("0x")).map_err(|_| invalid("data", &tx.data))?;
 
+        // operation (1 byte) + to (20 bytes) + value (32 bytes) + dataLength (32 bytes) + data
         encoded_txs.push(tx.operation as u8);
-        // Pad to address to 20 bytes
-        let mut to_padded = vec![0u8; 20 - to_bytes.len().min(20)];
-        to_padded.extend(&to_bytes[..to_bytes.len().min(20)]);
-        encoded_txs.extend(&to_padded);
-        // Value as 32 bytes big-endian
-        let mut value_bytes = vec![0u8; 16];
-        value_bytes.extend(&value.to_be_bytes());
-        encoded_txs.extend(&value_bytes);
-        // Data length as 32 bytes big-endian
-        let data_len = data_bytes.len() as u128;
-        let mut len_bytes = vec![0u8; 16];
-        len_bytes.extend(&data_len.to_be_bytes());
-        encoded_txs.extend(&len_bytes);
-        // Data
+        encoded_txs.extend_from_slice(to.as_slice());
+        encoded_txs.extend_from_slice(&value.to_be_bytes::<32>());
+        encoded_txs.extend_from_slice(&U256::from(data_bytes.len()).to_be_bytes::<32>());
         encoded_txs.extend(&data_bytes);
     }
 
     // Create multisend call: multiSend(bytes transactions)
     // Function selector: 0x8d80ff0a
-    let selector = hex::decode("8d80ff0a").unwrap();
+    let mut multisend_data = hex::decode("8d80ff0a").unwrap();
 
     // Encode as bytes: offset (32 bytes) + length (32 bytes) + data (padded to 32 bytes)
-    let offset: u128 = 32;
-    let length = encoded_txs.len() as u128;
-
-    let mut multisend_data = selector;
-    // Offset
-    let mut offset_bytes = vec![0u8; 16];
-    offset_bytes.extend(&offset.to_be_bytes());
-    multisend_data.extend(&offset_bytes);
-    // Length
-    let mut len_bytes = vec![0u8; 16];
-    len_bytes.extend(&length.to_be_bytes());
-    multisend_data.extend(&len_bytes);
+    multisend_data.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+    multisend_data.extend_from_slice(&U256::from(encoded_txs.len()).to_be_bytes::<32>());
     // Data (padded to 32-byte boundary)
     multisend_data.extend(&encoded_txs);
     let padding = (32 - (encoded_txs.len() % 32)) % 32;
     multisend_data.extend(vec![0u8; padding]);
 
-    SafeTransaction {
+    Ok(SafeTransaction {
         to: multisend_address.to_string(),
         operation: OperationType::DelegateCall,
         data: format!("0x{}", hex::encode(&multisend_data)),
         value: "0".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+    use alloy_signer_local::PrivateKeySigner;
+
+    const EOA: &str = "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5";
+
+    fn eoa_checksummed() -> String {
+        EOA.parse::<Address>().unwrap().to_checksum(None)
+    }
+
+    #[test]
+    fn test_derive_proxy_address_vectors() {
+        let factory = "0xaB45c5A4B0c941a2F231C04C3f49182e1A254052";
+        // From scripts/relayer_vectors.py, which follows the SDK's
+        // getCreate2Address with its own keccak256
+        for (eoa, proxy) in [
+            (EOA, "0x410afd66773504329b0d4733ac407914a0840da8"),
+            (
+                "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+                "0x365f0ca36ae1f641e02fe3b7743673da42a13a70",
+            ),
+            (
+                "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+                "0xd9d24e482c11f586cd9a1a53dc3eec6de3883862",
+            ),
+        ] {
+            assert_eq!(derive_proxy_address(eoa, factory).unwrap(), proxy);
+        }
+        // Address casing must not change the result
+        assert_eq!(
+            derive_proxy_address(&EOA.to_uppercase().replace("0X", "0x"), factory).unwrap(),
+            "0x410afd66773504329b0d4733ac407914a0840da8"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_http_client_uses_injected_client() {
+        let server = MockServer::start(vec![(200, r#"{"deployed":true}"#.to_string())]).await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-shared-client", "1".parse().unwrap());
+        let http_client = Client::builder().default_headers(headers).build().unwrap();
+
+        let client = RelayerClient::with_http_client(
+            http_client,
+            format!("{}/", server.url()),
+            137,
+            None::<PrivateKeySigner>,
+            None,
+        )
+        .unwrap();
+
+        assert!(client.get_deployed(EOA).await.unwrap());
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, format!("/deployed?address={}", EOA));
+        assert_eq!(requests[0].header("x-shared-client"), Some("1"));
+    }
+
+    #[test]
+    fn test_derive_rejects_invalid_addresses() {
+        let factory = &mainnet_relayer_config().safe_factory;
+
+        assert!(matches!(
+            derive_safe_address("0xzz", factory),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(derive_safe_address_checksummed(EOA, "0x1234").is_err());
+        assert!(derive_proxy_address(&EOA[..40], factory).is_err());
+    }
+
+    #[test]
+    fn test_exchange_matches_contract_config() {
+        use crate::config::{chains, get_contract_config};
+
+        for chain_id in [chains::POLYGON_MAINNET, chains::POLYGON_AMOY_TESTNET] {
+            let relayer_config = get_relayer_config(chain_id).unwrap();
+            let contract_config = get_contract_config(chain_id, false).unwrap();
+            assert_eq!(relayer_config.exchange, contract_config.exchange);
+        }
+    }
+
+    #[test]
+    fn test_derive_safe_address_checksummed() {
+        let factory = &mainnet_relayer_config().safe_factory;
+        let lowercase = derive_safe_address(EOA, factory).unwrap();
+        let checksummed = derive_safe_address_checksummed(EOA, factory).unwrap();
+
+        // Safe of EOA under the mainnet factory
+        assert_eq!(checksummed, "0x6d8c4e9aDF5748Af82Dabe2C6225207770d6B4fa");
+        assert_eq!(checksummed.to_lowercase(), lowercase);
+        // Input casing does not matter
+        assert_eq!(
+            derive_safe_address_checksummed(&EOA.to_uppercase().replace("0X", "0x"), factory)
+                .unwrap(),
+            checksummed
+        );
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let client = RelayerClient::new("http://localhost", 137, Some(signer), None).unwrap();
+        assert_eq!(
+            client
+                .get_expected_safe_checksummed()
+                .unwrap()
+                .to_lowercase(),
+            client.get_expected_safe().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_relayer_config_addresses_are_valid() {
+        for config in [mainnet_relayer_config(), amoy_relayer_config()] {
+            for address in [
+                &config.safe_factory,
+                &config.safe_multisend,
+                &config.ctf,
+                &config.collateral,
+                &config.exchange,
+                &config.neg_risk_adapter,
+            ] {
+                validate_address(address).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_mainnet_neg_risk_adapter() {
+        let config = mainnet_relayer_config();
+        assert_eq!(
+            config.neg_risk_adapter,
+            "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"
+        );
+        assert_ne!(config.neg_risk_adapter, config.ctf);
+    }
+
+    #[test]
+    fn test_encode_proxy_transactions() {
+        let transactions = vec![SafeTransaction {
+            to: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
+            operation: OperationType::Call,
+            data: "0xdeadbeef".to_string(),
+            value: "0".to_string(),
+        }];
+
+        let encoded = encode_proxy_transactions(&transactions).unwrap();
+        let selector = &keccak256("proxy((uint8,address,uint256,bytes)[])")[..4];
+        assert!(encoded.starts_with(&format!("0x{}", hex::encode(selector))));
+
+        let decoded = proxyCall::abi_decode(&hex::decode(&encoded[2..]).unwrap()).unwrap();
+        assert_eq!(decoded.calls.len(), 1);
+        assert_eq!(decoded.calls[0].typeCode, 1);
+        assert_eq!(decoded.calls[0].data.as_ref(), &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test_encode_proxy_transactions_invalid_address() {
+        let transactions = vec![SafeTransaction {
+            to: "not-an-address".to_string(),
+            operation: OperationType::Call,
+            data: "0x".to_string(),
+            value: "0".to_string(),
+        }];
+        assert!(encode_proxy_transactions(&transactions).is_err());
+    }
+
+    fn multisend_tx(to: &str, value: &str, data: &str) -> SafeTransaction {
+        SafeTransaction {
+            to: to.to_string(),
+            operation: OperationType::Call,
+            data: data.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_transactions_layout() {
+        let to = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let max = U256::MAX.to_string();
+        let transactions = vec![
+            multisend_tx(to, "1", "0xdeadbeef"),
+            multisend_tx(to, &max, "0x"),
+        ];
+
+        let multisend = aggregate_transactions(&transactions, EOA).unwrap();
+        assert_eq!(multisend.to, EOA);
+        assert_eq!(multisend.operation, OperationType::DelegateCall);
+
+        let data = hex::decode(&multisend.data[2..]).unwrap();
+        assert_eq!(&data[..4], &[0x8d, 0x80, 0xff, 0x0a]);
+        assert_eq!(U256::from_be_slice(&data[4..36]), U256::from(32));
+        // Two packed transactions: 85 bytes of header each plus 4 bytes of data
+        assert_eq!(U256::from_be_slice(&data[36..68]), U256::from(85 * 2 + 4));
+
+        let packed = &data[68..];
+        assert_eq!(packed[0], 0);
+        assert_eq!(&packed[1..21], to.parse::<Address>().unwrap().as_slice());
+        assert_eq!(U256::from_be_slice(&packed[21..53]), U256::from(1));
+        assert_eq!(U256::from_be_slice(&packed[53..85]), U256::from(4));
+        assert_eq!(&packed[85..89], &[0xde, 0xad, 0xbe, 0xef]);
+        // Values above u128::MAX keep all 32 bytes
+        assert_eq!(U256::from_be_slice(&packed[110..142]), U256::MAX);
+        assert_eq!(data.len() % 32, 4);
+    }
+
+    fn multisend_vector_transactions() -> Vec<SafeTransaction> {
+        // USDC approve for the CTF: 68 bytes of data
+        let approve = format!(
+            "0x095ea7b3{:0>64}{}",
+            "4d97dcd97ec945f40cf65f87097ace5ea0476045",
+            "f".repeat(64)
+        );
+        vec![
+            multisend_tx("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174", "0", &approve),
+            multisend_tx(
+                "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045",
+                "1000000000000000000",
+                "0x",
+            ),
+            SafeTransaction {
+                operation: OperationType::DelegateCall,
+                ..multisend_tx(
+                    "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296",
+                    "0",
+                    "0xabcdef",
+                )
+            },
+        ]
+    }
+
+    sol! {
+        function multiSend(bytes transactions);
+    }
+
+    /// MultiSend calldata built with alloy's ABI encoder: each transaction is
+    /// `encodePacked(uint8, address, uint256, uint256, bytes)` and the
+    /// concatenation is ABI-encoded as `multiSend(bytes)`
+    fn alloy_multisend_calldata(transactions: &[SafeTransaction]) -> String {
+        use alloy_sol_types::{sol_data, SolType};
+
+        type Packed = (
+            sol_data::Uint<8>,
+            sol_data::Address,
+            sol_data::Uint<256>,
+            sol_data::Uint<256>,
+            sol_data::Bytes,
+        );
+        let packed: Vec<u8> = transactions
+            .iter()
+            .flat_map(|tx| {
+                let data = Bytes::from(hex::decode(&tx.data).unwrap());
+                Packed::abi_encode_packed(&(
+                    tx.operation as u8,
+                    tx.to.parse::<Address>().unwrap(),
+                    tx.value.parse::<U256>().unwrap(),
+                    U256::from(data.len()),
+                    data,
+                ))
+            })
+            .collect();
+        hex::encode_prefixed(
+            multiSendCall {
+                transactions: packed.into(),
+            }
+            .abi_encode(),
+        )
+    }
+
+    #[test]
+    fn test_aggregate_transactions_exact_calldata() {
+        let transactions = multisend_vector_transactions();
+
+        for count in 1..=3 {
+            let multisend = aggregate_transactions(&transactions[..count], EOA).unwrap();
+            assert_eq!(
+                multisend.data,
+                alloy_multisend_calldata(&transactions[..count]),
+                "{} transaction(s)",
+                count
+            );
+            assert!(multisend.data.starts_with("0x8d80ff0a"));
+            assert_eq!(multisend.to, EOA);
+            assert_eq!(multisend.value, "0");
+            assert_eq!(multisend.operation, OperationType::DelegateCall);
+            // Packed lengths of 153, 238 and 326 bytes are all padded to a word
+            assert_eq!((multisend.data.len() - 10) % 64, 0);
+        }
+    }
+
+    #[test]
+    fn test_aggregate_transactions_rejects_long_address() {
+        let to = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let transactions = vec![
+            multisend_tx(to, "0", "0x"),
+            multisend_tx(&format!("{}00", to), "0", "0x"),
+        ];
+
+        match aggregate_transactions(&transactions, EOA) {
+            Err(Error::InvalidParameter(msg)) => {
+                assert!(msg.contains("address in transaction 1"), "{}", msg)
+            }
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        }
+
+        // Addresses must be 0x-prefixed
+        let transactions = vec![multisend_tx(&to[2..], "0", "0x")];
+        assert!(aggregate_transactions(&transactions, EOA).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_transactions_rejects_bad_value_and_data() {
+        let to = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let overflow = format!("{}0", U256::MAX);
+
+        for (tx, what) in [
+            (multisend_tx(to, &overflow, "0x"), "value"),
+            (multisend_tx(to, "-1", "0x"), "value"),
+            (multisend_tx(to, "0", "0xzz"), "data"),
+        ] {
+            match aggregate_transactions(&[tx], EOA) {
+                Err(Error::InvalidParameter(msg)) => {
+                    assert!(
+                        msg.contains(&format!("{} in transaction 0", what)),
+                        "{}",
+                        msg
+                    )
+                }
+                other => panic!("expected InvalidParameter, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_uint256_full_width() {
+        let value = U256::from(u128::MAX) * U256::from(3);
+        assert_eq!(
+            encode_uint256(&value.to_string()).unwrap(),
+            value.to_be_bytes::<32>()
+        );
+        assert_eq!(encode_uint256("0xff").unwrap()[31], 0xff);
+        assert!(matches!(
+            encode_uint256("12abc"),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_proxy_struct_hash_layout() {
+        let from = EOA;
+        let to = "0xaB45c5A4B0c941a2F231C04C3f49182e1A254052";
+        let relay_hub = "0xD216153c06E857cD7f72665E0aF1d7D82172F494";
+        let relay = "0x0000000000000000000000000000000000000001";
+
+        let hash = create_proxy_struct_hash(
+            from, to, "0xabcd", "0", "0", "10000000", "5", relay_hub, relay,
+        )
+        .unwrap();
+
+        let mut expected = b"rlx:".to_vec();
+        expected.extend(from.parse::<Address>().unwrap().as_slice());
+        expected.extend(to.parse::<Address>().unwrap().as_slice());
+        expected.extend([0xab, 0xcd]);
+        expected.extend(U256::ZERO.to_be_bytes::<32>());
+        expected.extend(U256::ZERO.to_be_bytes::<32>());
+        expected.extend(U256::from(10_000_000u64).to_be_bytes::<32>());
+        expected.extend(U256::from(5u64).to_be_bytes::<32>());
+        expected.extend(relay_hub.parse::<Address>().unwrap().as_slice());
+        expected.extend(relay.parse::<Address>().unwrap().as_slice());
+
+        assert_eq!(hash, keccak256(&expected));
+    }
+
+    // Well-known development key (anvil/hardhat account #0)
+    const TEST_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn decode_signature(signature: &str) -> Vec<u8> {
+        hex::decode(signature.trim_start_matches("0x")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_execute_preview_matches_submitted_request() {
+        let responses = || {
+            vec![
+                (200, r#"{"deployed":true}"#.to_string()),
+                (200, r#"{"nonce":"7"}"#.to_string()),
+            ]
+        };
+        let mut all = responses();
+        all.extend(responses());
+        all.push((200, r#"{"transactionID":"abc"}"#.to_string()));
+        let server = MockServer::start(all).await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let condition_id = format!("0x{}", "ab".repeat(32));
+        let data = CtfEncoder::encode_redeem_positions(
+            &client.contract_config.collateral,
+            &condition_id,
+            BINARY_PARTITION.to_vec(),
+        )
+        .unwrap();
+        let tx = SafeTransaction::new(&client.contract_config.ctf, data);
+
+        let preview = client
+            .execute_preview(vec![tx.clone()], Some("redeem"))
+            .await
+            .unwrap();
+        assert_eq!(preview.request.nonce.as_deref(), Some("7"));
+        assert_eq!(preview.request.to, tx.to.to_lowercase());
+        assert_eq!(preview.request.data, tx.data);
+
+        // The signature commits to the digest
+        let key: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        assert_eq!(
+            preview.request.signature,
+            sign_struct_hash(&key, &preview.digest, RelayerWallet::Safe)
+                .await
+                .unwrap()
+        );
+
+        let response = client.execute(vec![tx], Some("redeem")).await.unwrap();
+        assert_eq!(response.transaction_id, "abc");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 5);
+        assert_eq!(requests[4].path, "/submit");
+        assert_eq!(
+            requests[4].body,
+            serde_json::to_string(&preview.request).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_malformed_data_before_signing() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        // A single transaction skips the multisend encoder, so its data is only
+        // decoded when hashing
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xzz");
+        assert!(matches!(
+            client.execute(vec![tx], None).await,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(server.requests().iter().all(|r| r.path != "/submit"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_remote_signer_on_current_thread_runtime() {
+        use crate::signing::RemoteSigner;
+        use alloy_signer::Signer;
+
+        let responses = || {
+            vec![
+                (200, r#"{"deployed":true}"#.to_string()),
+                (200, r#"{"nonce":"7"}"#.to_string()),
+            ]
+        };
+        let mut all = responses();
+        all.extend(responses());
+        all.push((200, r#"{"transactionID":"abc"}"#.to_string()));
+        let server = MockServer::start(all).await;
+
+        // Signing must be awaited: blocking on the callback would fail on this runtime
+        let key: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let remote_key = key.clone();
+        let signer = RemoteSigner::new(key.address(), move |hash| {
+            let key = remote_key.clone();
+            async move {
+                tokio::task::yield_now().await;
+                key.sign_hash(&hash).await
+            }
+        });
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0x");
+        let preview = client
+            .execute_preview(vec![tx.clone()], None)
+            .await
+            .unwrap();
+        assert_eq!(
+            preview.request.signature,
+            sign_struct_hash(&key, &preview.digest, RelayerWallet::Safe)
+                .await
+                .unwrap()
+        );
+
+        let response = client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(response.transaction_id, "abc");
+        assert_eq!(
+            server.requests()[4].body,
+            serde_json::to_string(&preview.request).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_nonce_skips_nonce_lookup() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"abc"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        let response = client
+            .execute_with_nonce(vec![tx.clone()], None, 42)
+            .await
+            .unwrap();
+        assert_eq!(response.transaction_id, "abc");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].path, "/submit");
+        let request: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(request["nonce"], "42");
+
+        let safe_address = client.get_expected_safe().unwrap();
+        let struct_hash = create_safe_struct_hash(
+            137,
+            &safe_address,
+            &tx.to,
+            &tx.value,
+            &tx.data,
+            tx.operation,
+            "0",
+            "0",
+            "0",
+            ZERO_ADDRESS,
+            ZERO_ADDRESS,
+            "42",
+        )
+        .unwrap();
+        let key: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        assert_eq!(
+            request["signature"],
+            sign_struct_hash(&key, &struct_hash, RelayerWallet::Safe)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_execute_records_transaction_id_on_span() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (200, r#"{"transactionID":"abc"}"#.to_string()),
+        ])
+        .await;
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        client.execute(vec![tx], None).await.unwrap();
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("submit_transaction"));
+        assert!(output.contains(r#"transaction_id="abc""#));
+        assert!(output.contains(r#"nonce="7""#));
+        assert!(output.contains(&client.get_expected_safe().unwrap()));
+        assert!(output.contains("Signing SafeTx digest"));
+        // Only the public key's address may appear, never the key itself
+        assert!(!output.contains(TEST_KEY.trim_start_matches("0x")));
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_window_skips_identical_submission() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (200, r#"{"transactionID":"abc"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_dedupe_window(Duration::from_secs(60));
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        let first = client.execute(vec![tx.clone()], None).await.unwrap();
+        let second = client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(first.transaction_id, "abc");
+        assert_eq!(second.transaction_id, "abc");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 5);
+        let submits = requests.iter().filter(|r| r.path == "/submit").count();
+        assert_eq!(submits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_window_refuses_submission_with_unknown_outcome() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            // Unreadable response, so the first outcome is unknown
+            (200, "not json".to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_dedupe_window(Duration::from_secs(60));
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        assert!(matches!(
+            client.execute(vec![tx.clone()], None).await,
+            Err(Error::Deserialization { .. })
+        ));
+        match client.execute(vec![tx], None).await {
+            Err(Error::DuplicatePending { nonce }) => assert_eq!(nonce.as_deref(), Some("7")),
+            other => panic!("expected DuplicatePending, got {:?}", other),
+        }
+
+        let submits = server
+            .requests()
+            .iter()
+            .filter(|r| r.path == "/submit")
+            .count();
+        assert_eq!(submits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_window_holds_submission_after_gateway_error() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (503, "Service Unavailable".to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_dedupe_window(Duration::from_secs(60));
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        assert!(matches!(
+            client.execute(vec![tx.clone()], None).await,
+            Err(Error::Relayer { status: 503, .. })
+        ));
+        // The gateway may have passed the first one on, so the retry is held back
+        match client.execute(vec![tx], None).await {
+            Err(Error::DuplicatePending { nonce }) => assert_eq!(nonce.as_deref(), Some("7")),
+            other => panic!("expected DuplicatePending, got {:?}", other),
+        }
+
+        let submits = server
+            .requests()
+            .iter()
+            .filter(|r| r.path == "/submit")
+            .count();
+        assert_eq!(submits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_nonce_manager_consumes_consecutive_nonces() {
+        let server = MockServer::start(vec![
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx2"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx3"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_nonce_manager(NonceManager::new());
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        for expected in ["tx1", "tx2", "tx3"] {
+            let response = client.execute(vec![tx.clone()], None).await.unwrap();
+            assert_eq!(response.transaction_id, expected);
+        }
+        assert_eq!(client.nonce_manager().unwrap().peek(), Some(10));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests
+                .iter()
+                .filter(|r| r.path.starts_with("/nonce"))
+                .count(),
+            1
+        );
+        let nonces: Vec<String> = requests
+            .iter()
+            .filter(|r| r.path == "/submit")
+            .map(|r| {
+                let body: serde_json::Value = serde_json::from_str(&r.body).unwrap();
+                body["nonce"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(nonces, ["7", "8", "9"]);
+    }
+
+    #[tokio::test]
+    async fn test_nonce_manager_resyncs_after_rejection() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (400, r#"{"error":"nonce too low"}"#.to_string()),
+            (200, r#"{"nonce":"12"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_nonce_manager(NonceManager::starting_at(5));
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        assert!(matches!(
+            client.execute(vec![tx.clone()], None).await,
+            Err(Error::Relayer {
+                error: RelayerError::NonceTooLow(_),
+                ..
+            })
+        ));
+        assert_eq!(client.nonce_manager().unwrap().peek(), None);
+
+        client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(client.nonce_manager().unwrap().peek(), Some(13));
+    }
+
+    #[tokio::test]
+    async fn test_nonce_manager_failure_with_execute_in_flight() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (400, r#"{"error":"invalid signature"}"#.to_string()),
+            (200, r#"{"nonce":"5"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_nonce_manager(NonceManager::starting_at(5));
+        let nonces = client.nonce_manager().unwrap();
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+
+        // Reserves nonce 5, then another execute takes 6 while it is in flight
+        let mut failing = Box::pin(client.execute(vec![tx.clone()], None));
+        assert!(futures_util::poll!(&mut failing).is_pending());
+        assert_eq!(nonces.reserve(), Some(6));
+
+        // Giving back 5 would hand out 6 twice, so the counter resyncs
+        assert!(matches!(
+            failing.await,
+            Err(Error::Relayer {
+                error: RelayerError::InvalidSignature(_),
+                ..
+            })
+        ));
+        assert_eq!(nonces.peek(), None);
+
+        // The relayer still expects 5, so it is used again rather than left
+        // as a gap that 6 would wait behind
+        client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(nonces.peek(), Some(6));
+
+        let submitted: Vec<String> = server
+            .requests()
+            .iter()
+            .filter(|r| r.path == "/submit")
+            .map(|r| {
+                let body: serde_json::Value = serde_json::from_str(&r.body).unwrap();
+                body["nonce"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(submitted, ["5", "5"]);
+    }
+
+    #[tokio::test]
+    async fn test_nonce_manager_resyncs_after_gateway_error() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (503, r#"{"error":"service unavailable"}"#.to_string()),
+            (200, r#"{"nonce":"6"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_nonce_manager(NonceManager::starting_at(5));
+        let nonces = client.nonce_manager().unwrap();
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+
+        // The relayer may have taken nonce 5 behind the 503, so it is not
+        // given back; the relayer's nonce is fetched instead
+        let err = client.execute(vec![tx.clone()], None).await.unwrap_err();
+        assert!(matches!(err, Error::Relayer { status: 503, .. }));
+        assert_eq!(nonces.peek(), None);
+
+        client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(nonces.peek(), Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_submit_error_is_parsed_and_can_be_retried() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (400, r#"{"error":"nonce too low"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"7"}"#.to_string()),
+            (200, r#"{"transactionID":"abc"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_dedupe_window(Duration::from_secs(60));
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        match client.execute(vec![tx.clone()], None).await {
+            Err(Error::Relayer {
+                status: 400,
+                error: RelayerError::NonceTooLow(msg),
+            }) => assert_eq!(msg, "nonce too low"),
+            other => panic!("expected NonceTooLow, got {:?}", other),
+        }
+
+        // A rejected submission is not held back by the dedupe window
+        let response = client.execute(vec![tx], None).await.unwrap();
+        assert_eq!(response.transaction_id, "abc");
+    }
+
+    #[tokio::test]
+    async fn test_sign_struct_hash_v_values() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let hash = keccak256(b"polymarket relayer");
+
+        let safe = decode_signature(
+            &sign_struct_hash(&signer, &hash, RelayerWallet::Safe)
+                .await
+                .unwrap(),
+        );
+        let proxy = decode_signature(
+            &sign_struct_hash(&signer, &hash, RelayerWallet::Proxy)
+                .await
+                .unwrap(),
+        );
+
+        // Only the v-value differs between wallet types
+        assert_eq!(safe[..64], proxy[..64]);
+        assert!(matches!(proxy[64], 27 | 28));
+        assert_eq!(safe[64], proxy[64] + 4);
+
+        // The proxy signature recovers the signer from the EIP-191 prefixed hash
+        let recovered = alloy_primitives::Signature::from_raw(&proxy)
+            .unwrap()
+            .recover_address_from_msg(hash.as_slice())
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+
+        // Exact bytes from struct_hash_vectors() in scripts/relayer_vectors.py,
+        // which signs with its own keccak256 and secp256k1 (RFC 6979, low-s)
+        let rs = "7edaf6854166c034f9b011c77ce5860646be90bd43f560d4ef7fc0311c102e11\
+                  67c2dd8b070057362932fda5143a6ad399102456112669aee834f31bb2852bae";
+        assert_eq!(hex::encode(&safe), format!("{}1f", rs));
+        assert_eq!(hex::encode(&proxy), format!("{}1b", rs));
+    }
+
+    #[tokio::test]
+    async fn test_safe_and_typed_data_conventions() {
+        use crate::signing::{sign_typed_data_hash, typed_data_digest};
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let domain_separator = keccak256(b"domain");
+        let struct_hash = keccak256(b"struct");
+        let digest = typed_data_digest(domain_separator, struct_hash);
+
+        let typed = decode_signature(
+            &sign_typed_data_hash(&signer, domain_separator, struct_hash).unwrap(),
+        );
+        let safe = decode_signature(
+            &sign_struct_hash(&signer, &digest, RelayerWallet::Safe)
+                .await
+                .unwrap(),
+        );
+
+        // Typed data: the digest itself is signed and v is left at 27/28
+        assert!(matches!(typed[64], 27 | 28));
+        let recovered = alloy_primitives::Signature::from_raw(&typed)
+            .unwrap()
+            .recover_address_from_prehash(&digest)
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+
+        // Safe: the EIP-191 prefixed digest is signed and v is shifted to 31/32
+        assert!(matches!(safe[64], 31 | 32));
+        assert_ne!(safe[..64], typed[..64]);
+        let mut unshifted = safe.clone();
+        unshifted[64] -= 4;
+        let recovered = alloy_primitives::Signature::from_raw(&unshifted)
+            .unwrap()
+            .recover_address_from_msg(digest.as_slice())
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+
+        // Exact bytes from typed_data_vectors() in scripts/relayer_vectors.py
+        assert_eq!(
+            hex::encode(&typed),
+            "3125832740effb8994933ad5df1e29dee7d4eb03200986e7618c4c2d13cafc14\
+             5f5330831d04e6be791bc817815bedf180dee612230782d6a4ef5e5c0651c9ae1c"
+        );
+        assert_eq!(
+            hex::encode(&safe),
+            "25cbc9e1cdab660e793e9f5b1ffa543b578f4120dbeaf5f354bef5b20686c2c9\
+             0de3b54af31164b638a0a0384e4aa0301438b17a1f8a808eb050399e5207591b20"
+        );
+
+        // Boxed signers, as held by the clients, sign the same bytes
+        let boxed: Box<dyn EthSigner> = Box::new(signer.clone());
+        assert_eq!(
+            decode_signature(
+                &sign_typed_data_hash(&*boxed, domain_separator, struct_hash).unwrap()
+            ),
+            typed
+        );
+    }
+
+    #[test]
+    fn test_get_expected_wallet_follows_signature_type() {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let client = RelayerClient::new("http://localhost", 137, Some(signer), None).unwrap();
+        assert_eq!(client.signature_type(), SignatureType::PolyGnosisSafe);
+        assert_eq!(
+            client.get_expected_wallet().unwrap(),
+            client.get_expected_safe().unwrap()
+        );
+
+        let client = client
+            .with_signature_type(SignatureType::PolyProxy)
+            .unwrap();
+        assert_eq!(client.signature_type(), SignatureType::PolyProxy);
+        assert_eq!(
+            client.get_expected_wallet().unwrap(),
+            client.get_expected_proxy().unwrap()
+        );
+
+        // EOAs have no relayer wallet
+        assert!(matches!(
+            client.with_signature_type(SignatureType::Eoa),
+            Err(Error::Config(_))
+        ));
+    }
+
+    fn redeemable_page(start: u32, count: u32, current_value: f64) -> (u16, String) {
+        let positions: Vec<_> = (start..start + count)
+            .map(|i| {
+                serde_json::json!({
+                    "proxyWallet": EOA,
+                    "asset": i.to_string(),
+                    "conditionId": format!("0x{:064x}", i),
+                    "size": 10,
+                    "redeemable": true,
+                    "mergeable": false,
+                    "title": "Market",
+                    "outcome": "Yes",
+                    "outcomeIndex": 0,
+                    "curPrice": 1,
+                    "currentValue": current_value,
+                })
+            })
+            .collect();
+        (200, serde_json::Value::from(positions).to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_redeemable_positions_aggregates_pages() {
+        let server = MockServer::start(vec![
+            redeemable_page(0, REDEEMABLE_PAGE_SIZE, 10.0),
+            redeemable_page(REDEEMABLE_PAGE_SIZE, 3, 0.0),
+        ])
+        .await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        let params = RedeemableParams::new()
+            .with_limit(None)
+            .with_size_threshold(0.0)
+            .with_min_value(None);
+        let positions = client
+            .get_redeemable_positions(&server.url(), EOA, Some(params))
+            .await
+            .unwrap();
+
+        assert_eq!(positions.len(), REDEEMABLE_PAGE_SIZE as usize + 3);
+        assert_eq!(positions.last().unwrap().asset, "102");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].path.contains("&limit=100&offset=0"));
+        assert!(requests[0].path.contains("&sizeThreshold=0"));
+        assert!(requests[1].path.contains("&limit=100&offset=100"));
+    }
+
+    #[tokio::test]
+    async fn test_get_redeemable_positions_defaults() {
+        let server = MockServer::start(vec![redeemable_page(0, 2, 0.0)]).await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        // Zero-value positions are dropped by default, and a checksummed
+        // address is queried in lowercase
+        let positions = client
+            .get_redeemable_positions(&server.url(), &eoa_checksummed(), None)
+            .await
+            .unwrap();
+        assert!(positions.is_empty());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].path.contains(&format!("user={}&", EOA)));
+        assert!(requests[0].path.contains("&sizeThreshold=0.1"));
+        assert!(requests[0].path.contains("&limit=100&offset=0"));
+    }
+
+    fn position_data(condition_id: &str, outcome_index: u32, size: &str) -> PositionData {
+        PositionData {
+            proxy_wallet: EOA.to_string(),
+            asset: format!("{}-{}", condition_id, outcome_index),
+            condition_id: condition_id.to_string(),
+            size: size.to_string(),
+            redeemable: false,
+            mergeable: true,
+            title: format!("Market {}", condition_id),
+            outcome: if outcome_index == 0 { "Yes" } else { "No" }.to_string(),
+            outcome_index,
+            cur_price: None,
+            current_value: 0.0,
+            negative_risk: false,
+        }
+    }
+
+    #[test]
+    fn test_pair_mergeable_positions() {
+        let mut not_mergeable = position_data("0xc", 0, "5");
+        not_mergeable.mergeable = false;
+
+        let positions = vec![
+            position_data("0xa", 0, "10.5"),
+            position_data("0xb", 1, "3"),
+            position_data("0xa", 1, "4.1234567"),
+            not_mergeable,
+            position_data("0xc", 1, "5"),
+        ];
+
+        let pairs = pair_mergeable_positions(positions).unwrap();
+
+        // 0xb only holds one outcome and 0xc's YES side is not mergeable
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].condition_id, "0xa");
+        assert_eq!(pairs[0].title, "Market 0xa");
+        // min(10.5, 4.1234567) in 6-decimal units, rounded down
+        assert_eq!(pairs[0].amount, "4123456");
+    }
+
+    #[tokio::test]
+    async fn test_get_mergeable_positions() {
+        let positions = serde_json::json!([
+            {
+                "proxyWallet": EOA, "asset": "1", "conditionId": "0xa", "size": 12,
+                "redeemable": false, "mergeable": true, "title": "Market",
+                "outcome": "Yes", "outcomeIndex": 0, "negativeRisk": true
+            },
+            {
+                "proxyWallet": EOA, "asset": "2", "conditionId": "0xa", "size": 7.5,
+                "redeemable": false, "mergeable": true, "title": "Market",
+                "outcome": "No", "outcomeIndex": 1, "negativeRisk": true
+            }
+        ]);
+        let server = MockServer::start(vec![(200, positions.to_string())]).await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        let mergeable = client
+            .get_mergeable_positions(&server.url(), &eoa_checksummed())
+            .await
+            .unwrap();
+
+        assert_eq!(mergeable.len(), 1);
+        assert!(server.requests()[0]
+            .path
+            .contains(&format!("user={}&", EOA)));
+        assert_eq!(mergeable[0].amount, "7500000");
+        assert!(mergeable[0].negative_risk);
+    }
+
+    #[tokio::test]
+    async fn test_merge_all_positions_continues_after_failure() {
+        let conditions: Vec<String> = (1..=2).map(|i| format!("0x{:064x}", i)).collect();
+        let positions: Vec<_> = conditions
+            .iter()
+            .flat_map(|condition_id| {
+                (0..2).map(move |outcome_index| {
+                    serde_json::json!({
+                        "proxyWallet": EOA, "asset": "1", "conditionId": condition_id,
+                        "size": 5, "redeemable": false, "mergeable": true,
+                        "title": "Market", "outcome": "Yes", "outcomeIndex": outcome_index
+                    })
+                })
+            })
+            .collect();
+        let data_api =
+            MockServer::start(vec![(200, serde_json::json!(positions).to_string())]).await;
+        let relayer = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"3"}"#.to_string()),
+            (400, r#"{"error":"bad request"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"3"}"#.to_string()),
+            (200, r#"{"transactionID":"tx"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(relayer.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let summary = client.merge_all_positions(&data_api.url()).await.unwrap();
+        assert_eq!(summary.succeeded(), 1);
+        assert_eq!(summary.failed(), 1);
+        assert_eq!(summary.results[0].0, conditions[0]);
+        assert!(matches!(
+            summary.results[0].1,
+            Err(Error::Relayer { status: 400, .. })
+        ));
+        assert_eq!(summary.results[1].0, conditions[1]);
+        assert_eq!(summary.results[1].1.as_ref().unwrap().transaction_id, "tx");
+
+        // Both merges call mergePositions on the CTF contract
+        let selector = &keccak256("mergePositions(address,bytes32,bytes32,uint256[],uint256)")[..4];
+        let submitted: Vec<serde_json::Value> = relayer
+            .requests()
+            .iter()
+            .filter(|r| r.path == "/submit")
+            .map(|r| serde_json::from_str(&r.body).unwrap())
+            .collect();
+        assert_eq!(submitted.len(), 2);
+        for (body, condition_id) in submitted.iter().zip(&conditions) {
+            let data = body["data"].as_str().unwrap();
+            assert!(data.starts_with(&format!("0x{}", hex::encode(selector))));
+            assert!(data.starts_with("0x9e7212ad"));
+            assert!(data.contains(&condition_id[2..]));
+        }
+    }
+
+    fn rpc_result(value: u64) -> String {
+        format!(
+            r#"{{"jsonrpc":"2.0","id":1,"result":"0x{}"}}"#,
+            hex::encode(U256::from(value).to_be_bytes::<32>())
+        )
+    }
+
+    fn rpc_client(server: &MockServer) -> RelayerClient {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        RelayerClient::new(server.url(), 137, Some(signer), None)
+            .unwrap()
+            .with_rpc_url(server.url())
+    }
+
+    #[tokio::test]
+    async fn test_rpc_errors_do_not_leak_the_url() {
+        // Nothing listens on port 1, so the request fails to connect
+        let client = RelayerClient::new("http://127.0.0.1:1", 137, None::<PrivateKeySigner>, None)
+            .unwrap()
+            .with_rpc_url("http://127.0.0.1:1/v2/secret-api-key");
+
+        let err = client.is_deployed_onchain(EOA).await.unwrap_err();
+        assert!(!err.to_string().contains("secret-api-key"), "{}", err);
+        assert!(
+            !format!("{:?}", err).contains("secret-api-key"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_receipt() {
+        let tx_hash = format!("0x{}", "11".repeat(32));
+        let receipt = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "transactionHash": tx_hash,
+                "blockNumber": "0x10",
+                "gasUsed": "0x5208",
+                "status": "0x0",
+                "logs": [],
+            },
+        });
+        let server = MockServer::start(vec![
+            (200, receipt.to_string()),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":null}"#.to_string()),
+        ])
+        .await;
+        let client = rpc_client(&server);
+
+        let receipt = client
+            .get_transaction_receipt(&tx_hash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!receipt.succeeded());
+        assert_eq!(receipt.gas_used, 21_000);
+        assert!(client
+            .get_transaction_receipt(&tx_hash)
+            .await
+            .unwrap()
+            .is_none());
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(body["method"], "eth_getTransactionReceipt");
+        assert_eq!(body["params"][0], tx_hash);
+
+        assert!(matches!(
+            client.get_transaction_receipt("0x1234").await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_collateral_balance_and_allowance() {
+        let server =
+            MockServer::start(vec![(200, rpc_result(5_000_000)), (200, rpc_result(0))]).await;
+        let client = rpc_client(&server);
+        let ctf = client.contract_config.ctf.clone();
+
+        assert_eq!(
+            client.get_collateral_balance(EOA).await.unwrap(),
+            U256::from(5_000_000u64)
+        );
+        assert_eq!(
+            client.get_ctf_allowance(EOA, &ctf).await.unwrap(),
+            U256::ZERO
+        );
+
+        let requests = server.requests();
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["method"], "eth_call");
+        assert_eq!(body["params"][0]["to"], client.contract_config.collateral);
+        assert_eq!(
+            body["params"][0]["data"],
+            format!("0x70a08231{:0>64}", EOA.trim_start_matches("0x"))
+        );
+
+        let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        let data = body["params"][0]["data"].as_str().unwrap();
+        assert!(data.starts_with("0xdd62ed3e"));
+        assert!(data.ends_with(&ctf.trim_start_matches("0x").to_lowercase()));
+    }
+
+    #[tokio::test]
+    async fn test_eth_call_errors() {
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"execution reverted"}}"#
+                .to_string(),
+        )])
+        .await;
+        let client = rpc_client(&server);
+
+        match client.get_collateral_balance(EOA).await {
+            Err(Error::Api(err)) => assert_eq!(err.error, "execution reverted"),
+            other => panic!("expected API error, got {:?}", other),
+        }
+
+        // No RPC URL configured
+        let client =
+            RelayerClient::new("http://localhost", 137, None::<PrivateKeySigner>, None).unwrap();
+        assert!(matches!(
+            client.get_collateral_balance(EOA).await,
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_split_position_prechecks_funds() {
+        let condition_id = format!("0x{}", "ab".repeat(32));
+
+        // Balance too low: fails before the allowance read and the relayer
+        let server = MockServer::start(vec![(200, rpc_result(999_999))]).await;
+        let client = rpc_client(&server);
+        match client
+            .split_position(&condition_id, "1000000", None, None)
+            .await
+        {
+            Err(Error::InvalidParameter(msg)) => {
+                assert!(msg.contains("Insufficient collateral balance"), "{}", msg);
+                assert!(msg.contains("holds 999999, needs 1000000"), "{}", msg);
+            }
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        }
+        assert_eq!(server.requests().len(), 1);
+
+        // Enough balance but no allowance
+        let server =
+            MockServer::start(vec![(200, rpc_result(2_000_000)), (200, rpc_result(0))]).await;
+        let client = rpc_client(&server);
+        match client
+            .split_position(&condition_id, "1000000", None, None)
+            .await
+        {
+            Err(Error::InvalidParameter(msg)) => {
+                assert!(msg.contains("Insufficient collateral allowance"), "{}", msg);
+                assert!(msg.contains("approve_ctf_max"), "{}", msg);
+            }
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        }
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_is_deployed_onchain() {
+        let server = MockServer::start(vec![
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x"}"#.to_string()),
+            (
+                200,
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x6080604052"}"#.to_string(),
+            ),
+        ])
+        .await;
+        let client = rpc_client(&server);
+
+        assert!(!client.is_deployed_onchain(EOA).await.unwrap());
+        assert!(client.is_deployed_onchain(EOA).await.unwrap());
+
+        let requests = server.requests();
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["method"], "eth_getCode");
+        assert_eq!(body["params"][1], "latest");
+        assert_eq!(
+            body["params"][0].as_str().unwrap().to_lowercase(),
+            EOA.to_lowercase()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_uses_onchain_deployment_with_rpc() {
+        // The relayer is never asked: eth_getCode reports no code, so execute
+        // stops before fetching a nonce.
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"jsonrpc":"2.0","id":1,"result":"0x"}"#.to_string(),
+        )])
+        .await;
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_rpc_url(server.url());
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0x");
+        match client.execute(vec![tx], None).await {
+            Err(Error::Config(msg)) => assert!(msg.contains("is not deployed"), "{}", msg),
+            other => panic!("expected Config error, got {:?}", other),
+        }
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].body.contains("eth_getCode"));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_length_is_validated_before_signing() {
+        // No responses: any request to the relayer would fail the test
+        let server = MockServer::start(Vec::<(u16, String)>::new()).await;
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let condition_id = format!("0x{}", "ab".repeat(32));
+        let too_long = "a".repeat(MAX_METADATA_LEN + 1);
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0x");
+
+        assert!(matches!(
+            client.execute(vec![tx], Some(&too_long)).await,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            client
+                .split_position(&condition_id, "1", None, Some(&too_long))
+                .await,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            client
+                .with_signature_type(SignatureType::PolyProxy)
+                .unwrap()
+                .redeem_positions(&condition_id, vec![1, 2], Some(&too_long))
+                .await,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(server.requests().is_empty());
+
+        // Exactly at the limit, counted in characters rather than bytes
+        assert!(validate_metadata(Some(&"é".repeat(MAX_METADATA_LEN))).is_ok());
+        assert!(validate_metadata(None).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_all_positions_concurrent() {
+        let data_api = MockServer::start(vec![redeemable_page(0, 3, 10.0)]).await;
+        // The same body answers the deployed, nonce and submit requests, so
+        // the interleaving of concurrent redemptions does not matter. Both
+        // first redemptions may fetch the nonce, so there is a spare response.
+        // The failing response makes exactly one redemption fail.
+        let ok = (
+            200,
+            r#"{"deployed":true,"nonce":"0","transactionID":"tx"}"#.to_string(),
+        );
+        let mut responses = vec![ok; 9];
+        responses.insert(4, (400, r#"{"error":"bad request"}"#.to_string()));
+        let relayer = MockServer::start(responses).await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(relayer.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let summary = client
+            .redeem_all_positions_concurrent(&data_api.url(), 2)
+            .await
+            .unwrap();
+        assert_eq!(summary.results.len(), 3);
+        // Exactly one redemption was rejected. Accepted ones with a later
+        // nonce depend on it, so they are reported as queued behind it.
+        let rejected = summary
+            .results
+            .iter()
+            .filter(|(_, r)| matches!(r, Err(Error::Relayer { status: 400, .. })))
+            .count();
+        let queued = summary
+            .results
+            .iter()
+            .filter(|(_, r)| matches!(r, Err(Error::NonceGap { .. })))
+            .count();
+        assert_eq!(rejected, 1);
+        assert_eq!(summary.succeeded() + queued, 2);
+
+        let mut condition_ids: Vec<&str> = summary
+            .results
+            .iter()
+            .map(|(condition_id, _)| condition_id.as_str())
+            .collect();
+        condition_ids.sort();
+        let expected: Vec<String> = (0..3).map(|i| format!("0x{:064x}", i)).collect();
+        assert_eq!(condition_ids, expected);
+
+        // Accepted redemptions were signed with distinct nonces. The rejected
+        // one (request 4) gives its nonce back, so it is left out.
+        let mut accepted: Vec<u64> = relayer
+            .requests()
+            .iter()
+            .enumerate()
+            .filter(|(i, r)| *i != 4 && r.path == "/submit")
+            .map(|(_, r)| {
+                let body: serde_json::Value = serde_json::from_str(&r.body).unwrap();
+                body["nonce"].as_str().unwrap().parse().unwrap()
+            })
+            .collect();
+        assert_eq!(accepted.len(), 2);
+        accepted.sort();
+        accepted.dedup();
+        assert_eq!(accepted.len(), 2);
+
+        assert!(matches!(
+            client
+                .redeem_all_positions_concurrent(&data_api.url(), 0)
+                .await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_mark_nonce_gaps_after_failed_middle_redemption() {
+        let ok = |id: &str| {
+            Ok(RelayerSubmitResponse {
+                transaction_id: id.to_string(),
+                transaction_hash: None,
+                state: None,
+            })
+        };
+        let rejected = || {
+            Err(Error::Relayer {
+                status: 400,
+                error: RelayerError::Raw("bad request".to_string()),
+            })
+        };
+
+        // The middle nonce was rejected, so only the last one waits on it
+        let mut results = vec![
+            ("a".to_string(), Some(5), ok("tx1")),
+            ("b".to_string(), Some(6), rejected()),
+            ("c".to_string(), Some(7), ok("tx3")),
+            (
+                "d".to_string(),
+                None,
+                Err(Error::InvalidParameter("x".into())),
+            ),
+        ];
+        assert!(mark_nonce_gaps(&mut results));
+        assert!(results[0].2.is_ok());
+        assert!(matches!(results[1].2, Err(Error::Relayer { .. })));
+        match &results[2].2 {
+            Err(Error::NonceGap {
+                nonce,
+                transaction_id,
+            }) => {
+                assert_eq!(*nonce, 6);
+                assert_eq!(transaction_id, "tx3");
+            }
+            other => panic!("expected NonceGap, got {:?}", other),
+        }
+
+        // A nonce reused by a later accepted redemption leaves no gap
+        let mut results = vec![
+            ("b".to_string(), Some(6), rejected()),
+            ("c".to_string(), Some(7), ok("tx3")),
+            ("e".to_string(), Some(6), ok("tx4")),
+        ];
+        assert!(!mark_nonce_gaps(&mut results));
+        assert!(results[1].2.is_ok());
+
+        // A nonce the relayer reports as used leaves no gap either
+        let mut results = vec![
+            (
+                "b".to_string(),
+                Some(6),
+                Err(Error::Relayer {
+                    status: 400,
+                    error: RelayerError::NonceTooLow("nonce too low".to_string()),
+                }),
+            ),
+            ("c".to_string(), Some(7), ok("tx3")),
+        ];
+        assert!(!mark_nonce_gaps(&mut results));
+        assert!(results[1].2.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_all_concurrent_reports_redemptions_behind_a_failure() {
+        let data_api = MockServer::start(vec![redeemable_page(0, 3, 10.0)]).await;
+        // Nonces 5, 6 and 7 are taken in position order before any request.
+        // Which redemption gets the failing response depends on timing, so
+        // the expectations are derived from the one that failed.
+        let ok = (200, r#"{"deployed":true,"transactionID":"tx"}"#.to_string());
+        let mut responses = vec![ok; 5];
+        responses.insert(4, (400, r#"{"error":"bad request"}"#.to_string()));
+        let relayer = MockServer::start(responses).await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(relayer.url(), 137, Some(signer), Some(creds))
+            .unwrap()
+            .with_nonce_manager(NonceManager::starting_at(5));
+
+        let summary = client
+            .redeem_all_positions_concurrent(&data_api.url(), 3)
+            .await
+            .unwrap();
+        assert_eq!(summary.results.len(), 3);
+
+        let nonce_of =
+            |condition_id: &str| 5 + u64::from_str_radix(&condition_id[2..], 16).unwrap();
+        let failed: Vec<u64> = summary
+            .results
+            .iter()
+            .filter(|(_, r)| r.is_err() && !matches!(r, Err(Error::NonceGap { .. })))
+            .map(|(condition_id, _)| nonce_of(condition_id))
+            .collect();
+        assert_eq!(failed.len(), 1);
+        let failed = failed[0];
+
+        for (condition_id, result) in &summary.results {
+            let nonce = nonce_of(condition_id);
+            if nonce < failed {
+                assert!(result.is_ok(), "nonce {}: {:?}", nonce, result);
+            } else if nonce > failed {
+                assert!(
+                    matches!(result, Err(Error::NonceGap { nonce: gap, .. }) if *gap == failed),
+                    "nonce {}: {:?}",
+                    nonce,
+                    result
+                );
+            }
+        }
+
+        // The counter is resynced so the next execute fills the gap
+        assert_eq!(client.nonce_manager().unwrap().peek(), None);
+    }
+
+    #[tokio::test]
+    async fn test_redeem_all_rejects_out_of_range_outcome_index() {
+        let (status, body) = redeemable_page(0, 1, 10.0);
+        let page = body.replace(r#""outcomeIndex":0"#, r#""outcomeIndex":32"#);
+        let data_api = MockServer::start(vec![(status, page.clone()), (status, page)]).await;
+        // No responses: nothing may be submitted for the bad position
+        let relayer = MockServer::start(Vec::<(u16, String)>::new()).await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(relayer.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        assert!(matches!(
+            client.redeem_all_positions(&data_api.url()).await,
+            Err(Error::InvalidParameter(_))
+        ));
+
+        let summary = client
+            .redeem_all_positions_concurrent(&data_api.url(), 2)
+            .await
+            .unwrap();
+        assert_eq!(summary.results.len(), 1);
+        assert!(matches!(
+            summary.results[0].1,
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(relayer.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_positions_batch_uses_single_multisend() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"3"}"#.to_string()),
+            (200, r#"{"transactionID":"batch"}"#.to_string()),
+        ])
+        .await;
+
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap();
+
+        let conditions: Vec<String> = ["aa", "bb", "cc"]
+            .iter()
+            .map(|byte| format!("0x{}", byte.repeat(32)))
+            .collect();
+        let redemptions: Vec<(&str, Vec<u32>)> = conditions
+            .iter()
+            .map(|condition_id| (condition_id.as_str(), vec![1, 2]))
+            .collect();
+
+        let response = client
+            .redeem_positions_batch(&redemptions, Some("batch redeem"))
+            .await
+            .unwrap();
+        assert_eq!(response.transaction_id, "batch");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].path, "/submit");
+
+        let transactions: Vec<SafeTransaction> = redemptions
+            .iter()
+            .map(|(condition_id, index_sets)| {
+                let data = CtfEncoder::encode_redeem_positions(
+                    &client.contract_config.collateral,
+                    condition_id,
+                    index_sets.clone(),
+                )
+                .unwrap();
+                SafeTransaction::new(&client.contract_config.ctf, data)
+            })
+            .collect();
+        let multisend =
+            aggregate_transactions(&transactions, &client.contract_config.safe_multisend).unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+        assert_eq!(
+            body["to"],
+            client.contract_config.safe_multisend.to_lowercase()
+        );
+        assert_eq!(body["data"], multisend.data);
+
+        assert!(matches!(
+            client.redeem_positions_batch(&[], None).await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    fn relayer_tx(state: &str) -> (u16, String) {
+        (
+            200,
+            format!(r#"[{{"transactionID":"tx1","state":"{}"}}]"#, state),
+        )
+    }
+
+    fn fast_wait_config() -> TransactionWaitConfig {
+        TransactionWaitConfig {
+            initial_interval: std::time::Duration::from_millis(1),
+            max_interval: std::time::Duration::from_millis(5),
+            ..Default::default()
+        }
+    }
+
+    fn signed_client(server: &MockServer) -> RelayerClient {
+        let signer: PrivateKeySigner = TEST_KEY.parse().unwrap();
+        let creds = BuilderApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        RelayerClient::new(server.url(), 137, Some(signer), Some(creds)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ensure_deployed() {
+        // Already deployed: nothing is submitted
+        let server = MockServer::start(vec![(200, r#"{"deployed":true}"#.to_string())]).await;
+        assert!(signed_client(&server)
+            .ensure_deployed()
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(server.requests().len(), 1);
+
+        // Not deployed: deploy and wait for the deployment to be mined
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":false}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+            relayer_tx("STATE_MINED"),
+        ])
+        .await;
+        let tx = signed_client(&server)
+            .ensure_deployed()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tx.state, Some(RelayerTransactionState::Mined));
+        let requests = server.requests();
+        assert_eq!(requests[1].path, "/submit");
+        let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(body["type"], "SAFE-CREATE");
+        assert_eq!(requests[2].path, "/transaction?id=tx1");
+
+        // Deployed by someone else between the check and the submit
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":false}"#.to_string()),
+            (400, r#"{"error":"safe already deployed"}"#.to_string()),
+            (200, r#"{"deployed":true}"#.to_string()),
+        ])
+        .await;
+        assert!(signed_client(&server)
+            .ensure_deployed()
+            .await
+            .unwrap()
+            .is_none());
+
+        // Rejected and still not deployed: the submit error is returned
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":false}"#.to_string()),
+            (400, r#"{"error":"bad signature"}"#.to_string()),
+            (200, r#"{"deployed":false}"#.to_string()),
+        ])
+        .await;
+        assert!(matches!(
+            signed_client(&server).ensure_deployed().await,
+            Err(Error::Relayer {
+                status: 400,
+                error: RelayerError::InvalidSignature(_),
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_or_deploy_deploys_first() {
+        let server = MockServer::start(vec![
+            (200, r#"{"deployed":false}"#.to_string()),
+            (200, r#"{"transactionID":"tx1"}"#.to_string()),
+            relayer_tx("STATE_MINED"),
+            (200, r#"{"deployed":true}"#.to_string()),
+            (200, r#"{"nonce":"0"}"#.to_string()),
+            (200, r#"{"transactionID":"abc"}"#.to_string()),
+        ])
+        .await;
+        let client = signed_client(&server);
+
+        let tx = SafeTransaction::new(&client.contract_config.ctf, "0xabcd");
+        let response = client.execute_or_deploy(vec![tx], None).await.unwrap();
+        assert_eq!(response.transaction_id, "abc");
+
+        let submits: Vec<serde_json::Value> = server
+            .requests()
+            .into_iter()
+            .filter(|r| r.path == "/submit")
+            .map(|r| serde_json::from_str(&r.body).unwrap())
+            .collect();
+        assert_eq!(submits.len(), 2);
+        assert_eq!(submits[0]["type"], "SAFE-CREATE");
+        assert_eq!(submits[1]["type"], "SAFE");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction_target_state() {
+        let server = MockServer::start(vec![
+            relayer_tx("STATE_NEW"),
+            relayer_tx("STATE_MINED"),
+            relayer_tx("STATE_CONFIRMED"),
+        ])
+        .await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        let tx = client
+            .wait_for_transaction(
+                "tx1",
+                fast_wait_config().with_target_state(RelayerTransactionState::Confirmed),
+            )
+            .await
+            .unwrap();
+        assert_eq!(tx.state, Some(RelayerTransactionState::Confirmed));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction_timeout_and_failure() {
+        let server = MockServer::start(vec![
+            relayer_tx("STATE_NEW"),
+            relayer_tx("STATE_MINED"),
+            relayer_tx("STATE_FAILED"),
+        ])
+        .await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        let config = TransactionWaitConfig {
+            max_polls: 2,
+            ..fast_wait_config().with_target_state(RelayerTransactionState::Confirmed)
+        };
+        match client.wait_for_transaction("tx1", config).await {
+            Err(Error::TransactionTimeout {
+                transaction_id,
+                polls,
+            }) => {
+                assert_eq!(transaction_id, "tx1");
+                assert_eq!(polls, 2);
+            }
+            other => panic!("expected TransactionTimeout, got {:?}", other),
+        }
+
+        assert!(matches!(
+            client.wait_for_transaction("tx1", fast_wait_config()).await,
+            Err(Error::Api(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction_multiplier() {
+        let server = MockServer::start(vec![
+            relayer_tx("STATE_NEW"),
+            relayer_tx("STATE_NEW"),
+            relayer_tx("STATE_MINED"),
+        ])
+        .await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        for multiplier in [-1.0, f64::NAN, f64::INFINITY] {
+            let config = TransactionWaitConfig {
+                multiplier,
+                ..fast_wait_config()
+            };
+            assert!(matches!(
+                client.wait_for_transaction("tx1", config).await,
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+        assert!(server.requests().is_empty());
+
+        // An interval too large for a Duration is capped at max_interval
+        let config = TransactionWaitConfig {
+            multiplier: f64::MAX,
+            ..fast_wait_config()
+        };
+        let tx = client.wait_for_transaction("tx1", config).await.unwrap();
+        assert_eq!(tx.state, Some(RelayerTransactionState::Mined));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction_with_cancel() {
+        let server =
+            MockServer::start(vec![relayer_tx("STATE_NEW"), relayer_tx("STATE_NEW")]).await;
+        let client = RelayerClient::new(server.url(), 137, None::<PrivateKeySigner>, None).unwrap();
+
+        // Long interval so the wait is still sleeping after the first poll
+        let config = TransactionWaitConfig {
+            initial_interval: std::time::Duration::from_secs(60),
+            ..Default::default()
+        };
+        let cancel = async {
+            while server.requests().is_empty() {
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            }
+        };
+
+        assert!(matches!(
+            client
+                .wait_for_transaction_with_cancel("tx1", config, cancel)
+                .await,
+            Err(Error::Cancelled)
+        ));
+        assert_eq!(server.requests().len(), 1);
     }
 }
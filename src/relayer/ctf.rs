@@ -1,7 +1,44 @@
 //! CTF (Conditional Token Framework) Encoder
 //!
 //! This module provides functions for encoding CTF contract calls
-//! used in Polymarket's prediction markets.
+//! used in Polymarket's prediction markets, and for decoding the events
+//! those calls emit.
+
+use alloy_primitives::{hex, Address, B256, U256};
+use alloy_sol_types::{sol, SolEvent};
+
+use crate::error::{Error, Result};
+use crate::types::{validate_address, validate_condition_id};
+
+use super::types::{ReceiptLog, TransactionReceipt};
+
+// CTF events reporting the amounts of a redeem or split
+mod events {
+    use super::sol;
+
+    sol! {
+        event PayoutRedemption(
+            address indexed redeemer,
+            address indexed collateralToken,
+            bytes32 indexed parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets,
+            uint256 payout
+        );
+
+        event PositionSplit(
+            address indexed stakeholder,
+            address collateralToken,
+            bytes32 indexed parentCollectionId,
+            bytes32 indexed conditionId,
+            uint256[] partition,
+            uint256 amount
+        );
+    }
+}
+
+/// Outcome partition of a binary market: index set 1 (YES) and 2 (NO)
+pub const BINARY_PARTITION: [u32; 2] = [1, 2];
 
 /// Encoder for CTF contract function calls
 pub struct CtfEncoder;
@@ -16,11 +53,15 @@ impl CtfEncoder {
     ///
     /// # Returns
     /// Hex-encoded function call data
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed address or condition ID
     pub fn encode_redeem_positions(
         collateral_token: &str,
         condition_id: &str,
         index_sets: Vec<u32>,
-    ) -> String {
+    ) -> Result<String> {
         // redeemPositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] indexSets)
         // Function selector: keccak256("redeemPositions(address,bytes32,bytes32,uint256[])")[0:4] = 0x01b7037c
         let selector = "01b7037c";
@@ -29,13 +70,13 @@ impl CtfEncoder {
         data.push_str(selector);
 
         // Encode collateralToken (address, padded to 32 bytes)
-        data.push_str(&encode_address(collateral_token));
+        data.push_str(&encode_address(collateral_token)?);
 
         // Encode parentCollectionId (bytes32, all zeros for root)
         data.push_str(&"0".repeat(64));
 
         // Encode conditionId (bytes32)
-        data.push_str(&encode_bytes32(condition_id));
+        data.push_str(&encode_bytes32(condition_id)?);
 
         // Encode indexSets (uint256[] - dynamic array)
         // Offset to array data (4 * 32 = 128 bytes from start of params = 0x80)
@@ -49,7 +90,101 @@ impl CtfEncoder {
             data.push_str(&encode_uint256(index_set as u64));
         }
 
-        data
+        Ok(data)
+    }
+
+    /// Encode a NegRiskAdapter redeemPositions call
+    ///
+    /// Negative-risk markets redeem through the NegRiskAdapter rather than the
+    /// ConditionalTokens contract, and take explicit amounts instead of index sets.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `amounts` - Amount of each outcome token to redeem (in smallest units), ordered [YES, NO]
+    ///
+    /// # Returns
+    /// Hex-encoded function call data
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed condition ID or amount
+    pub fn encode_neg_risk_redeem(condition_id: &str, amounts: &[&str]) -> Result<String> {
+        // redeemPositions(bytes32 conditionId, uint256[] amounts)
+        // Function selector: keccak256("redeemPositions(bytes32,uint256[])")[0:4] = 0xdbeccb23
+        let selector = "dbeccb23";
+
+        let mut data = String::from("0x");
+        data.push_str(selector);
+
+        // Encode conditionId (bytes32)
+        data.push_str(&encode_bytes32(condition_id)?);
+
+        // Encode amounts (uint256[] - dynamic array)
+        // Offset to array data (2 * 32 = 64 bytes from start of params = 0x40)
+        data.push_str(&encode_uint256(64));
+
+        // Array length
+        data.push_str(&encode_uint256(amounts.len() as u64));
+
+        // Array elements
+        for amount in amounts {
+            data.push_str(&encode_uint256_from_str(amount)?);
+        }
+
+        Ok(data)
+    }
+
+    /// Encode a NegRiskAdapter convertPositions call
+    ///
+    /// Converts NO positions of some questions in a negative-risk market into
+    /// YES positions of all the other questions plus collateral. Bit `i` of
+    /// `index_set` (value `1 << i`) selects the question with index `i`, the
+    /// last byte of its question ID. For each selected question, `amount` NO
+    /// tokens are burned; the wallet receives `amount` YES tokens of every
+    /// question not selected, and `amount * (selected - 1)` collateral, less
+    /// the market's conversion fee if it has one.
+    ///
+    /// The wallet must have approved the NegRiskAdapter to move its CTF tokens.
+    ///
+    /// # Arguments
+    /// * `market_id` - The negative-risk market ID (`negRiskMarketID` in the Gamma API)
+    /// * `index_set` - Bitmask of the questions whose NO positions are converted
+    /// * `amount` - Amount of each NO position to convert (in smallest units)
+    ///
+    /// # Returns
+    /// Hex-encoded function call data
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed market ID or amount, or an empty index set
+    pub fn encode_convert_positions(
+        market_id: &str,
+        index_set: U256,
+        amount: &str,
+    ) -> Result<String> {
+        if index_set.is_zero() {
+            return Err(Error::InvalidParameter(
+                "index_set must select at least one question".to_string(),
+            ));
+        }
+
+        // convertPositions(bytes32 marketId, uint256 indexSet, uint256 amount)
+        // Function selector: keccak256("convertPositions(bytes32,uint256,uint256)")[0:4] = 0xc64748c4
+        let selector = "c64748c4";
+
+        let mut data = String::from("0x");
+        data.push_str(selector);
+
+        // Encode marketId (bytes32)
+        data.push_str(&encode_bytes32(market_id)?);
+
+        // Encode indexSet (uint256)
+        data.push_str(&encode_u256(index_set));
+
+        // Encode amount (uint256)
+        data.push_str(&encode_uint256_from_str(amount)?);
+
+        Ok(data)
     }
 
     /// Encode a splitPosition call
@@ -57,15 +192,21 @@ impl CtfEncoder {
     /// # Arguments
     /// * `collateral_token` - The collateral token address (USDC)
     /// * `condition_id` - The condition ID of the market
+    /// * `partition` - Index sets of the outcome partition (e.g. [1, 2, 4, 8] for 4 outcomes)
     /// * `amount` - Amount to split (in smallest units)
     ///
     /// # Returns
     /// Hex-encoded function call data
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed address, condition ID or amount
     pub fn encode_split_position(
         collateral_token: &str,
         condition_id: &str,
+        partition: &[u32],
         amount: &str,
-    ) -> String {
+    ) -> Result<String> {
         // splitPosition(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount)
         // Function selector: 0x72ce4275
         let selector = "72ce4275";
@@ -74,26 +215,36 @@ impl CtfEncoder {
         data.push_str(selector);
 
         // Encode collateralToken
-        data.push_str(&encode_address(collateral_token));
+        data.push_str(&encode_address(collateral_token)?);
 
         // Encode parentCollectionId (all zeros)
         data.push_str(&"0".repeat(64));
 
         // Encode conditionId
-        data.push_str(&encode_bytes32(condition_id));
+        data.push_str(&encode_bytes32(condition_id)?);
 
         // Encode partition offset (5 * 32 = 160 = 0xa0)
         data.push_str(&encode_uint256(160));
 
         // Encode amount
-        data.push_str(&encode_uint256_from_str(amount));
+        data.push_str(&encode_uint256_from_str(amount)?);
 
-        // Partition array - [1, 2] for binary markets
-        data.push_str(&encode_uint256(2)); // array length
-        data.push_str(&encode_uint256(1)); // index set 1 (YES)
-        data.push_str(&encode_uint256(2)); // index set 2 (NO)
+        // Partition array
+        data.push_str(&encode_uint256(partition.len() as u64));
+        for index_set in partition {
+            data.push_str(&encode_uint256(*index_set as u64));
+        }
 
-        data
+        Ok(data)
+    }
+
+    /// Encode a splitPosition call for a binary market (partition [1, 2])
+    pub fn encode_split_position_binary(
+        collateral_token: &str,
+        condition_id: &str,
+        amount: &str,
+    ) -> Result<String> {
+        Self::encode_split_position(collateral_token, condition_id, &BINARY_PARTITION, amount)
     }
 
     /// Encode a mergePositions call
@@ -101,102 +252,207 @@ impl CtfEncoder {
     /// # Arguments
     /// * `collateral_token` - The collateral token address (USDC)
     /// * `condition_id` - The condition ID of the market
+    /// * `partition` - Index sets of the outcome partition (e.g. [1, 2, 4, 8] for 4 outcomes)
     /// * `amount` - Amount to merge (in smallest units)
     ///
     /// # Returns
     /// Hex-encoded function call data
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed address, condition ID or amount
     pub fn encode_merge_positions(
         collateral_token: &str,
         condition_id: &str,
+        partition: &[u32],
         amount: &str,
-    ) -> String {
+    ) -> Result<String> {
         // mergePositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount)
-        // Function selector: 0xd4e59c76
-        let selector = "d4e59c76";
+        // Function selector: 0x9e7212ad
+        let selector = "9e7212ad";
 
         let mut data = String::from("0x");
         data.push_str(selector);
 
         // Encode collateralToken
-        data.push_str(&encode_address(collateral_token));
+        data.push_str(&encode_address(collateral_token)?);
 
         // Encode parentCollectionId (all zeros)
         data.push_str(&"0".repeat(64));
 
         // Encode conditionId
-        data.push_str(&encode_bytes32(condition_id));
+        data.push_str(&encode_bytes32(condition_id)?);
 
         // Encode partition offset (5 * 32 = 160 = 0xa0)
         data.push_str(&encode_uint256(160));
 
         // Encode amount
-        data.push_str(&encode_uint256_from_str(amount));
+        data.push_str(&encode_uint256_from_str(amount)?);
+
+        // Partition array
+        data.push_str(&encode_uint256(partition.len() as u64));
+        for index_set in partition {
+            data.push_str(&encode_uint256(*index_set as u64));
+        }
 
-        // Partition array - [1, 2] for binary markets
-        data.push_str(&encode_uint256(2)); // array length
-        data.push_str(&encode_uint256(1)); // index set 1 (YES)
-        data.push_str(&encode_uint256(2)); // index set 2 (NO)
+        Ok(data)
+    }
 
-        data
+    /// Encode a mergePositions call for a binary market (partition [1, 2])
+    pub fn encode_merge_positions_binary(
+        collateral_token: &str,
+        condition_id: &str,
+        amount: &str,
+    ) -> Result<String> {
+        Self::encode_merge_positions(collateral_token, condition_id, &BINARY_PARTITION, amount)
     }
 
     /// Encode an ERC20 approve call
     ///
     /// # Arguments
     /// * `spender` - The address to approve
-    /// * `amount` - Amount to approve (use u64::MAX for unlimited)
+    /// * `amount` - Amount to approve (see [`encode_approve_max`](Self::encode_approve_max) for unlimited)
     ///
     /// # Returns
     /// Hex-encoded function call data
-    pub fn encode_approve(spender: &str, amount: u128) -> String {
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) for a
+    /// malformed spender address
+    pub fn encode_approve(spender: &str, amount: U256) -> Result<String> {
         // approve(address spender, uint256 amount)
         // Function selector: 0x095ea7b3
         let selector = "095ea7b3";
 
         let mut data = String::from("0x");
         data.push_str(selector);
-        data.push_str(&encode_address(spender));
-        data.push_str(&encode_uint128(amount));
+        data.push_str(&encode_address(spender)?);
+        data.push_str(&encode_u256(amount));
 
-        data
+        Ok(data)
     }
 
     /// Encode an ERC20 approve call with maximum amount
-    pub fn encode_approve_max(spender: &str) -> String {
+    pub fn encode_approve_max(spender: &str) -> Result<String> {
         // Use max uint256
         let selector = "095ea7b3";
         let mut data = String::from("0x");
         data.push_str(selector);
-        data.push_str(&encode_address(spender));
-        // Max uint256
-        data.push_str(&"f".repeat(64));
-        data
+        data.push_str(&encode_address(spender)?);
+        data.push_str(&encode_u256(U256::MAX));
+        Ok(data)
     }
 }
 
+/// CTF event decoded from a transaction receipt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CtfEvent {
+    /// Winning positions were redeemed for `payout` collateral
+    PayoutRedemption {
+        redeemer: Address,
+        collateral_token: Address,
+        parent_collection_id: B256,
+        condition_id: B256,
+        index_sets: Vec<U256>,
+        payout: U256,
+    },
+    /// `amount` collateral was split into the outcome positions of `partition`
+    PositionSplit {
+        stakeholder: Address,
+        collateral_token: Address,
+        parent_collection_id: B256,
+        condition_id: B256,
+        partition: Vec<U256>,
+        amount: U256,
+    },
+}
+
+/// Decode the redemption and split events a CTF contract emitted in a receipt
+///
+/// Logs from other contracts and other CTF events are skipped.
+///
+/// # Arguments
+/// * `receipt` - Receipt from [`RelayerClient::get_transaction_receipt`](super::RelayerClient::get_transaction_receipt)
+/// * `ctf_address` - The CTF contract address (see [`RelayerContractConfig`](super::RelayerContractConfig))
+///
+/// # Errors
+/// Returns `Error::InvalidParameter` for a malformed address or event log
+pub fn decode_ctf_events(receipt: &TransactionReceipt, ctf_address: &str) -> Result<Vec<CtfEvent>> {
+    let ctf = validate_address(ctf_address)?;
+    receipt
+        .logs
+        .iter()
+        .filter(|log| log.address == ctf)
+        .filter_map(|log| decode_ctf_log(log).transpose())
+        .collect()
+}
+
+fn decode_ctf_log(log: &ReceiptLog) -> Result<Option<CtfEvent>> {
+    let invalid = |e: alloy_sol_types::Error| {
+        Error::InvalidParameter(format!("Invalid CTF event log: {}", e))
+    };
+    let topics = log.topics.iter().copied();
+
+    let event = match log.topics.first() {
+        Some(&events::PayoutRedemption::SIGNATURE_HASH) => {
+            let event =
+                events::PayoutRedemption::decode_raw_log(topics, &log.data).map_err(invalid)?;
+            CtfEvent::PayoutRedemption {
+                redeemer: event.redeemer,
+                collateral_token: event.collateralToken,
+                parent_collection_id: event.parentCollectionId,
+                condition_id: event.conditionId,
+                index_sets: event.indexSets,
+                payout: event.payout,
+            }
+        }
+        Some(&events::PositionSplit::SIGNATURE_HASH) => {
+            let event =
+                events::PositionSplit::decode_raw_log(topics, &log.data).map_err(invalid)?;
+            CtfEvent::PositionSplit {
+                stakeholder: event.stakeholder,
+                collateral_token: event.collateralToken,
+                parent_collection_id: event.parentCollectionId,
+                condition_id: event.conditionId,
+                partition: event.partition,
+                amount: event.amount,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
 // Helper encoding functions
 
-fn encode_address(addr: &str) -> String {
-    let addr = addr.trim_start_matches("0x").to_lowercase();
-    format!("{:0>64}", addr)
+fn encode_address(addr: &str) -> Result<String> {
+    Ok(format!("{:0>64}", hex::encode(validate_address(addr)?)))
 }
 
-fn encode_bytes32(value: &str) -> String {
-    let value = value.trim_start_matches("0x").to_lowercase();
-    format!("{:0>64}", value)
+fn encode_bytes32(value: &str) -> Result<String> {
+    Ok(hex::encode(validate_condition_id(value)?))
 }
 
 fn encode_uint256(value: u64) -> String {
-    format!("{:064x}", value)
+    encode_u256(U256::from(value))
 }
 
-fn encode_uint128(value: u128) -> String {
+fn encode_u256(value: U256) -> String {
     format!("{:064x}", value)
 }
 
-fn encode_uint256_from_str(value: &str) -> String {
-    let value: u128 = value.parse().unwrap_or(0);
-    format!("{:064x}", value)
+fn encode_uint256_from_str(value: &str) -> Result<String> {
+    Ok(encode_u256(parse_uint256(value)?))
+}
+
+/// Parse a decimal or 0x-prefixed hex uint256
+pub(crate) fn parse_uint256(value: &str) -> Result<U256> {
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) if !hex.is_empty() => U256::from_str_radix(hex, 16).ok(),
+        None if !value.is_empty() => U256::from_str_radix(value, 10).ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| Error::InvalidParameter(format!("Invalid uint256: {:?}", value)))
 }
 
 #[cfg(test)]
@@ -209,7 +465,8 @@ mod tests {
         let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
         let index_sets = vec![1, 2];
 
-        let result = CtfEncoder::encode_redeem_positions(collateral, condition_id, index_sets);
+        let result =
+            CtfEncoder::encode_redeem_positions(collateral, condition_id, index_sets).unwrap();
 
         // Should start with function selector
         assert!(result.starts_with("0x01b7037c"));
@@ -217,24 +474,240 @@ mod tests {
         assert!(result.len() > 10);
     }
 
+    #[test]
+    fn test_encode_neg_risk_redeem() {
+        let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+        let result = CtfEncoder::encode_neg_risk_redeem(condition_id, &["1000000", "0"]).unwrap();
+
+        let selector = &alloy_primitives::keccak256("redeemPositions(bytes32,uint256[])")[..4];
+        assert!(result.starts_with(&format!("0x{}", alloy_primitives::hex::encode(selector))));
+        // selector + conditionId + offset + length + 2 amounts
+        assert_eq!(result.len(), 2 + 8 + 64 * 5);
+        assert_eq!(&result[74..138], format!("{:064x}", 64));
+        assert_eq!(&result[202..266], format!("{:064x}", 1_000_000));
+    }
+
+    #[test]
+    fn test_encode_convert_positions() {
+        let market_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcd00";
+
+        // Convert the NO positions of questions 0 and 2
+        let result =
+            CtfEncoder::encode_convert_positions(market_id, U256::from(0b101), "1000000").unwrap();
+
+        let selector =
+            &alloy_primitives::keccak256("convertPositions(bytes32,uint256,uint256)")[..4];
+        assert!(result.starts_with(&format!("0x{}", alloy_primitives::hex::encode(selector))));
+        assert!(result.starts_with("0xc64748c4"));
+        // selector + marketId + indexSet + amount
+        assert_eq!(result.len(), 2 + 8 + 64 * 3);
+        assert_eq!(&result[10..74], &market_id[2..]);
+        assert_eq!(&result[74..138], format!("{:064x}", 5));
+        assert_eq!(&result[138..202], format!("{:064x}", 1_000_000));
+
+        assert!(matches!(
+            CtfEncoder::encode_convert_positions(market_id, U256::ZERO, "1000000"),
+            Err(Error::InvalidParameter(_))
+        ));
+        for amount in ["", "-1", "1e6", "0x"] {
+            assert!(matches!(
+                CtfEncoder::encode_convert_positions(market_id, U256::from(0b101), amount),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_encode_approve() {
         let spender = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
-        let amount = 1000000u128; // 1 USDC
+        let amount = U256::from(1_000_000); // 1 USDC
 
-        let result = CtfEncoder::encode_approve(spender, amount);
+        let result = CtfEncoder::encode_approve(spender, amount).unwrap();
 
         assert!(result.starts_with("0x095ea7b3"));
     }
 
+    #[test]
+    fn test_encode_uint256_beyond_u128() {
+        // u128::MAX + 1 = 2^128
+        let value = "340282366920938463463374607431768211456";
+        let expected = format!("{:0>64}", format!("1{}", "0".repeat(32)));
+
+        assert_eq!(encode_uint256_from_str(value).unwrap(), expected);
+        assert_eq!(
+            encode_uint256_from_str(&format!("0x1{}", "0".repeat(32))).unwrap(),
+            expected
+        );
+        assert_eq!(
+            encode_uint256_from_str(&U256::MAX.to_string()).unwrap(),
+            "f".repeat(64)
+        );
+
+        let result = CtfEncoder::encode_split_position_binary(
+            "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+            "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
+            value,
+        )
+        .unwrap();
+        assert_eq!(&result[10 + 4 * 64..10 + 5 * 64], expected);
+
+        let approve =
+            CtfEncoder::encode_approve("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045", U256::MAX)
+                .unwrap();
+        assert_eq!(
+            approve,
+            CtfEncoder::encode_approve_max("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_rejects_malformed_amounts() {
+        let collateral = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+        let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+        // Too large, negative, non-numeric and bad hex amounts are all rejected
+        let too_large = format!("{}0", U256::MAX);
+        for amount in [too_large.as_str(), "-1", "1.5", "", "0x", "0xzz"] {
+            assert!(matches!(
+                CtfEncoder::encode_split_position_binary(collateral, condition_id, amount),
+                Err(Error::InvalidParameter(_))
+            ));
+            assert!(matches!(
+                CtfEncoder::encode_merge_positions_binary(collateral, condition_id, amount),
+                Err(Error::InvalidParameter(_))
+            ));
+            assert!(matches!(
+                CtfEncoder::encode_neg_risk_redeem(condition_id, &["1", amount]),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_encode_split_position() {
         let collateral = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
         let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
         let amount = "1000000";
 
-        let result = CtfEncoder::encode_split_position(collateral, condition_id, amount);
+        let result =
+            CtfEncoder::encode_split_position_binary(collateral, condition_id, amount).unwrap();
+
+        let selector = &alloy_primitives::keccak256(
+            "splitPosition(address,bytes32,bytes32,uint256[],uint256)",
+        )[..4];
+        assert!(result.starts_with(&format!("0x{}", alloy_primitives::hex::encode(selector))));
+        assert_eq!(
+            result,
+            CtfEncoder::encode_split_position(collateral, condition_id, &[1, 2], amount).unwrap()
+        );
+    }
 
-        assert!(result.starts_with("0x72ce4275"));
+    #[test]
+    fn test_encode_split_position_four_outcomes() {
+        let collateral = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+        let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+        let result =
+            CtfEncoder::encode_split_position(collateral, condition_id, &[1, 2, 4, 8], "1000000")
+                .unwrap();
+
+        // selector + 5 head words + array length + 4 elements
+        assert_eq!(result.len(), 2 + 8 + 64 * 10);
+        let words: Vec<&str> = (0..10)
+            .map(|i| &result[10 + i * 64..10 + (i + 1) * 64])
+            .collect();
+        assert_eq!(words[3], format!("{:064x}", 160));
+        assert_eq!(words[4], format!("{:064x}", 1_000_000));
+        assert_eq!(words[5], format!("{:064x}", 4));
+        assert_eq!(words[6..], [1, 2, 4, 8].map(|v| format!("{:064x}", v)));
+    }
+
+    #[test]
+    fn test_encode_merge_positions_four_outcomes() {
+        let collateral = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+        let condition_id = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+        let result =
+            CtfEncoder::encode_merge_positions(collateral, condition_id, &[1, 2, 4, 8], "5")
+                .unwrap();
+
+        let selector = &alloy_primitives::keccak256(
+            "mergePositions(address,bytes32,bytes32,uint256[],uint256)",
+        )[..4];
+        assert!(result.starts_with(&format!("0x{}", alloy_primitives::hex::encode(selector))));
+        assert_eq!(result.len(), 2 + 8 + 64 * 10);
+        assert!(result.ends_with(&format!("{:064x}{:064x}", 4, 8)));
+    }
+
+    fn word(value: u64) -> String {
+        format!("{:064x}", value)
+    }
+
+    #[test]
+    fn test_decode_payout_redemption() {
+        let ctf = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let redeemer = "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5";
+        let collateral = "0x2791bca1f2de4661ed88a30c99a7a9449aa84174";
+        let condition_id = "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+        // Receipt of a redemption of index set 2 paying out 2.5 USDC, next to
+        // an unrelated USDC Transfer log
+        let receipt: TransactionReceipt = serde_json::from_value(serde_json::json!({
+            "transactionHash": format!("0x{}", "11".repeat(32)),
+            "blockNumber": "0x4c4b40",
+            "gasUsed": "0x1d8a8",
+            "status": "0x1",
+            "logs": [
+                {
+                    "address": collateral,
+                    "topics": [
+                        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                        format!("0x{:0>64}", &ctf[2..]),
+                        format!("0x{:0>64}", &redeemer[2..]),
+                    ],
+                    "data": format!("0x{}", word(2_500_000)),
+                },
+                {
+                    "address": ctf.to_lowercase(),
+                    "topics": [
+                        events::PayoutRedemption::SIGNATURE_HASH.to_string(),
+                        format!("0x{:0>64}", &redeemer[2..]),
+                        format!("0x{:0>64}", &collateral[2..]),
+                        format!("0x{}", word(0)),
+                    ],
+                    "data": format!(
+                        "0x{}{}{}{}{}",
+                        condition_id,
+                        word(0x60),
+                        word(2_500_000),
+                        word(1),
+                        word(2)
+                    ),
+                },
+            ],
+        }))
+        .unwrap();
+
+        assert!(receipt.succeeded());
+        assert_eq!(receipt.block_number, 5_000_000);
+        assert_eq!(receipt.gas_used, 121_000);
+
+        let events = decode_ctf_events(&receipt, ctf).unwrap();
+        assert_eq!(
+            events,
+            vec![CtfEvent::PayoutRedemption {
+                redeemer: redeemer.parse().unwrap(),
+                collateral_token: collateral.parse().unwrap(),
+                parent_collection_id: B256::ZERO,
+                condition_id: format!("0x{}", condition_id).parse().unwrap(),
+                index_sets: vec![U256::from(2)],
+                payout: U256::from(2_500_000),
+            }]
+        );
+
+        // Logs of other contracts are ignored
+        let other = "0x0000000000000000000000000000000000000001";
+        assert!(decode_ctf_events(&receipt, other).unwrap().is_empty());
     }
 }
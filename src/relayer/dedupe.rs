@@ -0,0 +1,191 @@
+//! Client-side guard against submitting the same relayer transaction twice
+
+use alloy_primitives::{keccak256, B256};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::types::{RelayerSubmitResponse, TransactionRequest};
+
+/// Number of recent submissions remembered
+const SUBMISSION_CACHE_CAPACITY: usize = 64;
+
+/// Result of checking a request against recent submissions
+#[derive(Debug)]
+pub(crate) enum SubmissionCheck {
+    /// No identical request within the window; it is now recorded as pending
+    New(B256),
+    /// An identical request was submitted and accepted
+    Accepted(RelayerSubmitResponse),
+    /// An identical request was submitted but its outcome is unknown
+    Pending,
+}
+
+struct Submission {
+    fingerprint: B256,
+    submitted_at: Instant,
+    response: Option<RelayerSubmitResponse>,
+}
+
+/// Recently submitted request fingerprints, oldest first
+///
+/// Best-effort: only submissions made through the same client within `window`
+/// are seen, and at most [`SUBMISSION_CACHE_CAPACITY`] of them are kept.
+pub(crate) struct SubmissionCache {
+    window: Duration,
+    submissions: Mutex<VecDeque<Submission>>,
+}
+
+impl SubmissionCache {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            submissions: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Look up a request, recording it as pending if it was not seen recently
+    pub(crate) fn check(&self, request: &TransactionRequest) -> SubmissionCheck {
+        let fingerprint = fingerprint(request);
+        let mut submissions = self.submissions.lock().unwrap_or_else(|e| e.into_inner());
+        submissions.retain(|s| s.submitted_at.elapsed() < self.window);
+
+        if let Some(submission) = submissions.iter().find(|s| s.fingerprint == fingerprint) {
+            return match &submission.response {
+                Some(response) => SubmissionCheck::Accepted(response.clone()),
+                None => SubmissionCheck::Pending,
+            };
+        }
+
+        if submissions.len() == SUBMISSION_CACHE_CAPACITY {
+            submissions.pop_front();
+        }
+        submissions.push_back(Submission {
+            fingerprint,
+            submitted_at: Instant::now(),
+            response: None,
+        });
+        SubmissionCheck::New(fingerprint)
+    }
+
+    /// Remember the relayer's response to a submission
+    pub(crate) fn accepted(&self, fingerprint: B256, response: &RelayerSubmitResponse) {
+        let mut submissions = self.submissions.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(submission) = submissions
+            .iter_mut()
+            .find(|s| s.fingerprint == fingerprint)
+        {
+            submission.response = Some(response.clone());
+        }
+    }
+
+    /// Forget a submission the relayer rejected, so it can be retried
+    pub(crate) fn rejected(&self, fingerprint: B256) {
+        let mut submissions = self.submissions.lock().unwrap_or_else(|e| e.into_inner());
+        submissions.retain(|s| s.fingerprint != fingerprint);
+    }
+}
+
+/// Hash of the fields identifying a submission: from, to, data and nonce
+fn fingerprint(request: &TransactionRequest) -> B256 {
+    let nonce = request.nonce.as_deref().unwrap_or_default();
+    let parts = [
+        request.from.as_str(),
+        request.to.as_str(),
+        request.data.as_str(),
+        nonce,
+    ];
+    keccak256(parts.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(nonce: &str) -> TransactionRequest {
+        TransactionRequest {
+            tx_type: "SAFE".to_string(),
+            from: "0x01".to_string(),
+            to: "0x02".to_string(),
+            proxy_wallet: "0x03".to_string(),
+            data: "0xabcd".to_string(),
+            signature: "0x".to_string(),
+            value: None,
+            nonce: Some(nonce.to_string()),
+            signature_params: None,
+            metadata: None,
+        }
+    }
+
+    fn response(id: &str) -> RelayerSubmitResponse {
+        RelayerSubmitResponse {
+            transaction_id: id.to_string(),
+            transaction_hash: None,
+            state: None,
+        }
+    }
+
+    #[test]
+    fn test_duplicate_is_detected() {
+        let cache = SubmissionCache::new(Duration::from_secs(60));
+
+        let SubmissionCheck::New(fingerprint) = cache.check(&request("1")) else {
+            panic!("first submission should be new");
+        };
+        assert!(matches!(
+            cache.check(&request("1")),
+            SubmissionCheck::Pending
+        ));
+
+        cache.accepted(fingerprint, &response("tx1"));
+        match cache.check(&request("1")) {
+            SubmissionCheck::Accepted(response) => assert_eq!(response.transaction_id, "tx1"),
+            other => panic!("expected the prior response, got {:?}", other),
+        }
+
+        // A different nonce is a different transaction
+        assert!(matches!(
+            cache.check(&request("2")),
+            SubmissionCheck::New(_)
+        ));
+    }
+
+    #[test]
+    fn test_rejected_and_expired_submissions_are_forgotten() {
+        let cache = SubmissionCache::new(Duration::from_secs(60));
+        let SubmissionCheck::New(fingerprint) = cache.check(&request("1")) else {
+            panic!("first submission should be new");
+        };
+        cache.rejected(fingerprint);
+        assert!(matches!(
+            cache.check(&request("1")),
+            SubmissionCheck::New(_)
+        ));
+
+        let cache = SubmissionCache::new(Duration::ZERO);
+        assert!(matches!(
+            cache.check(&request("1")),
+            SubmissionCheck::New(_)
+        ));
+        assert!(matches!(
+            cache.check(&request("1")),
+            SubmissionCheck::New(_)
+        ));
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let cache = SubmissionCache::new(Duration::from_secs(60));
+        for nonce in 0..=SUBMISSION_CACHE_CAPACITY {
+            cache.check(&request(&nonce.to_string()));
+        }
+        assert!(matches!(
+            cache.check(&request("0")),
+            SubmissionCheck::New(_)
+        ));
+        assert!(matches!(
+            cache.check(&request(&SUBMISSION_CACHE_CAPACITY.to_string())),
+            SubmissionCheck::Pending
+        ));
+    }
+}
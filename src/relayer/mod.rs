@@ -13,7 +13,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use polymarket_rs::relayer::{RelayerClient, BuilderApiCreds};
+//! use polymarket_rs::relayer::{BuilderApiCreds, RelayerClient, TransactionWaitConfig};
 //! use alloy_signer_local::PrivateKeySigner;
 //! use std::str::FromStr;
 //!
@@ -34,7 +34,9 @@
 //!
 //! // Deploy a Safe wallet
 //! let deploy_result = client.deploy().await?;
-//! let tx = client.wait_for_transaction(&deploy_result.transaction_id, None, None).await?;
+//! let tx = client
+//!     .wait_for_transaction(&deploy_result.transaction_id, TransactionWaitConfig::default())
+//!     .await?;
 //!
 //! // Redeem positions after market resolution
 //! let condition_id = "0x...";
@@ -43,10 +45,17 @@
 //! # }
 //! ```
 
+mod builder;
 mod client;
 mod ctf;
+mod dedupe;
+mod nonce;
 mod types;
 
-pub use client::{derive_safe_address, RelayerClient};
-pub use ctf::CtfEncoder;
+pub use builder::RelayerClientBuilder;
+pub use client::{
+    derive_proxy_address, derive_safe_address, derive_safe_address_checksummed, RelayerClient,
+};
+pub use ctf::{decode_ctf_events, CtfEncoder, CtfEvent, BINARY_PARTITION};
+pub use nonce::NonceManager;
 pub use types::*;
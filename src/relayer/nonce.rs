@@ -0,0 +1,144 @@
+//! Local Safe nonce counter for submitting many transactions in a row
+
+use std::sync::Mutex;
+
+/// Hands out consecutive Safe nonces without asking the relayer each time
+///
+/// The nonce is fetched from the relayer on first use, then incremented
+/// locally for each transaction. Attach it with
+/// [`RelayerClient::with_nonce_manager`](super::RelayerClient::with_nonce_manager)
+/// to make [`execute`](super::RelayerClient::execute) use it, including from
+/// concurrent tasks.
+///
+/// When an execute fails, the client:
+/// - gives the nonce back if the transaction was never submitted or the
+///   relayer rejected it with a 4xx, as long as no later nonce was handed out
+///   since; otherwise later nonces would sit behind the unused one, so it
+///   resyncs instead
+/// - resyncs on [`RelayerError::NonceTooLow`](crate::RelayerError::NonceTooLow)
+///   and on a 5xx, so the next execute refetches the nonce
+/// - keeps the counter if the outcome is unknown (e.g. a timeout), since the
+///   relayer may have accepted the transaction
+///
+/// After a resync the relayer's nonce wins, so a nonce left unused by a
+/// failure is filled again rather than leaving a gap the Safe can't execute
+/// past. Call [`resync`](Self::resync) yourself if the Safe is also used from
+/// elsewhere.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    state: Mutex<NonceState>,
+}
+
+#[derive(Debug, Default)]
+struct NonceState {
+    /// Next nonce to hand out, `None` until fetched
+    next: Option<u64>,
+}
+
+impl NonceState {
+    fn take(&mut self, nonce: u64) -> u64 {
+        self.next = Some(nonce + 1);
+        nonce
+    }
+}
+
+impl NonceManager {
+    /// Create a manager that fetches the nonce on first use
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a manager whose next nonce is `nonce`
+    pub fn starting_at(nonce: u64) -> Self {
+        Self {
+            state: Mutex::new(NonceState { next: Some(nonce) }),
+        }
+    }
+
+    /// Get the nonce the next transaction will use, if it is known
+    pub fn peek(&self) -> Option<u64> {
+        self.lock().next
+    }
+
+    /// Forget the local counter so the nonce is fetched again on next use
+    pub fn resync(&self) {
+        self.lock().next = None;
+    }
+
+    /// Take the next nonce, or `None` if it must be fetched first
+    pub(crate) fn reserve(&self) -> Option<u64> {
+        let mut state = self.lock();
+        let nonce = state.next?;
+        Some(state.take(nonce))
+    }
+
+    /// Take the next nonce after fetching `fetched` from the relayer
+    ///
+    /// If another task set the counter while the nonce was being fetched, its
+    /// counter wins.
+    pub(crate) fn reserve_fetched(&self, fetched: u64) -> u64 {
+        let mut state = self.lock();
+        let nonce = state.next.unwrap_or(fetched);
+        state.take(nonce)
+    }
+
+    /// Give back a nonce that was not used
+    ///
+    /// If it was the most recently handed out nonce, it is handed out again
+    /// next. Otherwise later nonces are already in use behind it, so the
+    /// counter is reset and the next nonce is fetched from the relayer.
+    pub(crate) fn release(&self, nonce: u64) {
+        let mut state = self.lock();
+        state.next = match state.next {
+            Some(next) if next == nonce + 1 => Some(nonce),
+            _ => None,
+        };
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, NonceState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonce_manager_counts_and_resyncs() {
+        let manager = NonceManager::new();
+        assert_eq!(manager.reserve(), None);
+        assert_eq!(manager.reserve_fetched(4), 4);
+        assert_eq!(manager.reserve(), Some(5));
+        // A late fetch keeps the counter another task set
+        assert_eq!(manager.reserve_fetched(4), 6);
+        assert_eq!(manager.peek(), Some(7));
+
+        manager.resync();
+        assert_eq!(manager.peek(), None);
+        assert_eq!(manager.reserve(), None);
+        // After a resync the relayer's nonce wins, even below the old counter
+        assert_eq!(manager.reserve_fetched(5), 5);
+        manager.resync();
+        assert_eq!(manager.reserve_fetched(20), 20);
+
+        let manager = NonceManager::starting_at(10);
+        assert_eq!(manager.reserve(), Some(10));
+        assert_eq!(manager.peek(), Some(11));
+    }
+
+    #[test]
+    fn test_nonce_manager_release() {
+        let manager = NonceManager::starting_at(5);
+        let first = manager.reserve().unwrap();
+        let second = manager.reserve().unwrap();
+
+        manager.release(second);
+        assert_eq!(manager.peek(), Some(6));
+        assert_eq!(manager.reserve(), Some(6));
+
+        // With a later nonce in flight, the counter is refetched instead
+        manager.release(first);
+        assert_eq!(manager.peek(), None);
+    }
+}
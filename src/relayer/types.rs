@@ -3,7 +3,16 @@
 //! This module contains all types used for interacting with Polymarket's
 //! Polygon relayer infrastructure for gasless transactions.
 
-use serde::{Deserialize, Deserializer, Serialize};
+use crate::error::Error;
+use crate::types::normalize_address;
+use crate::utils::get_current_unix_time_secs;
+use alloy_primitives::{Address, Bytes, B256, U64};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::Sha256;
+use std::time::Duration;
+
+type HmacSha256 = Hmac<Sha256>;
 
 /// Deserialize a number or string to String
 fn deserialize_number_to_string<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -25,6 +34,23 @@ where
     }
 }
 
+/// Deserialize a hex quantity (e.g. `"0x1a"`) to u64
+fn deserialize_hex_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = U64::deserialize(deserializer)?;
+    Ok(value.to::<u64>())
+}
+
+/// Serialize a u64 as a hex quantity, the inverse of [`deserialize_hex_u64`]
+fn serialize_hex_u64<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    U64::from(*value).serialize(serializer)
+}
+
 /// Builder API credentials for relayer authentication
 ///
 /// **Important**: These credentials are different from CLOB API credentials!
@@ -61,24 +87,134 @@ impl BuilderApiCreds {
     /// - `POLY_API_SECRET`: The API secret
     /// - `POLY_PASSPHRASE`: The API passphrase
     ///
-    /// Returns `None` if any of the required environment variables are not set.
-    pub fn from_env() -> Option<Self> {
-        let key = std::env::var("POLY_API_KEY").ok()?;
-        let secret = std::env::var("POLY_API_SECRET").ok()?;
-        let passphrase = std::env::var("POLY_PASSPHRASE").ok()?;
+    /// Returns `Error::Config` if any of them is not set, or if the
+    /// credentials fail [`validate`](Self::validate).
+    pub fn from_env() -> crate::error::Result<Self> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| Error::Config(format!("{} is not set", name)))
+        };
+        let creds = Self {
+            key: var("POLY_API_KEY")?,
+            secret: var("POLY_API_SECRET")?,
+            passphrase: var("POLY_PASSPHRASE")?,
+        };
+        creds.validate()?;
+        Ok(creds)
+    }
 
-        Some(Self {
-            key,
-            secret,
-            passphrase,
+    /// Check that the credentials can sign requests
+    ///
+    /// Returns `Error::Config` if the key, secret or passphrase is empty, or
+    /// if the secret is not base64 (standard or URL-safe). Call it before
+    /// building a [`RelayerClient`](super::RelayerClient) to catch a mistyped
+    /// secret early instead of on the first submission.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        for (name, value) in [
+            ("key", &self.key),
+            ("secret", &self.secret),
+            ("passphrase", &self.passphrase),
+        ] {
+            if value.trim().is_empty() {
+                return Err(Error::Config(format!("Builder API {} is empty", name)));
+            }
+        }
+
+        self.decode_secret()
+            .map_err(|e| Error::Config(format!("Builder API secret is not valid base64: {}", e)))?;
+        Ok(())
+    }
+
+    /// Decode the secret as standard base64, falling back to URL-safe
+    fn decode_secret(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        // Standard base64 matches the TypeScript SDK's Buffer.from(secret, "base64")
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &self.secret).or_else(
+            |_| base64::Engine::decode(&base64::engine::general_purpose::URL_SAFE, &self.secret),
+        )
+    }
+
+    /// Sign a relayer request with the current timestamp
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method (e.g., "POST")
+    /// * `path` - Request path without host (e.g., "/submit")
+    /// * `body` - Exact request body that will be sent, if any
+    pub fn sign_request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> crate::error::Result<BuilderHeaders> {
+        self.sign_request_at(get_current_unix_time_secs()?, method, path, body)
+    }
+
+    /// Sign a relayer request with a fixed timestamp (Unix seconds)
+    ///
+    /// The signature is HMAC-SHA256 over `timestamp + method + path + body`, keyed
+    /// with the base64-decoded secret, encoded as URL-safe base64 with padding.
+    pub fn sign_request_at(
+        &self,
+        timestamp: u64,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> crate::error::Result<BuilderHeaders> {
+        let timestamp = timestamp.to_string();
+        let message = format!("{}{}{}{}", timestamp, method, path, body.unwrap_or(""));
+
+        let secret_bytes = self
+            .decode_secret()
+            .map_err(|e| Error::Signing(format!("Failed to decode secret: {}", e)))?;
+
+        let mut mac = HmacSha256::new_from_slice(&secret_bytes)
+            .map_err(|e| Error::Signing(format!("HMAC error: {}", e)))?;
+        mac.update(message.as_bytes());
+
+        // Use URL-safe base64 encoding for the signature (matching TypeScript SDK)
+        // TypeScript converts '+' to '-' and '/' to '_' but keeps '=' padding
+        let signature = base64::Engine::encode(
+            &base64::engine::general_purpose::URL_SAFE,
+            mac.finalize().into_bytes(),
+        );
+
+        Ok(BuilderHeaders {
+            api_key: self.key.clone(),
+            signature,
+            timestamp,
+            passphrase: self.passphrase.clone(),
         })
     }
 }
 
+/// Authentication headers for a relayer request signed with Builder API credentials
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuilderHeaders {
+    pub api_key: String,
+    pub signature: String,
+    pub timestamp: String,
+    pub passphrase: String,
+}
+
+impl BuilderHeaders {
+    /// Header names and values to attach to the request
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("POLY_BUILDER_API_KEY", self.api_key.as_str()),
+            ("POLY_BUILDER_SIGNATURE", self.signature.as_str()),
+            ("POLY_BUILDER_TIMESTAMP", self.timestamp.as_str()),
+            ("POLY_BUILDER_PASSPHRASE", self.passphrase.as_str()),
+        ]
+        .into_iter()
+    }
+}
+
 /// Operation type for Safe transactions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as its numeric value (0 for `Call`, 1 for `DelegateCall`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
 #[repr(u8)]
 pub enum OperationType {
+    #[default]
     Call = 0,
     DelegateCall = 1,
 }
@@ -89,17 +225,31 @@ impl From<OperationType> for u8 {
     }
 }
 
-impl Default for OperationType {
-    fn default() -> Self {
-        OperationType::Call
+impl TryFrom<u8> for OperationType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OperationType::Call),
+            1 => Ok(OperationType::DelegateCall),
+            _ => Err(Error::InvalidParameter(format!(
+                "Invalid operation type: {}",
+                value
+            ))),
+        }
     }
 }
 
 /// Transaction type for relayer requests
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Serializes as the same string the relayer uses (see [`as_str`](Self::as_str)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransactionType {
+    #[serde(rename = "SAFE")]
     Safe,
+    #[serde(rename = "SAFE-CREATE")]
     SafeCreate,
+    #[serde(rename = "PROXY")]
     Proxy,
 }
 
@@ -114,7 +264,9 @@ impl TransactionType {
 }
 
 /// A single Safe transaction
-#[derive(Debug, Clone)]
+///
+/// Serializable so pending batches can be persisted and resubmitted later.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SafeTransaction {
     pub to: String,
     pub operation: OperationType,
@@ -156,7 +308,7 @@ pub struct SafeTransactionArgs {
 }
 
 /// Signature parameters for Safe transactions
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SignatureParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -178,22 +330,15 @@ pub struct SignatureParams {
     pub payment: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_receiver: Option<String>,
-}
-
-impl Default for SignatureParams {
-    fn default() -> Self {
-        Self {
-            gas_price: None,
-            operation: None,
-            safe_txn_gas: None,
-            base_gas: None,
-            gas_token: None,
-            refund_receiver: None,
-            payment_token: None,
-            payment: None,
-            payment_receiver: None,
-        }
-    }
+    // For PROXY
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer_fee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relay_hub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relay: Option<String>,
 }
 
 impl SignatureParams {
@@ -206,24 +351,29 @@ impl SignatureParams {
             base_gas: Some("0".to_string()),
             gas_token: Some(ZERO_ADDRESS.to_string()),
             refund_receiver: Some(ZERO_ADDRESS.to_string()),
-            payment_token: None,
-            payment: None,
-            payment_receiver: None,
+            ..Default::default()
         }
     }
 
     /// Create signature params for Safe creation
     pub fn for_safe_create() -> Self {
         Self {
-            gas_price: None,
-            operation: None,
-            safe_txn_gas: None,
-            base_gas: None,
-            gas_token: None,
-            refund_receiver: None,
             payment_token: Some(ZERO_ADDRESS.to_string()),
             payment: Some("0".to_string()),
             payment_receiver: Some(ZERO_ADDRESS.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Create signature params for a proxy wallet transaction
+    pub fn for_proxy(gas_limit: &str, relay_hub: &str, relay: &str) -> Self {
+        Self {
+            gas_price: Some("0".to_string()),
+            gas_limit: Some(gas_limit.to_string()),
+            relayer_fee: Some("0".to_string()),
+            relay_hub: Some(relay_hub.to_string()),
+            relay: Some(relay.to_string()),
+            ..Default::default()
         }
     }
 }
@@ -249,24 +399,49 @@ pub struct TransactionRequest {
     pub metadata: Option<String>,
 }
 
+/// Signed Safe transaction that has not been submitted to the relayer
+///
+/// Returned by [`RelayerClient::execute_preview`](super::RelayerClient::execute_preview).
+#[derive(Debug, Clone)]
+pub struct TransactionPreview {
+    /// Exact request [`execute`](super::RelayerClient::execute) would submit
+    pub request: TransactionRequest,
+    /// EIP-712 SafeTx digest that was signed
+    pub digest: B256,
+}
+
 /// State of a relayer transaction
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// (De)serializes as the relayer's string form (e.g. `"STATE_MINED"`). States
+/// this version does not know are kept as [`Unknown`](Self::Unknown) with the
+/// raw string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum RelayerTransactionState {
-    #[serde(rename = "STATE_NEW")]
     New,
-    #[serde(rename = "STATE_EXECUTED")]
     Executed,
-    #[serde(rename = "STATE_MINED")]
     Mined,
-    #[serde(rename = "STATE_CONFIRMED")]
     Confirmed,
-    #[serde(rename = "STATE_FAILED")]
     Failed,
-    #[serde(rename = "STATE_INVALID")]
     Invalid,
+    /// A state not known to this version
+    Unknown(String),
 }
 
 impl RelayerTransactionState {
+    /// The state as sent by the relayer
+    pub fn as_str(&self) -> &str {
+        match self {
+            RelayerTransactionState::New => "STATE_NEW",
+            RelayerTransactionState::Executed => "STATE_EXECUTED",
+            RelayerTransactionState::Mined => "STATE_MINED",
+            RelayerTransactionState::Confirmed => "STATE_CONFIRMED",
+            RelayerTransactionState::Failed => "STATE_FAILED",
+            RelayerTransactionState::Invalid => "STATE_INVALID",
+            RelayerTransactionState::Unknown(state) => state,
+        }
+    }
+
     pub fn is_terminal(&self) -> bool {
         matches!(
             self,
@@ -282,6 +457,86 @@ impl RelayerTransactionState {
             RelayerTransactionState::Mined | RelayerTransactionState::Confirmed
         )
     }
+
+    /// Returns true if the transaction has progressed at least to `target`
+    ///
+    /// Progress runs New, Executed, Mined, Confirmed. Failed, invalid and
+    /// unknown states never reach a target.
+    pub fn has_reached(&self, target: &RelayerTransactionState) -> bool {
+        match (self.progress(), target.progress()) {
+            (Some(current), Some(target)) => current >= target,
+            _ => false,
+        }
+    }
+
+    fn progress(&self) -> Option<u8> {
+        match self {
+            RelayerTransactionState::New => Some(0),
+            RelayerTransactionState::Executed => Some(1),
+            RelayerTransactionState::Mined => Some(2),
+            RelayerTransactionState::Confirmed => Some(3),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for RelayerTransactionState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "STATE_NEW" => RelayerTransactionState::New,
+            "STATE_EXECUTED" => RelayerTransactionState::Executed,
+            "STATE_MINED" => RelayerTransactionState::Mined,
+            "STATE_CONFIRMED" => RelayerTransactionState::Confirmed,
+            "STATE_FAILED" => RelayerTransactionState::Failed,
+            "STATE_INVALID" => RelayerTransactionState::Invalid,
+            _ => RelayerTransactionState::Unknown(state),
+        }
+    }
+}
+
+impl From<RelayerTransactionState> for String {
+    fn from(state: RelayerTransactionState) -> Self {
+        match state {
+            RelayerTransactionState::Unknown(state) => state,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// Polling configuration for [`wait_for_transaction`](super::RelayerClient::wait_for_transaction)
+#[derive(Debug, Clone)]
+pub struct TransactionWaitConfig {
+    /// Delay before the second poll
+    pub initial_interval: Duration,
+    /// Maximum delay between polls
+    pub max_interval: Duration,
+    /// Multiplier applied to the delay after each poll
+    pub multiplier: f64,
+    /// Maximum number of polls before giving up
+    pub max_polls: u32,
+    /// State to wait for; later states also count (see
+    /// [`RelayerTransactionState::has_reached`])
+    pub target_state: RelayerTransactionState,
+}
+
+impl Default for TransactionWaitConfig {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(15),
+            multiplier: 1.5,
+            max_polls: 30,
+            target_state: RelayerTransactionState::Mined,
+        }
+    }
+}
+
+impl TransactionWaitConfig {
+    /// Set the state to wait for
+    pub fn with_target_state(mut self, target_state: RelayerTransactionState) -> Self {
+        self.target_state = target_state;
+        self
+    }
 }
 
 /// Response from submitting a transaction to the relayer
@@ -292,7 +547,7 @@ pub struct RelayerSubmitResponse {
     #[serde(rename = "transactionHash", default)]
     pub transaction_hash: Option<String>,
     #[serde(default)]
-    pub state: Option<String>,
+    pub state: Option<RelayerTransactionState>,
 }
 
 /// Full relayer transaction details
@@ -311,7 +566,7 @@ pub struct RelayerTransaction {
     #[serde(default)]
     pub data: Option<String>,
     #[serde(default)]
-    pub state: Option<String>,
+    pub state: Option<RelayerTransactionState>,
     #[serde(rename = "type", default)]
     pub tx_type: Option<String>,
     #[serde(default)]
@@ -323,16 +578,9 @@ pub struct RelayerTransaction {
 }
 
 impl RelayerTransaction {
+    /// Get the transaction state (same as the `state` field)
     pub fn get_state(&self) -> Option<RelayerTransactionState> {
-        self.state.as_ref().and_then(|s| match s.as_str() {
-            "STATE_NEW" => Some(RelayerTransactionState::New),
-            "STATE_EXECUTED" => Some(RelayerTransactionState::Executed),
-            "STATE_MINED" => Some(RelayerTransactionState::Mined),
-            "STATE_CONFIRMED" => Some(RelayerTransactionState::Confirmed),
-            "STATE_FAILED" => Some(RelayerTransactionState::Failed),
-            "STATE_INVALID" => Some(RelayerTransactionState::Invalid),
-            _ => None,
-        })
+        self.state.clone()
     }
 }
 
@@ -342,12 +590,68 @@ pub struct NonceResponse {
     pub nonce: String,
 }
 
+/// Response from relay-payload endpoint
+///
+/// Contains the relay address that will broadcast the transaction and the
+/// signer's current nonce for the requested transaction type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayPayload {
+    pub address: String,
+    pub nonce: String,
+}
+
 /// Response from deployed endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeployedResponse {
     pub deployed: bool,
 }
 
+/// JSON-RPC response, used for on-chain reads
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct JsonRpcResponse<T = String> {
+    pub result: Option<T>,
+    pub error: Option<serde_json::Value>,
+}
+
+/// Receipt of a mined transaction, as returned by `eth_getTransactionReceipt`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    pub transaction_hash: B256,
+    #[serde(
+        serialize_with = "serialize_hex_u64",
+        deserialize_with = "deserialize_hex_u64"
+    )]
+    pub block_number: u64,
+    #[serde(
+        serialize_with = "serialize_hex_u64",
+        deserialize_with = "deserialize_hex_u64"
+    )]
+    pub gas_used: u64,
+    /// 1 if the transaction succeeded, 0 if it reverted
+    #[serde(
+        serialize_with = "serialize_hex_u64",
+        deserialize_with = "deserialize_hex_u64"
+    )]
+    pub status: u64,
+    pub logs: Vec<ReceiptLog>,
+}
+
+impl TransactionReceipt {
+    /// Returns true if the transaction did not revert
+    pub fn succeeded(&self) -> bool {
+        self.status == 1
+    }
+}
+
+/// Event log emitted by a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptLog {
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Bytes,
+}
+
 /// Relayer contract configuration
 #[derive(Debug, Clone)]
 pub struct RelayerContractConfig {
@@ -355,6 +659,44 @@ pub struct RelayerContractConfig {
     pub safe_multisend: String,
     pub ctf: String,
     pub collateral: String,
+    /// CTF Exchange, which needs a collateral allowance to fill orders
+    pub exchange: String,
+    /// NegRiskAdapter, used to redeem negative-risk market positions
+    pub neg_risk_adapter: String,
+    /// Proxy wallet factory (None if proxy wallets are unsupported on the chain)
+    pub proxy_factory: Option<String>,
+    /// Relay hub used by the proxy wallet factory
+    pub relay_hub: Option<String>,
+}
+
+impl RelayerContractConfig {
+    /// Check that every address is a well-formed 20-byte hex address
+    ///
+    /// # Errors
+    /// Returns `Error::Config` naming the first malformed field.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let addresses = [
+            ("safe_factory", Some(&self.safe_factory)),
+            ("safe_multisend", Some(&self.safe_multisend)),
+            ("ctf", Some(&self.ctf)),
+            ("collateral", Some(&self.collateral)),
+            ("exchange", Some(&self.exchange)),
+            ("neg_risk_adapter", Some(&self.neg_risk_adapter)),
+            ("proxy_factory", self.proxy_factory.as_ref()),
+            ("relay_hub", self.relay_hub.as_ref()),
+        ];
+        for (field, address) in addresses {
+            if let Some(address) = address {
+                normalize_address(address).map_err(|_| {
+                    Error::Config(format!(
+                        "Invalid {} address in relayer config: {}",
+                        field, address
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Constants
@@ -362,6 +704,14 @@ pub const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 pub const SAFE_INIT_CODE_HASH: &str =
     "0x2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf";
 pub const SAFE_FACTORY_NAME: &str = "Polymarket Contract Proxy Factory";
+pub const PROXY_INIT_CODE_HASH: &str =
+    "0xd21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b";
+/// Gas limit used for proxy transactions when no estimate is available
+pub const DEFAULT_PROXY_GAS_LIMIT: u64 = 10_000_000;
+/// Page size used when fetching all redeemable positions
+pub const REDEEMABLE_PAGE_SIZE: u32 = 100;
+/// Maximum length of transaction metadata accepted by the relayer, in characters
+pub const MAX_METADATA_LEN: usize = 500;
 
 /// Polygon Mainnet relayer configuration
 pub fn mainnet_relayer_config() -> RelayerContractConfig {
@@ -370,6 +720,10 @@ pub fn mainnet_relayer_config() -> RelayerContractConfig {
         safe_multisend: "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761".to_string(),
         ctf: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
         collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
+        exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
+        neg_risk_adapter: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_string(),
+        proxy_factory: Some("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052".to_string()),
+        relay_hub: Some("0xD216153c06E857cD7f72665E0aF1d7D82172F494".to_string()),
     }
 }
 
@@ -380,6 +734,11 @@ pub fn amoy_relayer_config() -> RelayerContractConfig {
         safe_multisend: "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761".to_string(),
         ctf: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_string(),
         collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_string(),
+        exchange: "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_string(),
+        neg_risk_adapter: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_string(),
+        // Proxy wallets are not supported on Amoy
+        proxy_factory: None,
+        relay_hub: None,
     }
 }
 
@@ -414,6 +773,51 @@ pub(crate) struct PositionData {
     /// Current value of the position in USDC
     #[serde(rename = "currentValue", default)]
     pub current_value: f64,
+    #[serde(rename = "negativeRisk", default)]
+    pub negative_risk: bool,
+}
+
+/// Query options for [`RelayerClient::get_redeemable_positions`](super::RelayerClient::get_redeemable_positions)
+#[derive(Debug, Clone)]
+pub struct RedeemableParams {
+    /// Minimum position size returned by the data API (default 0.1)
+    pub size_threshold: f64,
+    /// Maximum number of positions to fetch (default 100), or `None` to fetch all pages
+    pub limit: Option<u32>,
+    /// Keep only positions whose current value is above this (default 0.0),
+    /// or `None` to keep every redeemable position
+    pub min_value: Option<f64>,
+}
+
+impl Default for RedeemableParams {
+    fn default() -> Self {
+        Self {
+            size_threshold: 0.1,
+            limit: Some(100),
+            min_value: Some(0.0),
+        }
+    }
+}
+
+impl RedeemableParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_size_threshold(mut self, size_threshold: f64) -> Self {
+        self.size_threshold = size_threshold;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: Option<u32>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_min_value(mut self, min_value: Option<f64>) -> Self {
+        self.min_value = min_value;
+        self
+    }
 }
 
 /// A position that can be redeemed
@@ -434,3 +838,235 @@ pub struct RedeemablePosition {
     /// Current value of the position in USDC
     pub current_value: f64,
 }
+
+/// A pair of complementary positions that can be merged back into collateral
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeablePosition {
+    /// The condition ID of the market
+    pub condition_id: String,
+    /// The market title
+    pub title: String,
+    /// Amount that can be merged (in smallest units): the smaller of the two outcome sizes
+    pub amount: String,
+    /// Whether the market is a negative risk market
+    pub negative_risk: bool,
+}
+
+/// Outcome of [`redeem_all_positions_concurrent`](super::RelayerClient::redeem_all_positions_concurrent)
+///
+/// Holds one result per redeemable position, so a failed redemption does not
+/// hide the ones that went through.
+#[derive(Debug, Default)]
+pub struct RedeemSummary {
+    /// `(condition_id, result)` for each position, in completion order
+    pub results: Vec<(String, crate::error::Result<RelayerSubmitResponse>)>,
+}
+
+impl RedeemSummary {
+    /// Number of redemptions submitted successfully
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|(_, r)| r.is_ok()).count()
+    }
+
+    /// Number of redemptions that failed
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+}
+
+/// Outcome of [`merge_all_positions`](super::RelayerClient::merge_all_positions)
+///
+/// Holds one result per mergeable condition, so a failed merge does not hide
+/// the ones that went through.
+#[derive(Debug, Default)]
+pub struct MergeSummary {
+    /// `(condition_id, result)` for each condition, in submission order
+    pub results: Vec<(String, crate::error::Result<RelayerSubmitResponse>)>,
+}
+
+impl MergeSummary {
+    /// Number of merges submitted successfully
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|(_, r)| r.is_ok()).count()
+    }
+
+    /// Number of merges that failed
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn creds() -> BuilderApiCreds {
+        // Secret is base64 of the bytes 0x00..=0x1f
+        BuilderApiCreds::new(
+            "builder-key".to_string(),
+            "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+            "builder-pass".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_sign_request_known_vector() {
+        let body = r#"{"from":"0x6e0c80c90ea6c15917308f820eac91ce2724b5b5"}"#;
+        let headers = creds()
+            .sign_request_at(1700000003, "POST", "/submit", Some(body))
+            .unwrap();
+
+        // HMAC-SHA256 of "1700000003POST/submit{body}", URL-safe base64 with padding
+        assert_eq!(
+            headers.signature,
+            "oz8KtY_fSs0-4YmxMjsaQQhv9ndGU2E8u02OdkfswDI="
+        );
+        assert_eq!(headers.timestamp, "1700000003");
+        assert_eq!(headers.api_key, "builder-key");
+        assert_eq!(headers.passphrase, "builder-pass");
+    }
+
+    #[test]
+    fn test_sign_request_accepts_url_safe_secret() {
+        let mut standard = creds();
+        standard.secret = "+/+/".to_string();
+        let mut url_safe = creds();
+        url_safe.secret = "-_-_".to_string();
+
+        assert_eq!(
+            standard.sign_request_at(1, "GET", "/nonce", None).unwrap(),
+            url_safe.sign_request_at(1, "GET", "/nonce", None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate_builder_creds() {
+        creds().validate().unwrap();
+
+        let mut url_safe = creds();
+        url_safe.secret = "-_-_".to_string();
+        url_safe.validate().unwrap();
+
+        let mut invalid = creds();
+        invalid.secret = "not base64!".to_string();
+        match invalid.validate() {
+            Err(Error::Config(msg)) => {
+                assert!(
+                    msg.starts_with("Builder API secret is not valid base64"),
+                    "{}",
+                    msg
+                )
+            }
+            other => panic!("expected Config error, got {:?}", other),
+        }
+
+        let mut empty = creds();
+        empty.passphrase = " ".to_string();
+        match empty.validate() {
+            Err(Error::Config(msg)) => assert_eq!(msg, "Builder API passphrase is empty"),
+            other => panic!("expected Config error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_headers_iter() {
+        let headers = creds().sign_request_at(1, "GET", "/nonce", None).unwrap();
+        let names: Vec<_> = headers.iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "POLY_BUILDER_API_KEY",
+                "POLY_BUILDER_SIGNATURE",
+                "POLY_BUILDER_TIMESTAMP",
+                "POLY_BUILDER_PASSPHRASE",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_safe_transaction_serde_round_trip() {
+        let transactions = vec![
+            SafeTransaction::new("0x4d97dcd97ec945f40cf65f87097ace5ea0476045", "0xabcd"),
+            SafeTransaction::new("0xa238cbeb142c10ef7ad8442c6d1f9e89e07e7761", "0x")
+                .operation(OperationType::DelegateCall)
+                .value("5"),
+        ];
+
+        let json = serde_json::to_value(&transactions).unwrap();
+        assert_eq!(json[0]["operation"], 0);
+        assert_eq!(json[1]["operation"], 1);
+        assert_eq!(json[1]["value"], "5");
+
+        let decoded: Vec<SafeTransaction> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, transactions);
+
+        assert!(serde_json::from_str::<OperationType>("2").is_err());
+    }
+
+    #[test]
+    fn test_transaction_receipt_serde_round_trip() {
+        let json = serde_json::json!({
+            "transactionHash": "0x9c1b7d3e5a6f8e2c4b0d1a3e5f7092b4c6d8e0f1a2b3c4d5e6f708192a3b4c5d",
+            "blockNumber": "0x4c4b40",
+            "gasUsed": "0x1d8a8",
+            "status": "0x1",
+            "logs": [{
+                "address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                "topics": [
+                    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                ],
+                "data": "0x00000000000000000000000000000000000000000000000000000000002625a0"
+            }]
+        });
+        let receipt: TransactionReceipt = serde_json::from_value(json.clone()).unwrap();
+
+        let encoded = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(encoded, json);
+        let decoded: TransactionReceipt = serde_json::from_value(encoded).unwrap();
+        assert_eq!(decoded, receipt);
+    }
+
+    #[test]
+    fn test_transaction_type_serde_matches_as_str() {
+        for tx_type in [
+            TransactionType::Safe,
+            TransactionType::SafeCreate,
+            TransactionType::Proxy,
+        ] {
+            let json = serde_json::to_string(&tx_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", tx_type.as_str()));
+            assert_eq!(
+                serde_json::from_str::<TransactionType>(&json).unwrap(),
+                tx_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_transaction_state_deserialization() {
+        let tx: RelayerTransaction =
+            serde_json::from_str(r#"{"transactionID":"1","state":"STATE_MINED"}"#).unwrap();
+        assert_eq!(tx.state, Some(RelayerTransactionState::Mined));
+        assert!(tx.get_state().unwrap().is_success());
+
+        let response: RelayerSubmitResponse =
+            serde_json::from_str(r#"{"transactionID":"2","state":"STATE_FOO"}"#).unwrap();
+        let state = response.state.unwrap();
+        assert_eq!(
+            state,
+            RelayerTransactionState::Unknown("STATE_FOO".to_string())
+        );
+        assert_eq!(state.as_str(), "STATE_FOO");
+        assert!(!state.is_terminal());
+
+        // Raw strings survive a round trip
+        assert_eq!(serde_json::to_string(&state).unwrap(), r#""STATE_FOO""#);
+        assert_eq!(
+            serde_json::to_string(&RelayerTransactionState::Confirmed).unwrap(),
+            r#""STATE_CONFIRMED""#
+        );
+
+        let tx: RelayerTransaction = serde_json::from_str(r#"{"transactionID":"3"}"#).unwrap();
+        assert_eq!(tx.state, None);
+    }
+}
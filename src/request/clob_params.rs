@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+
+use super::QueryBuilder;
+use crate::types::TokenId;
+
+/// Time range covered by a price history query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceHistoryInterval {
+    OneHour,
+    SixHours,
+    OneDay,
+    OneWeek,
+    OneMonth,
+    Max,
+}
+
+impl PriceHistoryInterval {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PriceHistoryInterval::OneHour => "1h",
+            PriceHistoryInterval::SixHours => "6h",
+            PriceHistoryInterval::OneDay => "1d",
+            PriceHistoryInterval::OneWeek => "1w",
+            PriceHistoryInterval::OneMonth => "1m",
+            PriceHistoryInterval::Max => "max",
+        }
+    }
+}
+
+/// Query parameters for the CLOB `/prices-history` endpoint
+///
+/// The CLOB takes either an `interval` ending now or an explicit
+/// `start_ts`/`end_ts` range. `fidelity` is the resolution in minutes.
+#[derive(Debug, Clone)]
+pub struct PriceHistoryParams {
+    pub token_id: TokenId,
+    pub interval: Option<PriceHistoryInterval>,
+    pub fidelity: Option<u32>,
+    pub start_ts: Option<DateTime<Utc>>,
+    pub end_ts: Option<DateTime<Utc>>,
+}
+
+impl PriceHistoryParams {
+    pub fn new(token_id: TokenId) -> Self {
+        Self {
+            token_id,
+            interval: None,
+            fidelity: None,
+            start_ts: None,
+            end_ts: None,
+        }
+    }
+
+    pub fn with_interval(mut self, interval: PriceHistoryInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn with_fidelity(mut self, fidelity: u32) -> Self {
+        self.fidelity = Some(fidelity);
+        self
+    }
+
+    pub fn with_start_ts(mut self, start_ts: DateTime<Utc>) -> Self {
+        self.start_ts = Some(start_ts);
+        self
+    }
+
+    pub fn with_end_ts(mut self, end_ts: DateTime<Utc>) -> Self {
+        self.end_ts = Some(end_ts);
+        self
+    }
+
+    pub fn to_query(&self) -> QueryBuilder {
+        QueryBuilder::new()
+            .push("market", self.token_id.as_str())
+            .push_opt("interval", self.interval.as_ref().map(|i| i.as_str()))
+            .push_opt("fidelity", self.fidelity)
+            .push_opt("startTs", self.start_ts.map(|ts| ts.timestamp()))
+            .push_opt("endTs", self.end_ts.map(|ts| ts.timestamp()))
+    }
+
+    pub fn to_query_string(&self) -> String {
+        self.to_query().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_price_history_query_string() {
+        let params = PriceHistoryParams::new(TokenId::new("123"))
+            .with_interval(PriceHistoryInterval::OneDay)
+            .with_fidelity(60);
+        assert_eq!(
+            params.to_query_string(),
+            "?market=123&interval=1d&fidelity=60"
+        );
+
+        let params =
+            PriceHistoryParams::new(TokenId::new("123")).with_interval(PriceHistoryInterval::Max);
+        assert_eq!(params.to_query_string(), "?market=123&interval=max");
+    }
+
+    #[test]
+    fn test_price_history_query_string_with_range() {
+        let params = PriceHistoryParams::new(TokenId::new("123"))
+            .with_start_ts(Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+            .with_end_ts(Utc.timestamp_opt(1_700_086_400, 0).unwrap());
+        assert_eq!(
+            params.to_query_string(),
+            "?market=123&startTs=1700000000&endTs=1700086400"
+        );
+    }
+}
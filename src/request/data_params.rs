@@ -1,3 +1,5 @@
+use super::QueryBuilder;
+
 /// Sort direction for activity queries
 #[derive(Debug, Clone)]
 pub enum SortDirection {
@@ -28,6 +30,34 @@ impl ActivitySortBy {
     }
 }
 
+/// Sort field for position queries
+#[derive(Debug, Clone)]
+pub enum PositionSortBy {
+    Current,
+    Initial,
+    Tokens,
+    CashPnl,
+    PercentPnl,
+    Title,
+    Resolving,
+    Price,
+}
+
+impl PositionSortBy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PositionSortBy::Current => "CURRENT",
+            PositionSortBy::Initial => "INITIAL",
+            PositionSortBy::Tokens => "TOKENS",
+            PositionSortBy::CashPnl => "CASHPNL",
+            PositionSortBy::PercentPnl => "PERCENTPNL",
+            PositionSortBy::Title => "TITLE",
+            PositionSortBy::Resolving => "RESOLVING",
+            PositionSortBy::Price => "PRICE",
+        }
+    }
+}
+
 /// Query parameters for trade endpoints with offset/limit pagination
 #[derive(Debug, Clone, Default)]
 pub struct TradeQueryParams {
@@ -56,24 +86,17 @@ impl TradeQueryParams {
         self
     }
 
-    pub fn to_query_string(&self) -> String {
-        let mut params = Vec::new();
-
-        if let Some(limit) = self.limit {
-            params.push(format!("limit={}", limit));
-        }
-        if let Some(offset) = self.offset {
-            params.push(format!("offset={}", offset));
-        }
-        if let Some(taker_only) = self.taker_only {
-            params.push(format!("takerOnly={}", taker_only));
-        }
+    pub fn to_query(&self) -> QueryBuilder {
+        QueryBuilder::new()
+            .push_opt("limit", self.limit)
+            .push_opt("offset", self.offset)
+            .push_opt("takerOnly", self.taker_only)
+    }
 
-        if params.is_empty() {
-            String::new()
-        } else {
-            format!("&{}", params.join("&"))
-        }
+    /// Build the query as `&`-prefixed pairs, to follow a `?user=...` segment
+    #[deprecated(note = "use `to_query`, which handles the `?`/`&` joining")]
+    pub fn to_query_string(&self) -> String {
+        continuation(self.to_query())
     }
 }
 
@@ -111,26 +134,186 @@ impl ActivityQueryParams {
         self
     }
 
+    pub fn to_query(&self) -> QueryBuilder {
+        QueryBuilder::new()
+            .push_opt("limit", self.limit)
+            .push_opt("offset", self.offset)
+            .push_opt("sortBy", self.sort_by.as_ref().map(ActivitySortBy::as_str))
+            .push_opt(
+                "sortDirection",
+                self.sort_direction.as_ref().map(SortDirection::as_str),
+            )
+    }
+
+    /// Build the query as `&`-prefixed pairs, to follow a `?user=...` segment
+    #[deprecated(note = "use `to_query`, which handles the `?`/`&` joining")]
     pub fn to_query_string(&self) -> String {
-        let mut params = Vec::new();
+        continuation(self.to_query())
+    }
+}
 
-        if let Some(limit) = self.limit {
-            params.push(format!("limit={}", limit));
-        }
-        if let Some(ref sort_by) = self.sort_by {
-            params.push(format!("sortBy={}", sort_by.as_str()));
-        }
-        if let Some(ref sort_direction) = self.sort_direction {
-            params.push(format!("sortDirection={}", sort_direction.as_str()));
-        }
-        if let Some(offset) = self.offset {
-            params.push(format!("offset={}", offset));
-        }
+/// Query parameters for position endpoints with offset/limit pagination, filters and sorting
+#[derive(Debug, Clone, Default)]
+pub struct PositionQueryParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub size_threshold: Option<f64>,
+    pub redeemable: Option<bool>,
+    pub sort_by: Option<PositionSortBy>,
+    pub sort_direction: Option<SortDirection>,
+}
 
-        if params.is_empty() {
-            String::new()
-        } else {
-            format!("&{}", params.join("&"))
-        }
+impl PositionQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Only include positions with at least this size
+    pub fn with_size_threshold(mut self, size_threshold: f64) -> Self {
+        self.size_threshold = Some(size_threshold);
+        self
+    }
+
+    /// Only include positions that are (or are not) redeemable
+    pub fn with_redeemable(mut self, redeemable: bool) -> Self {
+        self.redeemable = Some(redeemable);
+        self
+    }
+
+    pub fn with_sort_by(mut self, sort_by: PositionSortBy) -> Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn with_sort_direction(mut self, sort_direction: SortDirection) -> Self {
+        self.sort_direction = Some(sort_direction);
+        self
+    }
+
+    pub fn to_query(&self) -> QueryBuilder {
+        QueryBuilder::new()
+            .push_opt("redeemable", self.redeemable)
+            .push_opt("sizeThreshold", self.size_threshold)
+            .push_opt("limit", self.limit)
+            .push_opt("offset", self.offset)
+            .push_opt("sortBy", self.sort_by.as_ref().map(PositionSortBy::as_str))
+            .push_opt(
+                "sortDirection",
+                self.sort_direction.as_ref().map(SortDirection::as_str),
+            )
+    }
+
+    /// Build the query as `&`-prefixed pairs, to follow a `?user=...` segment
+    #[deprecated(note = "use `to_query`, which handles the `?`/`&` joining")]
+    pub fn to_query_string(&self) -> String {
+        continuation(self.to_query())
+    }
+}
+
+/// Render a query as `&`-prefixed pairs, the format `to_query_string` always had
+fn continuation(query: QueryBuilder) -> String {
+    let query = query.build();
+    match query.strip_prefix('?') {
+        Some(pairs) => format!("&{}", pairs),
+        None => query,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_query_string_empty() {
+        assert_eq!(PositionQueryParams::new().to_query().build(), "");
+    }
+
+    #[test]
+    fn test_position_query_string() {
+        let params = PositionQueryParams::new()
+            .with_redeemable(true)
+            .with_size_threshold(0.1)
+            .with_limit(100)
+            .with_offset(200)
+            .with_sort_by(PositionSortBy::Current)
+            .with_sort_direction(SortDirection::Desc);
+
+        assert_eq!(
+            params.to_query().build(),
+            "?redeemable=true&sizeThreshold=0.1&limit=100&offset=200&sortBy=CURRENT&sortDirection=DESC"
+        );
+    }
+
+    #[test]
+    fn test_trade_and_activity_query_strings() {
+        assert_eq!(TradeQueryParams::new().to_query().build(), "");
+        assert_eq!(
+            TradeQueryParams::new()
+                .with_taker_only(true)
+                .with_offset(20)
+                .with_limit(10)
+                .to_query()
+                .build(),
+            "?limit=10&offset=20&takerOnly=true"
+        );
+
+        // Offset follows limit like the other params
+        assert_eq!(
+            ActivityQueryParams::new()
+                .with_sort_direction(SortDirection::Asc)
+                .with_sort_by(ActivitySortBy::Timestamp)
+                .with_offset(20)
+                .with_limit(10)
+                .to_query()
+                .build(),
+            "?limit=10&offset=20&sortBy=TIMESTAMP&sortDirection=ASC"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_query_string_keeps_ampersand_prefix() {
+        assert_eq!(TradeQueryParams::new().to_query_string(), "");
+        assert_eq!(
+            TradeQueryParams::new().with_limit(10).to_query_string(),
+            "&limit=10"
+        );
+        assert_eq!(
+            ActivityQueryParams::new()
+                .with_limit(10)
+                .with_sort_by(ActivitySortBy::Timestamp)
+                .to_query_string(),
+            "&limit=10&sortBy=TIMESTAMP"
+        );
+        assert_eq!(
+            PositionQueryParams::new()
+                .with_redeemable(true)
+                .to_query_string(),
+            "&redeemable=true"
+        );
+    }
+
+    #[test]
+    fn test_params_compose_after_leading_pair() {
+        let query = QueryBuilder::new()
+            .push("user", "0xabc")
+            .append(ActivityQueryParams::new().with_limit(5).to_query());
+        assert_eq!(query.build(), "?user=0xabc&limit=5");
+
+        let query = TradeQueryParams::new()
+            .with_limit(5)
+            .to_query()
+            .push("user", "0xabc");
+        assert_eq!(query.build(), "?limit=5&user=0xabc");
     }
 }
@@ -1,3 +1,5 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
 /// Query parameters for Gamma API market endpoints
 #[derive(Debug, Clone, Default)]
 pub struct GammaMarketParams {
@@ -6,9 +8,16 @@ pub struct GammaMarketParams {
     pub active: Option<bool>,
     pub closed: Option<bool>,
     pub archived: Option<bool>,
-    pub tag_id: Option<String>,
+    pub tag_ids: Vec<String>,
+    pub slug: Option<String>,
     pub order: Option<String>,
     pub ascending: Option<bool>,
+    pub start_date_min: Option<DateTime<Utc>>,
+    pub start_date_max: Option<DateTime<Utc>>,
+    pub end_date_min: Option<DateTime<Utc>>,
+    pub end_date_max: Option<DateTime<Utc>>,
+    pub liquidity_num_min: Option<f64>,
+    pub volume_num_min: Option<f64>,
 }
 
 impl GammaMarketParams {
@@ -47,13 +56,192 @@ impl GammaMarketParams {
         self
     }
 
+    /// Filter by a single tag ID, replacing any tags added before
+    pub fn with_tag_id(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_ids.clear();
+        self.add_tag_id(tag_id)
+    }
+
+    /// Add a tag ID; markets matching any of the tags are returned
+    pub fn add_tag_id(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_ids.push(tag_id.into());
+        self
+    }
+
+    /// Filter by market slug
+    pub fn with_slug(mut self, slug: impl Into<String>) -> Self {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    /// Set the ordering field
+    pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
+        self.order = Some(order.into());
+        self.ascending = Some(ascending);
+        self
+    }
+
+    /// Only markets starting at or after this time
+    pub fn with_start_date_min(mut self, start_date_min: DateTime<Utc>) -> Self {
+        self.start_date_min = Some(start_date_min);
+        self
+    }
+
+    /// Only markets starting at or before this time
+    pub fn with_start_date_max(mut self, start_date_max: DateTime<Utc>) -> Self {
+        self.start_date_max = Some(start_date_max);
+        self
+    }
+
+    /// Only markets ending at or after this time
+    pub fn with_end_date_min(mut self, end_date_min: DateTime<Utc>) -> Self {
+        self.end_date_min = Some(end_date_min);
+        self
+    }
+
+    /// Only markets ending at or before this time
+    pub fn with_end_date_max(mut self, end_date_max: DateTime<Utc>) -> Self {
+        self.end_date_max = Some(end_date_max);
+        self
+    }
+
+    /// Only markets with at least this much liquidity
+    pub fn with_liquidity_num_min(mut self, liquidity_num_min: f64) -> Self {
+        self.liquidity_num_min = Some(liquidity_num_min);
+        self
+    }
+
+    /// Only markets with at least this much volume
+    pub fn with_volume_num_min(mut self, volume_num_min: f64) -> Self {
+        self.volume_num_min = Some(volume_num_min);
+        self
+    }
+
+    /// Convert parameters to query string
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+        if let Some(active) = self.active {
+            params.push(format!("active={}", active));
+        }
+        if let Some(closed) = self.closed {
+            params.push(format!("closed={}", closed));
+        }
+        if let Some(archived) = self.archived {
+            params.push(format!("archived={}", archived));
+        }
+        // One pair per tag, in the order they were added
+        for tag_id in &self.tag_ids {
+            params.push(format!("tag_id={}", tag_id));
+        }
+        if let Some(ref slug) = self.slug {
+            params.push(format!("slug={}", slug));
+        }
+        if let Some(ref order) = self.order {
+            params.push(format!("order={}", order));
+        }
+        if let Some(ascending) = self.ascending {
+            params.push(format!("ascending={}", ascending));
+        }
+        for (key, date) in [
+            ("start_date_min", self.start_date_min),
+            ("start_date_max", self.start_date_max),
+            ("end_date_min", self.end_date_min),
+            ("end_date_max", self.end_date_max),
+        ] {
+            if let Some(date) = date {
+                params.push(format!("{}={}", key, format_date(&date)));
+            }
+        }
+        if let Some(liquidity_num_min) = self.liquidity_num_min {
+            params.push(format!("liquidity_num_min={}", liquidity_num_min));
+        }
+        if let Some(volume_num_min) = self.volume_num_min {
+            params.push(format!("volume_num_min={}", volume_num_min));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
+
+/// Format a date filter as the API expects, e.g. `2025-01-01T00:00:00Z`
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Query parameters for Gamma API event endpoints
+#[derive(Debug, Clone, Default)]
+pub struct GammaEventParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    pub tag_id: Option<String>,
+    pub slug: Option<String>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+}
+
+impl GammaEventParams {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of results to return
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination offset
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Filter for active events
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Filter for closed events
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Filter for archived events
+    pub fn with_archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
     /// Filter by tag ID
     pub fn with_tag_id(mut self, tag_id: impl Into<String>) -> Self {
         self.tag_id = Some(tag_id.into());
         self
     }
 
-    /// Set the ordering field
+    /// Filter by event slug
+    pub fn with_slug(mut self, slug: impl Into<String>) -> Self {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    /// Set the ordering field, e.g. `"volume"` or `"startDate"`
     pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
         self.order = Some(order.into());
         self.ascending = Some(ascending);
@@ -82,6 +270,88 @@ impl GammaMarketParams {
         if let Some(ref tag_id) = self.tag_id {
             params.push(format!("tag_id={}", tag_id));
         }
+        if let Some(ref slug) = self.slug {
+            params.push(format!("slug={}", slug));
+        }
+        if let Some(ref order) = self.order {
+            params.push(format!("order={}", order));
+        }
+        if let Some(ascending) = self.ascending {
+            params.push(format!("ascending={}", ascending));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
+
+/// Query parameters for Gamma API series endpoints
+#[derive(Debug, Clone, Default)]
+pub struct GammaSeriesParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub closed: Option<bool>,
+    pub slug: Option<String>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+}
+
+impl GammaSeriesParams {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of results to return
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination offset
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Filter for closed series
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Filter by series slug
+    pub fn with_slug(mut self, slug: impl Into<String>) -> Self {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    /// Set the ordering field, e.g. `"volume"` or `"startDate"`
+    pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
+        self.order = Some(order.into());
+        self.ascending = Some(ascending);
+        self
+    }
+
+    /// Convert parameters to query string
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+        if let Some(closed) = self.closed {
+            params.push(format!("closed={}", closed));
+        }
+        if let Some(ref slug) = self.slug {
+            params.push(format!("slug={}", slug));
+        }
         if let Some(ref order) = self.order {
             params.push(format!("order={}", order));
         }
@@ -100,6 +370,7 @@ impl GammaMarketParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_empty_params() {
@@ -151,4 +422,114 @@ mod tests {
         assert!(query.contains("closed=false"));
         assert!(query.contains("tag_id=politics"));
     }
+
+    #[test]
+    fn test_multiple_tag_ids() {
+        let params = GammaMarketParams::new()
+            .add_tag_id("politics")
+            .add_tag_id("crypto");
+        assert_eq!(params.to_query_string(), "?tag_id=politics&tag_id=crypto");
+
+        // with_tag_id replaces earlier tags
+        let params = params.with_tag_id("sports").add_tag_id("nba");
+        assert_eq!(params.tag_ids, vec!["sports", "nba"]);
+        assert_eq!(params.to_query_string(), "?tag_id=sports&tag_id=nba");
+    }
+
+    #[test]
+    fn test_slug_filter() {
+        let params = GammaMarketParams::new().with_slug("will-it-rain");
+
+        let query = params.to_query_string();
+        assert_eq!(query, "?slug=will-it-rain");
+    }
+
+    #[test]
+    fn test_date_range_and_numeric_filters() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 8, 12, 30, 0).unwrap();
+
+        let params = GammaMarketParams::new()
+            .with_closed(false)
+            .with_start_date_min(start)
+            .with_end_date_min(start)
+            .with_end_date_max(end)
+            .with_liquidity_num_min(1000.0)
+            .with_volume_num_min(2500.5);
+
+        assert_eq!(
+            params.to_query_string(),
+            "?closed=false&start_date_min=2025-01-01T00:00:00Z\
+             &end_date_min=2025-01-01T00:00:00Z&end_date_max=2025-01-08T12:30:00Z\
+             &liquidity_num_min=1000&volume_num_min=2500.5"
+        );
+
+        let params = GammaMarketParams::new().with_start_date_max(end);
+        assert_eq!(
+            params.to_query_string(),
+            "?start_date_max=2025-01-08T12:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_date_format_round_trips() {
+        #[derive(serde::Deserialize)]
+        struct Parsed {
+            #[serde(
+                deserialize_with = "crate::types::serde_helpers::deserialize_optional_datetime"
+            )]
+            date: Option<DateTime<Utc>>,
+        }
+
+        let date = Utc.with_ymd_and_hms(2025, 12, 9, 0, 30, 15).unwrap();
+        let parsed: Parsed =
+            serde_json::from_value(serde_json::json!({ "date": format_date(&date) })).unwrap();
+        assert_eq!(parsed.date, Some(date));
+    }
+
+    #[test]
+    fn test_event_params() {
+        assert_eq!(GammaEventParams::new().to_query_string(), "");
+
+        let params = GammaEventParams::new()
+            .with_limit(10)
+            .with_offset(20)
+            .with_active(true)
+            .with_closed(false)
+            .with_tag_id("politics")
+            .with_order("volume", false);
+
+        assert_eq!(
+            params.to_query_string(),
+            "?limit=10&offset=20&active=true&closed=false&tag_id=politics&order=volume&ascending=false"
+        );
+    }
+
+    #[test]
+    fn test_event_params_start_date_ordering() {
+        let params = GammaEventParams::new()
+            .with_archived(false)
+            .with_order("startDate", true);
+
+        assert_eq!(
+            params.to_query_string(),
+            "?archived=false&order=startDate&ascending=true"
+        );
+    }
+
+    #[test]
+    fn test_series_params() {
+        assert_eq!(GammaSeriesParams::new().to_query_string(), "");
+
+        let params = GammaSeriesParams::new()
+            .with_limit(5)
+            .with_closed(true)
+            .with_slug("nba")
+            .with_order("startDate", false);
+
+        assert_eq!(
+            params.to_query_string(),
+            "?limit=5&closed=true&slug=nba&order=startDate&ascending=false"
+        );
+    }
 }
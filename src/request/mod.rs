@@ -1,7 +1,14 @@
+mod clob_params;
 mod data_params;
 mod gamma_params;
 mod pagination;
+mod query;
 
-pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
-pub use gamma_params::GammaMarketParams;
+pub use clob_params::{PriceHistoryInterval, PriceHistoryParams};
+pub use data_params::{
+    ActivityQueryParams, ActivitySortBy, PositionQueryParams, PositionSortBy, SortDirection,
+    TradeQueryParams,
+};
+pub use gamma_params::{GammaEventParams, GammaMarketParams, GammaSeriesParams};
 pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+pub use query::QueryBuilder;
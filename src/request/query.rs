@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+/// Builder for URL query strings
+///
+/// Collects `key=value` pairs and owns the `?`/`&` joining, so query
+/// parameters can be combined in any order.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::request::{QueryBuilder, TradeQueryParams};
+///
+/// let params = TradeQueryParams::new().with_limit(10);
+/// let query = QueryBuilder::new()
+///     .push("user", "0xabc")
+///     .append(params.to_query())
+///     .build();
+/// assert_eq!(query, "?user=0xabc&limit=10");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryBuilder {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryBuilder {
+    /// Create an empty query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a `key=value` pair
+    pub fn push(mut self, key: &str, value: impl Display) -> Self {
+        self.pairs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add a `key=value` pair if the value is set
+    pub fn push_opt(self, key: &str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.push(key, value),
+            None => self,
+        }
+    }
+
+    /// Add every `(key, value)` pair, e.g. from a params `to_query_params`
+    pub fn push_all<'a, V: Display>(self, pairs: impl IntoIterator<Item = (&'a str, V)>) -> Self {
+        pairs
+            .into_iter()
+            .fold(self, |query, (key, value)| query.push(key, value))
+    }
+
+    /// Add all pairs of another query after the current ones
+    pub fn append(mut self, other: QueryBuilder) -> Self {
+        self.pairs.extend(other.pairs);
+        self
+    }
+
+    /// Returns true if no pairs have been added
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Build the query string, `?`-prefixed, or empty if there are no pairs
+    pub fn build(&self) -> String {
+        if self.pairs.is_empty() {
+            return String::new();
+        }
+
+        let pairs: Vec<String> = self
+            .pairs
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        format!("?{}", pairs.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query() {
+        assert!(QueryBuilder::new().is_empty());
+        assert_eq!(QueryBuilder::new().build(), "");
+        assert_eq!(
+            QueryBuilder::new().push_opt("limit", None::<u32>).build(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_composition_order() {
+        let params = QueryBuilder::new().push("limit", 10).push("offset", 20);
+
+        // Params after a leading pair
+        let query = QueryBuilder::new()
+            .push("user", "0xabc")
+            .append(params.clone());
+        assert_eq!(query.build(), "?user=0xabc&limit=10&offset=20");
+
+        // Params as the leading segment
+        let query = params.append(QueryBuilder::new().push("user", "0xabc"));
+        assert_eq!(query.build(), "?limit=10&offset=20&user=0xabc");
+    }
+
+    #[test]
+    fn test_push_all() {
+        let pairs = vec![("market", "0xabc".to_string()), ("id", "1".to_string())];
+        let query = QueryBuilder::new().push("user", "0xdef").push_all(pairs);
+        assert_eq!(query.build(), "?user=0xdef&market=0xabc&id=1");
+        assert_eq!(
+            QueryBuilder::new()
+                .push_all(Vec::<(&str, u32)>::new())
+                .build(),
+            ""
+        );
+    }
+}
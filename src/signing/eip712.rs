@@ -1,6 +1,7 @@
 use crate::error::Result;
-use alloy_primitives::{hex::encode_prefixed, Address, U256};
-use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use crate::signing::EthSigner;
+use alloy_primitives::{hex::encode_prefixed, keccak256, Address, B256, U256};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct};
 
 // EIP-712 struct for CLOB authentication
 sol! {
@@ -43,10 +44,42 @@ pub fn sign_clob_auth_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
+    let signature = signer
+        .sign_hash_sync(&hash)
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Signs a CLOB authentication message using EIP-712, without blocking
+///
+/// Same signature as [`sign_clob_auth_message`], produced through the async
+/// [`Signer::sign_hash`](alloy_signer::Signer::sign_hash) so signers backed by
+/// a remote service work on any runtime.
+pub async fn sign_clob_auth_message_async<T>(
+    signer: &T,
+    timestamp: String,
+    nonce: U256,
+    chain_id: u64,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + Sync + ?Sized,
+{
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
+    let signature = signer
+        .sign_hash(&hash)
+        .await
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+fn clob_auth_signing_hash(address: Address, timestamp: String, nonce: U256, chain_id: u64) -> B256 {
     let message = "This message attests that I control the given wallet".to_owned();
 
     let auth_struct = ClobAuth {
-        address: signer.address(),
+        address,
         timestamp,
         nonce,
         message,
@@ -58,10 +91,59 @@ where
         chain_id: chain_id,
     );
 
-    let hash = auth_struct.eip712_signing_hash(&domain);
+    auth_struct.eip712_signing_hash(&domain)
+}
+
+/// Computes the EIP-712 digest of an order
+///
+/// This is the hash signed by [`sign_order_message`] and the order hash
+/// used by the exchange.
+pub fn order_signing_hash(order: &Order, chain_id: u64, verifying_contract: Address) -> B256 {
+    order.eip712_signing_hash(&order_domain(chain_id, verifying_contract))
+}
+
+/// Computes the EIP-712 domain separator used for orders on an exchange
+///
+/// The standard and neg risk exchanges are separate contracts, so an order
+/// signed for one is rejected by the other.
+pub fn order_domain_separator(chain_id: u64, verifying_contract: Address) -> B256 {
+    order_domain(chain_id, verifying_contract).separator()
+}
+
+fn order_domain(chain_id: u64, verifying_contract: Address) -> Eip712Domain {
+    eip712_domain!(
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: verifying_contract,
+    )
+}
+
+/// Computes the EIP-712 digest `keccak256(0x19 0x01 || domain_separator || struct_hash)`
+pub fn typed_data_digest(domain_separator: B256, struct_hash: B256) -> B256 {
+    let mut encoded = [0u8; 66];
+    encoded[..2].copy_from_slice(&[0x19, 0x01]);
+    encoded[2..34].copy_from_slice(domain_separator.as_slice());
+    encoded[34..].copy_from_slice(struct_hash.as_slice());
+    keccak256(encoded)
+}
+
+/// Signs EIP-712 typed data given its domain separator and struct hash
+///
+/// This is the standard `eth_signTypedData` convention: the
+/// [`typed_data_digest`] is signed directly and the signature is returned as
+/// 65 bytes with v = 27/28, as expected by ecrecover-based verifiers such as the
+/// CLOB exchange. Safe transactions use a different convention (an EIP-191
+/// prefixed hash with v shifted by 4), which the relayer client applies itself.
+pub fn sign_typed_data_hash(
+    signer: &dyn EthSigner,
+    domain_separator: B256,
+    struct_hash: B256,
+) -> Result<String> {
+    let hash = typed_data_digest(domain_separator, struct_hash);
     let signature = signer
         .sign_hash_sync(&hash)
-        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign typed data: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
 }
@@ -79,17 +161,66 @@ pub fn sign_order_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
-    let domain = eip712_domain!(
-        name: "Polymarket CTF Exchange",
-        version: "1",
-        chain_id: chain_id,
-        verifying_contract: verifying_contract,
-    );
-
-    let hash = order.eip712_signing_hash(&domain);
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
     let signature = signer
         .sign_hash_sync(&hash)
         .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
 }
+
+/// Signs an order using EIP-712, without blocking
+///
+/// Same signature as [`sign_order_message`], produced through the async
+/// [`Signer::sign_hash`](alloy_signer::Signer::sign_hash).
+pub async fn sign_order_message_async<T>(
+    signer: &T,
+    order: Order,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + Sync + ?Sized,
+{
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
+    let signature = signer
+        .sign_hash(&hash)
+        .await
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[test]
+    fn test_sign_typed_data_hash_eip712_reference_vector() {
+        // The "Ether Mail" example from the EIP-712 specification
+        // (assets/eip-712/Example.js), signed by keccak256("cow")
+        let signer = PrivateKeySigner::from_bytes(&keccak256("cow")).unwrap();
+        assert_eq!(
+            signer.address(),
+            "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+                .parse::<Address>()
+                .unwrap()
+        );
+        let domain_separator =
+            b256!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+        let struct_hash = b256!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e");
+
+        assert_eq!(
+            typed_data_digest(domain_separator, struct_hash),
+            b256!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+        assert_eq!(
+            sign_typed_data_hash(&signer, domain_separator, struct_hash).unwrap(),
+            "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+             07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562\
+             1c"
+        );
+    }
+}
@@ -1,5 +1,13 @@
 mod eip712;
+#[cfg(not(target_arch = "wasm32"))]
+mod remote;
 mod signer;
 
-pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
+pub use eip712::{
+    order_domain_separator, order_signing_hash, sign_clob_auth_message,
+    sign_clob_auth_message_async, sign_order_message, sign_order_message_async,
+    sign_typed_data_hash, typed_data_digest, ClobAuth, Order,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use remote::RemoteSigner;
 pub use signer::EthSigner;
@@ -0,0 +1,185 @@
+use alloy_primitives::{Address, ChainId, Signature, B256};
+use alloy_signer::{Signer, SignerSync};
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+type SignFn = dyn Fn(B256) -> BoxFuture<'static, alloy_signer::Result<Signature>> + Send + Sync;
+
+/// Signer that delegates signing to a remote service (KMS, HSM, HTTP signer, ...)
+///
+/// The signing function receives the 32-byte hash to sign and returns the
+/// recoverable secp256k1 signature produced by the remote key. Async signing
+/// awaits it directly; sync signing blocks on it (see [`EthSigner`](super::EthSigner)).
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::signing::RemoteSigner;
+/// use polymarket_rs::Address;
+///
+/// let address: Address = "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5".parse().unwrap();
+/// let signer = RemoteSigner::new(address, |hash| async move {
+///     // Ask the remote service to sign `hash`
+///     Err(alloy_signer::Error::message(format!("cannot sign {}", hash)))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct RemoteSigner {
+    address: Address,
+    chain_id: Option<ChainId>,
+    sign_fn: Arc<SignFn>,
+}
+
+impl RemoteSigner {
+    /// Create a new RemoteSigner
+    ///
+    /// # Arguments
+    /// * `address` - The address of the remote key
+    /// * `sign_fn` - Async function signing a 32-byte hash with the remote key
+    pub fn new<F, Fut>(address: Address, sign_fn: F) -> Self
+    where
+        F: Fn(B256) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = alloy_signer::Result<Signature>> + Send + 'static,
+    {
+        Self {
+            address,
+            chain_id: None,
+            sign_fn: Arc::new(move |hash| Box::pin(sign_fn(hash))),
+        }
+    }
+}
+
+impl std::fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("address", &self.address)
+            .field("chain_id", &self.chain_id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, hash: &B256) -> alloy_signer::Result<Signature> {
+        (self.sign_fn)(*hash).await
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+impl SignerSync for RemoteSigner {
+    fn sign_hash_sync(&self, hash: &B256) -> alloy_signer::Result<Signature> {
+        let future = (self.sign_fn)(*hash);
+
+        match Handle::try_current() {
+            // Move the blocking call off the worker so other tasks keep running
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| handle.block_on(future))
+            }
+            Ok(_) => Err(alloy_signer::Error::message(
+                "RemoteSigner cannot sign synchronously on a current-thread runtime; \
+                 use a multi-threaded runtime",
+            )),
+            Err(_) => tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(alloy_signer::Error::other)?
+                .block_on(future),
+        }
+    }
+
+    fn chain_id_sync(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::{sign_clob_auth_message, sign_clob_auth_message_async};
+    use alloy_signer_local::PrivateKeySigner;
+
+    // Stands in for a KMS: signs with a local key behind the async callback
+    fn mock_remote_signer() -> (PrivateKeySigner, RemoteSigner) {
+        let key = PrivateKeySigner::random();
+        let remote_key = key.clone();
+        let signer = RemoteSigner::new(key.address(), move |hash| {
+            let key = remote_key.clone();
+            async move {
+                tokio::task::yield_now().await;
+                key.sign_hash(&hash).await
+            }
+        });
+        (key, signer)
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_async() {
+        let (key, signer) = mock_remote_signer();
+
+        let signature = signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(signature, key.sign_message(b"hello").await.unwrap());
+        assert_eq!(
+            signature.recover_address_from_msg(b"hello").unwrap(),
+            signer.address()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sign_message_sync_inside_runtime() {
+        let (key, signer) = mock_remote_signer();
+
+        let signature = signer.sign_message_sync(b"hello").unwrap();
+        assert_eq!(signature, key.sign_message_sync(b"hello").unwrap());
+    }
+
+    #[test]
+    fn test_sign_message_sync_outside_runtime() {
+        let (key, signer) = mock_remote_signer();
+
+        let signature = signer.sign_message_sync(b"hello").unwrap();
+        assert_eq!(signature, key.sign_message_sync(b"hello").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_sync_current_thread_runtime_fails() {
+        let (_, signer) = mock_remote_signer();
+        assert!(signer.sign_message_sync(b"hello").is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sign_clob_auth_message() {
+        let (key, signer) = mock_remote_signer();
+
+        let signature =
+            sign_clob_auth_message(&signer, "0".to_string(), Default::default(), 137).unwrap();
+        let expected =
+            sign_clob_auth_message(&key, "0".to_string(), Default::default(), 137).unwrap();
+        assert_eq!(signature, expected);
+    }
+
+    #[tokio::test]
+    async fn test_sign_clob_auth_message_async_current_thread_runtime() {
+        let (key, signer) = mock_remote_signer();
+
+        let signature =
+            sign_clob_auth_message_async(&signer, "0".to_string(), Default::default(), 137)
+                .await
+                .unwrap();
+        let expected =
+            sign_clob_auth_message(&key, "0".to_string(), Default::default(), 137).unwrap();
+        assert_eq!(signature, expected);
+    }
+}
@@ -4,6 +4,20 @@ use alloy_signer::{Signer, SignerSync};
 ///
 /// This trait combines the required traits for signing EIP-712 messages
 /// both synchronously and asynchronously.
+///
+/// The async methods come from [`Signer`] (`sign_hash`, `sign_message`, ...), and
+/// signers backed by remote services can implement them without blocking.
+///
+/// The async client methods (posting orders, L1 authentication, relayer
+/// transactions) await these, so a remote signer works on any runtime, including
+/// a current-thread one.
+///
+/// The sync methods from [`SignerSync`] (`sign_hash_sync`, `sign_message_sync`)
+/// back the sync helpers such as [`OrderBuilder::create_order`](crate::orders::OrderBuilder::create_order).
+/// Local signers return immediately. A signer that does I/O must not block a Tokio
+/// worker directly; [`RemoteSigner`](super::RemoteSigner) wraps the call in
+/// `tokio::task::block_in_place`, which needs a multi-threaded runtime. Prefer the
+/// `_async` variants with remote signers.
 pub trait EthSigner: Signer + SignerSync + Send + Sync {}
 
 // Blanket implementation for any type that meets the requirements
@@ -0,0 +1,273 @@
+//! Helpers shared by unit tests
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::error::{ApiError, Error, Result};
+use crate::http::HttpTransport;
+
+/// A request received by [`MockServer`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Get a header value by case-insensitive name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A canned response served by [`MockServer`]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    /// How long to wait before replying
+    pub delay: Option<Duration>,
+}
+
+impl MockResponse {
+    /// Add a response header
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Reply only after `delay`, without holding up other connections
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+impl From<(u16, String)> for MockResponse {
+    fn from((status, body): (u16, String)) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+            delay: None,
+        }
+    }
+}
+
+/// Minimal HTTP/1.1 server that replies with canned responses in order
+///
+/// Each connection is served a single response and then closed. Once all
+/// responses are used up, further requests get a 500.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    /// Start a server that replies with the given responses in order
+    pub async fn start<R: Into<MockResponse>>(responses: Vec<R>) -> Self {
+        let responses: Vec<MockResponse> = responses.into_iter().map(Into::into).collect();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            let mut responses = responses.into_iter();
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let Some(request) = read_request(&mut socket).await else {
+                    continue;
+                };
+                recorded.lock().unwrap().push(request);
+
+                let response = responses
+                    .next()
+                    .unwrap_or_else(|| (500, "no more mock responses".to_string()).into());
+                let headers: String = response
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                    .collect();
+                let delay = response.delay;
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                    response.status,
+                    response.body.len(),
+                    headers,
+                    response.body
+                );
+                match delay {
+                    Some(delay) => {
+                        tokio::spawn(async move {
+                            tokio::time::sleep(delay).await;
+                            let _ = socket.write_all(response.as_bytes()).await;
+                            let _ = socket.shutdown().await;
+                        });
+                    }
+                    None => {
+                        let _ = socket.write_all(response.as_bytes()).await;
+                        let _ = socket.shutdown().await;
+                    }
+                }
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Base URL of the server
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// Requests received so far
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<RecordedRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    // Read until the end of the headers
+    let header_end = loop {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buf.len() < header_end + content_length {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
+
+/// [`HttpTransport`] answering with canned JSON bodies in order, without a network
+///
+/// Requests are recorded like [`MockServer`] does. Once all responses are used
+/// up, further requests fail with a 500 API error.
+#[derive(Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<String>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    /// Create a transport replying with the given bodies in order
+    pub fn new<S: Into<String>>(responses: Vec<S>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into_iter().map(Into::into).collect()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Requests made so far
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<&str, String>>,
+        body: String,
+    ) -> Result<T> {
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            headers: headers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+            body,
+        });
+
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| Error::Api(ApiError::new(500, "no more mock responses")))?;
+        serde_json::from_str(&response).map_err(|e| Error::deserialization(e, &response))
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.respond("GET", path, headers, String::new())
+    }
+
+    async fn post<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + Sync,
+    {
+        let body = serde_json::to_string(body)?;
+        self.respond("POST", path, headers, body)
+    }
+
+    async fn delete<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.respond("DELETE", path, headers, String::new())
+    }
+}
@@ -1,4 +1,9 @@
+use crate::error::Result;
+use crate::http::build_l2_headers;
+use crate::utils::get_current_unix_time_secs;
+use alloy_primitives::Address;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// API credentials for L2 authentication
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -17,10 +22,52 @@ impl ApiCreds {
             passphrase,
         }
     }
+
+    /// Build the L2 (HMAC) authentication headers for a CLOB request
+    ///
+    /// Use this to sign requests to endpoints the clients don't wrap yet.
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method (e.g., "GET", "POST")
+    /// * `path` - Request path without host or query string (e.g., "/orders")
+    /// * `body` - Request body, serialized as compact JSON exactly as it will be sent
+    /// * `address` - Address of the wallet that owns the API key
+    pub fn l2_headers<T>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&T>,
+        address: Address,
+    ) -> Result<HashMap<&'static str, String>>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.l2_headers_at(get_current_unix_time_secs()?, method, path, body, address)
+    }
+
+    /// Build the L2 authentication headers with a fixed timestamp (Unix seconds)
+    ///
+    /// `POLY_SIGNATURE` is HMAC-SHA256 over the message
+    /// `timestamp + method + path + body`, with no separators and the body as
+    /// compact JSON (omitted when there is no body). The key is the URL-safe
+    /// base64-decoded secret and the digest is encoded as URL-safe base64 with padding.
+    pub fn l2_headers_at<T>(
+        &self,
+        timestamp: u64,
+        method: &str,
+        path: &str,
+        body: Option<&T>,
+        address: Address,
+    ) -> Result<HashMap<&'static str, String>>
+    where
+        T: ?Sized + Serialize,
+    {
+        build_l2_headers(self, timestamp, method, path, body, address)
+    }
 }
 
 /// Response from API keys list endpoint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApiKeysResponse {
     #[serde(rename = "apiKeys")]
     pub api_keys: Vec<String>,
@@ -76,3 +123,84 @@ impl BalanceAllowanceParams {
         params
     }
 }
+
+/// Balance and allowance of the wallet, as returned by `/balance-allowance`
+///
+/// Amounts are strings in the token's smallest units; allowances set to the
+/// maximum uint256 do not fit in a `Decimal`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BalanceAllowance {
+    pub balance: String,
+    /// Single allowance, as returned by older API versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowance: Option<String>,
+    /// Allowance per spender contract address
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub allowances: HashMap<String, String>,
+}
+
+impl BalanceAllowance {
+    /// Allowance granted to `spender`, falling back to the single `allowance`
+    ///
+    /// The spender address is matched case-insensitively.
+    pub fn allowance_for(&self, spender: &str) -> Option<&str> {
+        self.allowances
+            .iter()
+            .find(|(address, _)| address.eq_ignore_ascii_case(spender))
+            .map(|(_, allowance)| allowance.as_str())
+            .or(self.allowance.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AssetType;
+
+    #[test]
+    fn test_balance_allowance_query_params() {
+        let params = BalanceAllowanceParams::new()
+            .asset_type(AssetType::Collateral)
+            .signature_type(2);
+        assert_eq!(
+            params.to_query_params(),
+            vec![
+                ("asset_type", "COLLATERAL".to_string()),
+                ("signature_type", "2".to_string())
+            ]
+        );
+
+        let params = BalanceAllowanceParams::new()
+            .asset_type(AssetType::Conditional)
+            .token_id("123");
+        assert_eq!(
+            params.to_query_params(),
+            vec![
+                ("asset_type", "CONDITIONAL".to_string()),
+                ("token_id", "123".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_balance_allowance_parsing() {
+        let parsed: BalanceAllowance = serde_json::from_str(
+            r#"{"balance":"1500000","allowances":{"0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E":"0","0xC5d563A36AE78145C45a50134d48A1215220f80a":"115792089237316195423570985008687907853269984665640564039457584007913129639935"}}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.balance, "1500000");
+        assert_eq!(
+            parsed.allowance_for("0x4bfb41d5b3570defd03c39a9a4d8de6bd8b8982e"),
+            Some("0")
+        );
+        assert!(parsed
+            .allowance_for("0xc5d563a36ae78145c45a50134d48a1215220f80a")
+            .unwrap()
+            .starts_with("1157920892"));
+        assert_eq!(parsed.allowance_for("0x0000000000000000000000000000000000000001"), None);
+
+        let legacy: BalanceAllowance =
+            serde_json::from_str(r#"{"balance":"10","allowance":"20"}"#).unwrap();
+        assert_eq!(legacy.allowance_for("0xanything"), Some("20"));
+    }
+}
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Asset type for balance and allowance operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -103,13 +103,66 @@ pub enum MarketStatus {
 }
 
 /// Order status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+///
+/// Parsed case-insensitively, since the CLOB reports `"live"` when posting an
+/// order and `"LIVE"` when listing open orders. Statuses this crate doesn't
+/// know about are kept as [`OrderStatus::Unknown`] instead of failing.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OrderStatus {
+    /// Resting on the book
     Live,
+    /// Matched against existing orders
     Matched,
+    /// Marketable order held back by the matching delay
+    Delayed,
+    /// Marketable order that could not be matched
+    Unmatched,
     Canceled,
     Expired,
+    /// Status not recognized by this crate, with the raw value
+    Unknown(String),
+}
+
+impl OrderStatus {
+    /// Convert status to its uppercase string form, or the raw value if unknown
+    pub fn as_str(&self) -> &str {
+        match self {
+            OrderStatus::Live => "LIVE",
+            OrderStatus::Matched => "MATCHED",
+            OrderStatus::Delayed => "DELAYED",
+            OrderStatus::Unmatched => "UNMATCHED",
+            OrderStatus::Canceled => "CANCELED",
+            OrderStatus::Expired => "EXPIRED",
+            OrderStatus::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl From<&str> for OrderStatus {
+    fn from(value: &str) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "LIVE" => OrderStatus::Live,
+            "MATCHED" => OrderStatus::Matched,
+            "DELAYED" => OrderStatus::Delayed,
+            "UNMATCHED" => OrderStatus::Unmatched,
+            "CANCELED" | "CANCELLED" => OrderStatus::Canceled,
+            "EXPIRED" => OrderStatus::Expired,
+            _ => OrderStatus::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for OrderStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderStatus::from(value.as_str()))
+    }
 }
 
 /// Notification type
@@ -136,3 +189,35 @@ pub enum ActivityType {
     Conversion,
     Redeem,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_status_deserialization() {
+        let statuses: Vec<OrderStatus> =
+            serde_json::from_str(r#"["live","MATCHED","delayed","Unmatched","CANCELED"]"#).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                OrderStatus::Live,
+                OrderStatus::Matched,
+                OrderStatus::Delayed,
+                OrderStatus::Unmatched,
+                OrderStatus::Canceled,
+            ]
+        );
+
+        let status: OrderStatus = serde_json::from_str(r#""pending_review""#).unwrap();
+        assert_eq!(status, OrderStatus::Unknown("pending_review".to_string()));
+        assert_eq!(
+            serde_json::to_string(&status).unwrap(),
+            r#""pending_review""#
+        );
+        assert_eq!(
+            serde_json::to_string(&OrderStatus::Live).unwrap(),
+            r#""LIVE""#
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::order::CreateOrderOptions;
+use super::outcome::IndexSet;
+use super::serde_helpers::decimal_from_f64;
+use crate::error::{Error, Result};
 
 /// Gamma API market with rich metadata
 /// Note: Most fields are optional since the API has inconsistent data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaMarket {
     pub id: String,
@@ -23,6 +31,11 @@ pub struct GammaMarket {
     pub archived: bool,
     #[serde(default)]
     pub restricted: bool,
+    /// UMA oracle status, e.g. "proposed", "disputed" or "resolved"
+    pub uma_resolution_status: Option<String>,
+    /// Market-level neg risk flag; the flag of the market's event takes
+    /// precedence (see [`GammaEvent::tradable_markets`])
+    pub neg_risk: Option<bool>,
 
     // Metadata
     pub slug: String,
@@ -41,13 +54,205 @@ pub struct GammaMarket {
     pub best_bid: Option<f64>,
     pub best_ask: Option<f64>,
     pub spread: Option<f64>,
+    /// Minimum price increment of the market's order book
+    pub order_price_min_tick_size: Option<f64>,
     // Nested data
     #[serde(default)]
     pub events: Vec<GammaSimplifiedEvent>,
 }
 
+impl GammaMarket {
+    /// Last trade price as a Decimal
+    ///
+    /// The Decimal accessors convert through the shortest decimal form of the
+    /// f64, so the value matches the number sent by the API (e.g. `0.345`
+    /// stays `0.345`). Prices are quoted with far fewer than the 15-17
+    /// significant digits an f64 round-trips exactly.
+    pub fn last_trade_price_decimal(&self) -> Option<Decimal> {
+        self.last_trade_price.and_then(decimal_from_f64)
+    }
+
+    /// Best bid as a Decimal (see [`Self::last_trade_price_decimal`])
+    pub fn best_bid_decimal(&self) -> Option<Decimal> {
+        self.best_bid.and_then(decimal_from_f64)
+    }
+
+    /// Best ask as a Decimal (see [`Self::last_trade_price_decimal`])
+    pub fn best_ask_decimal(&self) -> Option<Decimal> {
+        self.best_ask.and_then(decimal_from_f64)
+    }
+
+    /// Spread as a Decimal (see [`Self::last_trade_price_decimal`])
+    pub fn spread_decimal(&self) -> Option<Decimal> {
+        self.spread.and_then(decimal_from_f64)
+    }
+
+    /// Resolution state derived from the market's status and outcome prices
+    ///
+    /// Once a market resolves, Gamma settles `outcomePrices` to the payout of
+    /// each outcome (`["1", "0"]`, or `["0.5", "0.5"]` for a 50-50 split). A
+    /// market counts as resolved when it is closed, its prices are settled
+    /// like this, and its UMA status (when present) is `resolved`.
+    ///
+    /// # Errors
+    /// Returns an error if `outcomePrices` is not a JSON array of decimals.
+    pub fn resolution(&self) -> Result<Resolution> {
+        let payouts = match &self.outcome_prices {
+            Some(raw) => parse_decimals(raw)?,
+            None => Vec::new(),
+        };
+
+        let settled = !payouts.is_empty()
+            && payouts.iter().sum::<Decimal>() == Decimal::ONE
+            && payouts
+                .iter()
+                .all(|p| p.is_zero() || *p == dec!(0.5) || *p == Decimal::ONE);
+        let oracle_resolved = self
+            .uma_resolution_status
+            .as_deref()
+            .is_none_or(|status| status.eq_ignore_ascii_case("resolved"));
+
+        if !(self.closed && settled && oracle_resolved) {
+            return Ok(Resolution {
+                resolved: false,
+                winning_outcome_index: None,
+                payouts: Vec::new(),
+            });
+        }
+
+        let winning_outcome_index = payouts
+            .iter()
+            .position(|p| *p == Decimal::ONE)
+            .map(|index| index as u32);
+        Ok(Resolution {
+            resolved: true,
+            winning_outcome_index,
+            payouts,
+        })
+    }
+
+    /// Extract the fields needed to trade the market
+    ///
+    /// Parses the JSON-string `outcomes`, `clobTokenIds` and `outcomePrices`
+    /// fields. `neg_risk` is taken from the market's events, falling back to
+    /// the market-level flag when the market has no events.
+    ///
+    /// # Errors
+    /// Returns [`Error::MissingField`] if any of those fields, the tick size
+    /// or both neg risk sources are missing, and [`Error::InvalidParameter`]
+    /// if the outcome, token and price lists differ in length.
+    pub fn to_tradable(&self) -> Result<TradableMarket> {
+        let outcomes = parse_strings(required(&self.outcomes, "outcomes")?)?;
+        let token_ids = parse_strings(required(&self.clob_token_ids, "clobTokenIds")?)?;
+        let prices = parse_decimals(required(&self.outcome_prices, "outcomePrices")?)?;
+        if token_ids.len() != outcomes.len() || prices.len() != outcomes.len() {
+            return Err(Error::InvalidParameter(format!(
+                "Market {} has {} outcomes, {} token IDs and {} prices",
+                self.condition_id,
+                outcomes.len(),
+                token_ids.len(),
+                prices.len()
+            )));
+        }
+
+        let tick_size = self
+            .order_price_min_tick_size
+            .and_then(decimal_from_f64)
+            .ok_or_else(|| Error::MissingField("orderPriceMinTickSize".to_string()))?;
+        let neg_risk = if self.events.is_empty() {
+            self.neg_risk
+                .ok_or_else(|| Error::MissingField("negRisk".to_string()))?
+        } else {
+            self.events.iter().any(|event| event.neg_risk)
+        };
+
+        Ok(TradableMarket {
+            condition_id: self.condition_id.clone(),
+            outcomes,
+            token_ids,
+            prices,
+            tick_size,
+            neg_risk,
+        })
+    }
+}
+
+fn required<'a>(value: &'a Option<String>, field: &str) -> Result<&'a str> {
+    value
+        .as_deref()
+        .ok_or_else(|| Error::MissingField(field.to_string()))
+}
+
+/// Parse a JSON-encoded list of strings, as Gamma returns for list fields
+fn parse_strings(raw: &str) -> Result<Vec<String>> {
+    serde_json::from_str(raw).map_err(|e| Error::deserialization(e, raw))
+}
+
+fn parse_decimals(raw: &str) -> Result<Vec<Decimal>> {
+    parse_strings(raw)?
+        .iter()
+        .map(|value| Ok(Decimal::from_str(value)?))
+        .collect()
+}
+
+/// The fields of a [`GammaMarket`] needed to place orders
+///
+/// Built by [`GammaMarket::to_tradable`]. `outcomes`, `token_ids` and `prices`
+/// are in outcome order and have the same length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradableMarket {
+    pub condition_id: String,
+    pub outcomes: Vec<String>,
+    pub token_ids: Vec<String>,
+    /// Current price of each outcome
+    pub prices: Vec<Decimal>,
+    pub tick_size: Decimal,
+    pub neg_risk: bool,
+}
+
+impl TradableMarket {
+    /// Token ID of the outcome with the given name, compared case-insensitively
+    pub fn token_id(&self, outcome: &str) -> Option<&str> {
+        self.outcomes
+            .iter()
+            .position(|o| o.eq_ignore_ascii_case(outcome))
+            .map(|index| self.token_ids[index].as_str())
+    }
+
+    /// Order options with this market's tick size and neg risk flag
+    pub fn order_options(&self) -> CreateOrderOptions {
+        CreateOrderOptions::new()
+            .tick_size(self.tick_size)
+            .neg_risk(self.neg_risk)
+    }
+}
+
+/// Resolution state of a market
+///
+/// Returned by [`GammaClient::get_resolution`](crate::client::GammaClient::get_resolution).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// Whether the market has resolved
+    pub resolved: bool,
+    /// Index of the outcome that pays out in full, None for unresolved or split markets
+    pub winning_outcome_index: Option<u32>,
+    /// Payout per share of each outcome, in outcome order; empty until resolved
+    pub payouts: Vec<Decimal>,
+}
+
+impl Resolution {
+    /// Index set of the winning outcome, for redeeming just that position
+    ///
+    /// None if there is no winning outcome or its index does not fit in an
+    /// index set.
+    pub fn winning_index_set(&self) -> Option<u32> {
+        self.winning_outcome_index
+            .and_then(IndexSet::from_outcome_index)
+    }
+}
+
 /// Event associated with a market
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaSimplifiedEvent {
     pub id: String,
@@ -99,7 +304,7 @@ pub struct GammaSimplifiedEvent {
 }
 
 /// Event associated with a market
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaEvent {
     pub id: String,
@@ -187,8 +392,63 @@ pub struct GammaEvent {
     pub markets: Vec<GammaMarket>,
 }
 
+impl GammaEvent {
+    /// Trading views of the event's open markets, with the event's neg risk context
+    ///
+    /// Markets nested in an event usually come without their own `events`,
+    /// so each open market is given this event before conversion with
+    /// [`GammaMarket::to_tradable`]. Closed and inactive markets, such as
+    /// placeholder outcomes of neg risk events, are skipped.
+    ///
+    /// # Neg risk flags
+    ///
+    /// Neg risk is a property of the event: its markets are traded through
+    /// the neg risk exchange and adapter together. The event's `neg_risk` is
+    /// therefore the source of truth, and a differing market-level
+    /// [`GammaMarket::neg_risk`] is ignored. `enable_neg_risk` only records
+    /// that the event was set up for neg risk and does not change where
+    /// orders go.
+    ///
+    /// # Errors
+    /// Returns the first error of [`GammaMarket::to_tradable`].
+    pub fn tradable_markets(&self) -> Result<Vec<TradableMarket>> {
+        let context = self.to_simplified();
+        self.markets
+            .iter()
+            .filter(|market| market.active && !market.closed)
+            .map(|market| {
+                let mut market = market.clone();
+                market.events = vec![context.clone()];
+                market.to_tradable()
+            })
+            .collect()
+    }
+
+    fn to_simplified(&self) -> GammaSimplifiedEvent {
+        GammaSimplifiedEvent {
+            id: self.id.clone(),
+            ticker: self.ticker.clone(),
+            slug: self.slug.clone(),
+            title: self.title.clone(),
+            end_date: self.end_date,
+            start_time: self.start_time,
+            active: self.active,
+            closed: self.closed,
+            archived: self.archived,
+            new: self.new,
+            featured: self.featured,
+            restricted: self.restricted,
+            enable_order_book: self.enable_order_book,
+            neg_risk: self.neg_risk,
+            enable_neg_risk: self.enable_neg_risk,
+            neg_risk_augmented: self.neg_risk_augmented,
+            tags: self.tags.clone(),
+        }
+    }
+}
+
 /// Tag for market categorization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaTag {
     pub id: String,
@@ -201,7 +461,7 @@ pub struct GammaTag {
 }
 
 /// Category for market organization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaCategory {
     pub id: String,
@@ -210,7 +470,7 @@ pub struct GammaCategory {
 }
 
 /// Series grouping multiple related events/markets
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GammaSeries {
     pub id: String,
@@ -243,3 +503,227 @@ pub struct GammaSeries {
     #[serde(default)]
     pub events: Vec<GammaSimplifiedEvent>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_price_decimal_accessors_are_exact() {
+        let json = r#"{
+            "id": "1",
+            "question": "Q?",
+            "description": "",
+            "conditionId": "0x01",
+            "slug": "q",
+            "lastTradePrice": 0.345,
+            "bestBid": 0.34,
+            "bestAsk": 0.35,
+            "spread": 0.01
+        }"#;
+        let market: GammaMarket = serde_json::from_str(json).unwrap();
+
+        assert_eq!(market.last_trade_price_decimal(), Some(dec!(0.345)));
+        assert_eq!(market.best_bid_decimal(), Some(dec!(0.34)));
+        assert_eq!(market.best_ask_decimal(), Some(dec!(0.35)));
+        assert_eq!(market.spread_decimal(), Some(dec!(0.01)));
+    }
+
+    fn market_with(closed: bool, prices: &str, uma_status: Option<&str>) -> GammaMarket {
+        let json = serde_json::json!({
+            "id": "1",
+            "question": "Q?",
+            "description": "",
+            "conditionId": "0x01",
+            "slug": "q",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": prices,
+            "closed": closed,
+            "umaResolutionStatus": uma_status,
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_resolution_of_resolved_binary_market() {
+        let market = market_with(true, r#"["1", "0"]"#, Some("resolved"));
+
+        let resolution = market.resolution().unwrap();
+        assert!(resolution.resolved);
+        assert_eq!(resolution.winning_outcome_index, Some(0));
+        assert_eq!(resolution.payouts, vec![dec!(1), dec!(0)]);
+        assert_eq!(resolution.winning_index_set(), Some(1));
+
+        // A 50-50 resolution has no single winner
+        let split = market_with(true, r#"["0.5", "0.5"]"#, None)
+            .resolution()
+            .unwrap();
+        assert!(split.resolved);
+        assert_eq!(split.winning_outcome_index, None);
+    }
+
+    #[test]
+    fn test_resolution_of_unresolved_markets() {
+        for market in [
+            market_with(false, r#"["0.62", "0.38"]"#, None),
+            market_with(true, r#"["0.9995", "0.0005"]"#, None),
+            market_with(true, r#"["1", "0"]"#, Some("proposed")),
+        ] {
+            let resolution = market.resolution().unwrap();
+            assert!(!resolution.resolved);
+            assert_eq!(resolution.winning_outcome_index, None);
+            assert!(resolution.payouts.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_binary_market_to_tradable() {
+        let json = r#"{
+            "id": "516710",
+            "question": "Fed decreases interest rates by 25 bps after December 2025 meeting?",
+            "description": "",
+            "conditionId": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "slug": "fed-decreases-interest-rates-by-25-bps-after-december-2025-meeting",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.345\", \"0.655\"]",
+            "clobTokenIds": "[\"87769991026114894163580777793845523168226980076553814689875238288185044414090\", \"13411284055273560855537595688801764123705139415061660246624128667183605973730\"]",
+            "orderPriceMinTickSize": 0.001,
+            "events": [{
+                "id": "16085",
+                "ticker": "fed-decision-in-december",
+                "slug": "fed-decision-in-december",
+                "title": "Fed decision in December?",
+                "negRisk": true
+            }]
+        }"#;
+        let mut market: GammaMarket = serde_json::from_str(json).unwrap();
+
+        let tradable = market.to_tradable().unwrap();
+        assert_eq!(tradable.condition_id, market.condition_id);
+        assert_eq!(tradable.outcomes, vec!["Yes", "No"]);
+        assert_eq!(tradable.prices, vec![dec!(0.345), dec!(0.655)]);
+        assert_eq!(tradable.tick_size, dec!(0.001));
+        assert!(tradable.neg_risk);
+        assert_eq!(
+            tradable.token_id("no"),
+            Some("13411284055273560855537595688801764123705139415061660246624128667183605973730")
+        );
+        let options = tradable.order_options();
+        assert_eq!(options.tick_size, Some(dec!(0.001)));
+        assert_eq!(options.neg_risk, Some(true));
+
+        market.clob_token_ids = None;
+        assert!(matches!(
+            market.to_tradable(),
+            Err(Error::MissingField(field)) if field == "clobTokenIds"
+        ));
+    }
+
+    #[test]
+    fn test_event_neg_risk_overrides_market_flag() {
+        let child = |id: &str, active: bool, closed: bool| {
+            serde_json::json!({
+                "id": id,
+                "question": format!("Candidate {}?", id),
+                "description": "",
+                "conditionId": format!("0x0{}", id),
+                "slug": format!("candidate-{}", id),
+                "outcomes": "[\"Yes\", \"No\"]",
+                "outcomePrices": "[\"0.2\", \"0.8\"]",
+                "clobTokenIds": format!("[\"{}1\", \"{}2\"]", id, id),
+                "orderPriceMinTickSize": 0.01,
+                "negRisk": false,
+                "active": active,
+                "closed": closed
+            })
+        };
+        let event: GammaEvent = serde_json::from_value(serde_json::json!({
+            "id": "903",
+            "ticker": "election-winner",
+            "slug": "election-winner",
+            "title": "Who will win the election?",
+            "negRisk": true,
+            "enableNegRisk": true,
+            "markets": [child("1", true, false), child("2", true, true), child("3", false, false)]
+        }))
+        .unwrap();
+
+        // The market disagrees with its event and has no events of its own
+        assert_eq!(event.markets[0].neg_risk, Some(false));
+        assert!(!event.markets[0].to_tradable().unwrap().neg_risk);
+
+        let tradable = event.tradable_markets().unwrap();
+        assert_eq!(tradable.len(), 1);
+        assert_eq!(tradable[0].condition_id, "0x01");
+        assert!(tradable[0].neg_risk);
+        assert_eq!(tradable[0].token_id("yes"), Some("11"));
+    }
+
+    #[test]
+    fn test_event_serde_round_trip() {
+        let json = r#"{
+            "id": "16085",
+            "ticker": "fed-decision-in-december",
+            "slug": "fed-decision-in-december",
+            "title": "Fed decision in December?",
+            "endDate": "2025-12-10T12:00:00Z",
+            "startTime": "2025-10-29 18:00:00+00",
+            "active": true,
+            "closed": false,
+            "negRisk": true,
+            "enableOrderBook": true,
+            "volume": 152345678.12,
+            "liquidity": 4123456.5,
+            "openInterest": 0,
+            "competitive": 0.9321,
+            "volume24hr": 2345678.9,
+            "seriesSlug": "fed-interest-rates",
+            "tags": [
+                { "id": "100196", "label": "Fed Rates", "slug": "fed-rates", "forceShow": true },
+                { "id": "120", "label": "Finance", "slug": "finance" }
+            ],
+            "markets": [{
+                "id": "516710",
+                "question": "Fed decreases interest rates by 25 bps after December 2025 meeting?",
+                "description": "This market will resolve according to the FOMC statement.",
+                "conditionId": "0x56687bf447db6ffa42ffe2204a05edaa20f55839a5f1ca5b7a1c0b8dc0ff0e00",
+                "slug": "fed-decreases-interest-rates-by-25-bps-after-december-2025-meeting",
+                "outcomes": "[\"Yes\", \"No\"]",
+                "outcomePrices": "[\"0.345\", \"0.655\"]",
+                "clobTokenIds": "[\"87769991026114894163580777793845523168226980076553814689875238288185044414090\", \"13411284055273560855537595688801764123705139415061660246624128667183605973730\"]",
+                "active": true,
+                "umaResolutionStatus": null,
+                "volume": "45678901.234",
+                "volumeNum": 45678901.234,
+                "liquidityNum": 987654.32,
+                "lastTradePrice": 0.345,
+                "bestBid": 0.34,
+                "bestAsk": 0.35,
+                "spread": 0.01,
+                "events": [{
+                    "id": "16085",
+                    "ticker": "fed-decision-in-december",
+                    "slug": "fed-decision-in-december",
+                    "title": "Fed decision in December?",
+                    "endDate": "2025-12-10",
+                    "negRisk": true
+                }]
+            }]
+        }"#;
+
+        let event: GammaEvent = serde_json::from_str(json).unwrap();
+        let decoded: GammaEvent =
+            serde_json::from_value(serde_json::to_value(&event).unwrap()).unwrap();
+        assert_eq!(decoded, event);
+
+        let market = &event.markets[0];
+        let decoded: GammaMarket =
+            serde_json::from_value(serde_json::to_value(market).unwrap()).unwrap();
+        assert_eq!(&decoded, market);
+        assert_eq!(
+            decoded.events[0].end_date,
+            event.markets[0].events[0].end_date
+        );
+    }
+}
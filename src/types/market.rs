@@ -1,6 +1,11 @@
 use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::order::PriceLevel;
+use super::websocket::WsEvent;
+use super::Side;
 
 /// Full market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +43,25 @@ pub struct Market {
 }
 
 impl Market {
+    /// Returns the market token with the given ID, if the market has one
+    pub fn token(&self, token_id: &str) -> Option<&Token> {
+        self.tokens.iter().find(|token| token.token_id == token_id)
+    }
+
+    /// The market's tokens with their outcome index and condition ID
+    pub fn condition_tokens(&self) -> Vec<ConditionToken> {
+        self.tokens
+            .iter()
+            .zip(0u32..)
+            .map(|(token, outcome_index)| ConditionToken {
+                condition_id: self.condition_id.clone(),
+                token_id: token.token_id.clone(),
+                outcome: token.outcome.clone(),
+                outcome_index,
+            })
+            .collect()
+    }
+
     /// Returns true if the market ends within the specified time period from now.
     /// Returns true if there's no end date (perpetual market).
     pub fn ends_within(&self, time_delta: TimeDelta) -> bool {
@@ -67,6 +91,24 @@ pub struct SimplifiedMarket {
 pub struct Token {
     pub token_id: String,
     pub outcome: String,
+    /// Last price reported by the CLOB for this outcome
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub price: Option<Decimal>,
+}
+
+/// A CLOB token together with the condition it trades
+///
+/// `outcome_index` is the token's position among the condition's outcomes, as
+/// used for CTF index sets (see [`IndexSet`](super::IndexSet)).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionToken {
+    pub condition_id: String,
+    pub token_id: String,
+    pub outcome: String,
+    pub outcome_index: u32,
 }
 
 /// Market rewards configuration
@@ -111,21 +153,29 @@ pub struct MidpointResponse {
     pub mid: Decimal,
 }
 
-/// Price response
+/// Decimal values keyed by token ID, as returned by the batch price endpoints
 #[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct TokenDecimals(
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal_map")]
+    pub  HashMap<String, Decimal>,
+);
+
+/// Price response
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PriceResponse {
     #[serde(with = "rust_decimal::serde::str")]
     pub price: Decimal,
 }
 
 /// Price history response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PriceHistoryResponse {
     pub history: Vec<PriceHistory>,
 }
 
 /// Price at a specific timestamp
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PriceHistory {
     #[serde(
         rename = "p",
@@ -136,30 +186,289 @@ pub struct PriceHistory {
     pub timestamp: u64,
 }
 
-/// Spread response
+/// Price at a point in time, as returned by
+/// [`ClobClient::get_price_history`](crate::client::ClobClient::get_price_history)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    #[serde(rename = "t", with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    #[serde(
+        rename = "p",
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
+    pub price: Decimal,
+}
+
+/// Response of the `/prices-history` endpoint with chrono timestamps
 #[derive(Debug, Deserialize)]
+pub(crate) struct PricePointsResponse {
+    pub history: Vec<PricePoint>,
+}
+
+/// Spread response
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SpreadResponse {
     #[serde(with = "rust_decimal::serde::str")]
     pub spread: Decimal,
 }
 
 /// Tick size response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TickSizeResponse {
     #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub minimum_tick_size: Decimal,
 }
 
 /// Negative risk response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NegRiskResponse {
     pub neg_risk: bool,
 }
 
+/// Order book for one token maintained from market WebSocket events
+///
+/// Seed it with a [`WsEvent::Book`] snapshot and keep feeding it every event
+/// from the stream; events for other tokens are ignored.
+///
+/// # Gaps
+///
+/// Price changes can be missed or arrive out of order, e.g. around a
+/// reconnect. The book flags this with [`needs_resync`](Self::needs_resync)
+/// when a change is older than one already applied, or when the best bid or
+/// ask a message reports for after the update differs from the local book.
+/// The best prices are compared once all of the message's levels for the
+/// token are applied. Changes older than the current snapshot are already
+/// part of it and are skipped.
+///
+/// The reported [`hash`](Self::hash) is kept but not recomputed: the server
+/// hashes its own book representation, which the stream does not carry.
+///
+/// To recover, subscribe again: the server answers a subscription with a
+/// fresh snapshot, which replaces the book and clears the flag.
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polymarket_rs::types::LocalOrderBook;
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// # async fn run() -> polymarket_rs::Result<()> {
+/// let token_id = "123".to_string();
+/// let client = MarketWsClient::new();
+/// let mut stream = client.subscribe(vec![token_id.clone()]).await?;
+/// let mut book = LocalOrderBook::new(token_id.clone());
+///
+/// while let Some(event) = stream.next().await {
+///     book.apply(&event?);
+///     if book.needs_resync() {
+///         // Resubscribe to get a fresh snapshot
+///         stream = client.subscribe(vec![token_id.clone()]).await?;
+///         continue;
+///     }
+///     println!("midpoint: {:?}", book.midpoint());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    asset_id: String,
+    tick_size: Option<Decimal>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    snapshot_timestamp: Option<u64>,
+    timestamp: Option<u64>,
+    hash: Option<String>,
+    needs_resync: bool,
+}
+
+impl LocalOrderBook {
+    /// Create an empty order book for a token
+    pub fn new(asset_id: impl Into<String>) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Token/asset ID this book tracks
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// Current tick size, if a tick size change has been seen
+    pub fn tick_size(&self) -> Option<Decimal> {
+        self.tick_size
+    }
+
+    /// Timestamp (ms) of the latest snapshot or change applied
+    pub fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    /// Book hash reported with the latest snapshot or change applied
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Whether a gap was detected since the last snapshot
+    ///
+    /// See [Gaps](Self#gaps) for how to recover.
+    pub fn needs_resync(&self) -> bool {
+        self.needs_resync
+    }
+
+    /// Update the book from a market WebSocket event
+    ///
+    /// - [`WsEvent::Book`] replaces the whole book with the snapshot
+    /// - [`WsEvent::PriceChange`] sets the size of each changed level; a size of
+    ///   zero removes the level. Out-of-sequence changes are flagged (see
+    ///   [Gaps](Self#gaps))
+    /// - [`WsEvent::TickSizeChange`] moves every level onto the new tick grid,
+    ///   bids rounding down and asks rounding up, merging levels that collide
+    pub fn apply(&mut self, event: &WsEvent) {
+        match event {
+            WsEvent::Book(book) if book.asset_id == self.asset_id => {
+                self.bids = collect_levels(&book.bids);
+                self.asks = collect_levels(&book.asks);
+                self.snapshot_timestamp = book.timestamp.parse().ok();
+                self.timestamp = self.snapshot_timestamp;
+                self.hash = Some(book.hash.clone());
+                self.needs_resync = false;
+            }
+            WsEvent::PriceChange(change) => {
+                let mut levels = change
+                    .price_changes
+                    .iter()
+                    .filter(|c| c.asset_id == self.asset_id)
+                    .peekable();
+                if levels.peek().is_none() {
+                    return;
+                }
+
+                let timestamp = change.timestamp.as_deref().and_then(|t| t.parse().ok());
+                if is_older(timestamp, self.snapshot_timestamp) {
+                    return;
+                }
+                if is_older(timestamp, self.timestamp) {
+                    self.needs_resync = true;
+                }
+
+                let (mut best_bid, mut best_ask) = (None, None);
+                for level in levels {
+                    let side = match level.side {
+                        Side::Buy => &mut self.bids,
+                        Side::Sell => &mut self.asks,
+                    };
+                    if level.size.is_zero() {
+                        side.remove(&level.price);
+                    } else {
+                        side.insert(level.price, level.size);
+                    }
+
+                    // The last reported best prices describe the final state
+                    best_bid = level.best_bid.or(best_bid);
+                    best_ask = level.best_ask.or(best_ask);
+                    if let Some(hash) = &level.hash {
+                        self.hash = Some(hash.clone());
+                    }
+                }
+                if !agrees(best_bid, self.best_bid()) || !agrees(best_ask, self.best_ask()) {
+                    self.needs_resync = true;
+                }
+                if timestamp > self.timestamp {
+                    self.timestamp = timestamp;
+                }
+            }
+            WsEvent::TickSizeChange(change) if change.asset_id == self.asset_id => {
+                let tick = change.new_tick_size;
+                if tick > Decimal::ZERO {
+                    self.bids = rebucket(&self.bids, |price| (price / tick).floor() * tick);
+                    self.asks = rebucket(&self.asks, |price| (price / tick).ceil() * tick);
+                }
+                self.tick_size = Some(tick);
+            }
+            _ => {}
+        }
+    }
+
+    /// Bid levels, best (highest) first
+    pub fn bids(&self) -> Vec<PriceLevel> {
+        self.bids.iter().rev().map(to_level).collect()
+    }
+
+    /// Ask levels, best (lowest) first
+    pub fn asks(&self) -> Vec<PriceLevel> {
+        self.asks.iter().map(to_level).collect()
+    }
+
+    /// Highest bid
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.iter().next_back().map(to_level)
+    }
+
+    /// Lowest ask
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(to_level)
+    }
+
+    /// Midpoint between the best bid and best ask
+    ///
+    /// Returns `None` if either side of the book is empty.
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+        Some((bid + ask) / Decimal::TWO)
+    }
+}
+
+/// Whether `timestamp` is known to be before `other`
+fn is_older(timestamp: Option<u64>, other: Option<u64>) -> bool {
+    matches!((timestamp, other), (Some(t), Some(other)) if t < other)
+}
+
+/// Whether a reported best price matches the local best level
+///
+/// A reported price of zero stands for an empty side.
+fn agrees(reported: Option<Decimal>, local: Option<PriceLevel>) -> bool {
+    match reported {
+        None => true,
+        Some(price) if price.is_zero() => local.is_none(),
+        Some(price) => local.is_some_and(|level| level.price == price),
+    }
+}
+
+fn collect_levels(levels: &[PriceLevel]) -> BTreeMap<Decimal, Decimal> {
+    levels
+        .iter()
+        .filter(|level| !level.size.is_zero())
+        .map(|level| (level.price, level.size))
+        .collect()
+}
+
+fn rebucket(
+    levels: &BTreeMap<Decimal, Decimal>,
+    round: impl Fn(Decimal) -> Decimal,
+) -> BTreeMap<Decimal, Decimal> {
+    let mut rebucketed = BTreeMap::new();
+    for (price, size) in levels {
+        *rebucketed.entry(round(*price)).or_insert(Decimal::ZERO) += *size;
+    }
+    rebucketed
+}
+
+fn to_level((price, size): (&Decimal, &Decimal)) -> PriceLevel {
+    PriceLevel {
+        price: *price,
+        size: *size,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::TimeDelta;
+    use rust_decimal_macros::dec;
 
     fn create_test_market(end_date_iso: Option<DateTime<Utc>>) -> Market {
         Market {
@@ -168,10 +477,12 @@ mod tests {
                 Token {
                     token_id: "token1".to_string(),
                     outcome: "Yes".to_string(),
+                    price: None,
                 },
                 Token {
                     token_id: "token2".to_string(),
                     outcome: "No".to_string(),
+                    price: None,
                 },
             ],
             rewards: Rewards {
@@ -208,6 +519,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_condition_tokens() {
+        let tokens = create_test_market(None).condition_tokens();
+        assert_eq!(
+            tokens,
+            vec![
+                ConditionToken {
+                    condition_id: "test".to_string(),
+                    token_id: "token1".to_string(),
+                    outcome: "Yes".to_string(),
+                    outcome_index: 0,
+                },
+                ConditionToken {
+                    condition_id: "test".to_string(),
+                    token_id: "token2".to_string(),
+                    outcome: "No".to_string(),
+                    outcome_index: 1,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_ends_within_near_future() {
         // Market ending in 1 hour should end within 2 hours
@@ -243,4 +576,259 @@ mod tests {
         assert!(market.ends_within(TimeDelta::hours(1)));
         assert!(market.ends_within(TimeDelta::days(7)));
     }
+
+    const ASSET: &str = "123";
+
+    fn event(value: serde_json::Value) -> WsEvent {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn snapshot() -> WsEvent {
+        event(serde_json::json!({
+            "event_type": "book",
+            "market": "0xabc",
+            "asset_id": ASSET,
+            "timestamp": "1700000000000",
+            "hash": "0x0",
+            "bids": [
+                { "price": "0.48", "size": "30" },
+                { "price": "0.50", "size": "10" },
+                { "price": "0.49", "size": "20" }
+            ],
+            "asks": [
+                { "price": "0.53", "size": "15" },
+                { "price": "0.52", "size": "5" }
+            ]
+        }))
+    }
+
+    fn price_change(asset_id: &str, side: &str, price: &str, size: &str) -> WsEvent {
+        event(serde_json::json!({
+            "event_type": "price_change",
+            "market": "0xabc",
+            "price_changes": [
+                { "asset_id": asset_id, "side": side, "price": price, "size": size }
+            ]
+        }))
+    }
+
+    fn prices(levels: Vec<PriceLevel>) -> Vec<Decimal> {
+        levels.into_iter().map(|level| level.price).collect()
+    }
+
+    #[test]
+    fn test_local_order_book_snapshot() {
+        let mut book = LocalOrderBook::new(ASSET);
+        assert!(book.midpoint().is_none());
+
+        book.apply(&snapshot());
+
+        assert_eq!(
+            prices(book.bids()),
+            vec![dec!(0.50), dec!(0.49), dec!(0.48)]
+        );
+        assert_eq!(prices(book.asks()), vec![dec!(0.52), dec!(0.53)]);
+        assert_eq!(book.best_bid().unwrap().size, dec!(10));
+        assert_eq!(book.best_ask().unwrap().size, dec!(5));
+        assert_eq!(book.midpoint(), Some(dec!(0.51)));
+    }
+
+    #[test]
+    fn test_local_order_book_price_changes() {
+        let mut book = LocalOrderBook::new(ASSET);
+        book.apply(&snapshot());
+
+        // New best bid
+        book.apply(&price_change(ASSET, "BUY", "0.51", "7"));
+        // Update an existing ask level
+        book.apply(&price_change(ASSET, "SELL", "0.53", "40"));
+        // Remove the best ask
+        book.apply(&price_change(ASSET, "SELL", "0.52", "0"));
+        // Removing a missing level is harmless
+        book.apply(&price_change(ASSET, "BUY", "0.10", "0"));
+        // Other tokens are ignored
+        book.apply(&price_change("456", "BUY", "0.99", "1"));
+
+        assert_eq!(
+            prices(book.bids()),
+            vec![dec!(0.51), dec!(0.50), dec!(0.49), dec!(0.48)]
+        );
+        assert_eq!(prices(book.asks()), vec![dec!(0.53)]);
+        assert_eq!(book.best_ask().unwrap().size, dec!(40));
+        assert_eq!(book.midpoint(), Some(dec!(0.52)));
+
+        // A new snapshot replaces everything
+        book.apply(&snapshot());
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(prices(book.asks()), vec![dec!(0.52), dec!(0.53)]);
+    }
+
+    fn timed_change(
+        timestamp: &str,
+        side: &str,
+        price: &str,
+        size: &str,
+        best_bid: &str,
+        best_ask: &str,
+    ) -> WsEvent {
+        event(serde_json::json!({
+            "event_type": "price_change",
+            "market": "0xabc",
+            "timestamp": timestamp,
+            "price_changes": [{
+                "asset_id": ASSET,
+                "side": side,
+                "price": price,
+                "size": size,
+                "hash": format!("hash-{}", timestamp),
+                "best_bid": best_bid,
+                "best_ask": best_ask
+            }]
+        }))
+    }
+
+    #[test]
+    fn test_local_order_book_detects_out_of_sequence_change() {
+        let mut book = LocalOrderBook::new(ASSET);
+        book.apply(&snapshot());
+        assert_eq!(book.timestamp(), Some(1700000000000));
+        assert_eq!(book.hash(), Some("0x0"));
+
+        // Older than the snapshot: already included, skipped
+        book.apply(&timed_change(
+            "1699999999999",
+            "BUY",
+            "0.50",
+            "0",
+            "0.49",
+            "0.52",
+        ));
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert!(!book.needs_resync());
+
+        book.apply(&timed_change(
+            "1700000000002",
+            "SELL",
+            "0.52",
+            "4",
+            "0.50",
+            "0.52",
+        ));
+        assert_eq!(book.hash(), Some("hash-1700000000002"));
+        assert!(!book.needs_resync());
+
+        // Sent before the change above but delivered after it
+        book.apply(&timed_change(
+            "1700000000001",
+            "SELL",
+            "0.52",
+            "0",
+            "0.50",
+            "0.53",
+        ));
+        assert!(book.needs_resync());
+        assert_eq!(book.timestamp(), Some(1700000000002));
+
+        // A fresh snapshot clears the flag
+        book.apply(&snapshot());
+        assert!(!book.needs_resync());
+    }
+
+    #[test]
+    fn test_local_order_book_checks_best_prices_after_all_levels() {
+        let mut book = LocalOrderBook::new(ASSET);
+        book.apply(&snapshot());
+
+        // The best bid moves from 0.50 to 0.505 in two steps; only the state
+        // after both matches the reported best bid
+        let change = |best_bid: &str| {
+            event(serde_json::json!({
+                "event_type": "price_change",
+                "market": "0xabc",
+                "timestamp": "1700000000001",
+                "price_changes": [
+                    {
+                        "asset_id": ASSET, "side": "BUY", "price": "0.50", "size": "0",
+                        "best_bid": best_bid, "best_ask": "0.52"
+                    },
+                    {
+                        "asset_id": ASSET, "side": "BUY", "price": "0.505", "size": "3",
+                        "best_bid": best_bid, "best_ask": "0.52"
+                    }
+                ]
+            }))
+        };
+        book.apply(&change("0.505"));
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.505));
+        assert!(!book.needs_resync());
+
+        book.apply(&snapshot());
+        book.apply(&change("0.51"));
+        assert!(book.needs_resync());
+    }
+
+    #[test]
+    fn test_local_order_book_detects_missed_change() {
+        let mut book = LocalOrderBook::new(ASSET);
+        book.apply(&snapshot());
+
+        // The server's book has a 0.51 bid this book never saw
+        book.apply(&timed_change(
+            "1700000000001",
+            "SELL",
+            "0.53",
+            "20",
+            "0.51",
+            "0.52",
+        ));
+        assert!(book.needs_resync());
+
+        // Emptying a side is reported as a zero price
+        book.apply(&snapshot());
+        book.apply(&timed_change(
+            "1700000000001",
+            "SELL",
+            "0.52",
+            "0",
+            "0.50",
+            "0.53",
+        ));
+        book.apply(&timed_change(
+            "1700000000002",
+            "SELL",
+            "0.53",
+            "0",
+            "0.50",
+            "0",
+        ));
+        assert!(book.best_ask().is_none());
+        assert!(!book.needs_resync());
+    }
+
+    #[test]
+    fn test_local_order_book_tick_size_change() {
+        let mut book = LocalOrderBook::new(ASSET);
+        book.apply(&snapshot());
+        book.apply(&price_change(ASSET, "SELL", "0.57", "1"));
+
+        book.apply(&event(serde_json::json!({
+            "event_type": "tick_size_change",
+            "asset_id": ASSET,
+            "market": "0xabc",
+            "old_tick_size": "0.01",
+            "new_tick_size": "0.1",
+            "timestamp": "1700000000000"
+        })));
+
+        assert_eq!(book.tick_size(), Some(dec!(0.1)));
+        // Bids round down: 0.50 stays, 0.49 and 0.48 merge into 0.4
+        let bids = book.bids();
+        assert_eq!(prices(bids.clone()), vec![dec!(0.5), dec!(0.4)]);
+        assert_eq!(bids[0].size, dec!(10));
+        assert_eq!(bids[1].size, dec!(50));
+        // All asks round up into 0.6
+        assert_eq!(book.asks().len(), 1);
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.6));
+        assert_eq!(book.best_ask().unwrap().size, dec!(21));
+    }
 }
@@ -3,8 +3,10 @@ mod enums;
 mod gamma;
 mod market;
 mod order;
+mod outcome;
 mod primitives;
-mod serde_helpers;
+mod rewards;
+pub(crate) mod serde_helpers;
 mod trade;
 mod websocket;
 
@@ -14,7 +16,9 @@ pub use enums::*;
 pub use gamma::*;
 pub use market::*;
 pub use order::*;
+pub use outcome::*;
 pub use primitives::*;
+pub use rewards::*;
 pub use trade::*;
 pub use websocket::*;
 
@@ -1,9 +1,11 @@
-use super::enums::{OrderType, Side};
+use super::enums::{OrderStatus, OrderType, Side};
 use crate::error::Result;
 use crate::{orders::calculate_market_price, OrderId};
 use alloy_primitives::U256;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 
@@ -14,6 +16,8 @@ pub struct OrderArgs {
     pub price: Decimal,
     pub size: Decimal,
     pub side: Side,
+    /// Time the order expires, required for GTD orders
+    pub expiration: Option<DateTime<Utc>>,
 }
 
 impl OrderArgs {
@@ -23,16 +27,33 @@ impl OrderArgs {
             price,
             size,
             side,
+            expiration: None,
         }
     }
+
+    /// Set the expiration time (signed as Unix seconds)
+    pub fn with_expiration(mut self, expiration: DateTime<Utc>) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Expiration as the Unix seconds the exchange expects (0 if unset)
+    pub fn expiration_secs(&self) -> u64 {
+        self.expiration
+            .map(|expiration| expiration.timestamp().max(0) as u64)
+            .unwrap_or(0)
+    }
 }
 
 /// Arguments for creating a market order
 #[derive(Debug, Clone)]
 pub struct MarketOrderArgs {
     pub token_id: String,
+    /// Collateral (USDC) to spend for BUY orders, shares to sell for SELL orders
     pub amount: Decimal,
     pub side: Side,
+    /// Order type used when posting, FOK by default
+    pub order_type: OrderType,
 }
 
 impl MarketOrderArgs {
@@ -41,8 +62,15 @@ impl MarketOrderArgs {
             token_id: token_id.into(),
             amount,
             side,
+            order_type: OrderType::Fok,
         }
     }
+
+    /// Set the order type used when posting (FOK or FAK)
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
 }
 
 /// Extra optional arguments for order creation
@@ -89,6 +117,13 @@ impl ExtraOrderArgs {
 pub struct CreateOrderOptions {
     pub tick_size: Option<Decimal>,
     pub neg_risk: Option<bool>,
+    /// Order salt (random if not set)
+    pub salt: Option<u64>,
+    /// Highest fee rate the market accepts, checked against
+    /// [`ExtraOrderArgs::fee_rate_bps`] when known
+    pub max_fee_rate_bps: Option<u32>,
+    /// Ask the exchange to defer matching of the posted order
+    pub defer_exec: bool,
 }
 
 impl CreateOrderOptions {
@@ -105,6 +140,31 @@ impl CreateOrderOptions {
         self.neg_risk = Some(neg_risk);
         self
     }
+
+    /// Use a fixed salt instead of a random one
+    ///
+    /// The same arguments signed with the same salt produce an identical order,
+    /// which makes order creation reproducible and resubmission idempotent.
+    /// The salt is part of the signed payload, so reusing it with different
+    /// arguments still produces a different order.
+    pub fn salt(mut self, salt: u64) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Reject orders whose fee rate exceeds `max_fee_rate_bps`
+    pub fn max_fee_rate_bps(mut self, max_fee_rate_bps: u32) -> Self {
+        self.max_fee_rate_bps = Some(max_fee_rate_bps);
+        self
+    }
+
+    /// Post the order with `deferExec` set
+    ///
+    /// This is a posting flag, not part of the signed order.
+    pub fn defer_exec(mut self, defer_exec: bool) -> Self {
+        self.defer_exec = defer_exec;
+        self
+    }
 }
 
 /// Signed order request ready to be posted
@@ -124,6 +184,9 @@ pub struct SignedOrderRequest {
     pub side: String,
     pub signature_type: u8,
     pub signature: String,
+    /// Sent as `deferExec` next to the order when posting; not signed
+    #[serde(skip)]
+    pub defer_exec: bool,
 }
 
 /// Order to be posted to the API
@@ -133,11 +196,13 @@ pub struct PostOrder {
     order: SignedOrderRequest,
     owner: String,
     order_type: OrderType,
+    defer_exec: bool,
 }
 
 impl PostOrder {
     pub fn new(order: SignedOrderRequest, owner: String, order_type: OrderType) -> Self {
         Self {
+            defer_exec: order.defer_exec,
             order,
             owner,
             order_type,
@@ -146,7 +211,7 @@ impl PostOrder {
 }
 
 /// Response for open orders query
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct OpenOrdersResponse {
     pub limit: u64,
     pub count: u64,
@@ -155,7 +220,7 @@ pub struct OpenOrdersResponse {
 }
 
 /// Open order from the API
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct OpenOrder {
     pub id: OrderId,
     pub associate_trades: Vec<String>,
@@ -185,6 +250,7 @@ pub struct OpenOrderParams {
     pub id: Option<String>,
     pub asset_id: Option<String>,
     pub market: Option<String>,
+    pub next_cursor: Option<String>,
 }
 
 impl OpenOrderParams {
@@ -207,8 +273,13 @@ impl OpenOrderParams {
         self
     }
 
+    pub fn next_cursor(mut self, next_cursor: impl Into<String>) -> Self {
+        self.next_cursor = Some(next_cursor.into());
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, &String)> {
-        let mut params = Vec::with_capacity(3);
+        let mut params = Vec::with_capacity(4);
 
         if let Some(ref id) = self.id {
             params.push(("id", id));
@@ -222,12 +293,16 @@ impl OpenOrderParams {
             params.push(("market", market));
         }
 
+        if let Some(ref next_cursor) = self.next_cursor {
+            params.push(("next_cursor", next_cursor));
+        }
+
         params
     }
 }
 
 /// Price level in order book (price and size pair)
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PriceLevel {
     /// Price at this level
     #[serde(with = "rust_decimal::serde::str")]
@@ -238,7 +313,7 @@ pub struct PriceLevel {
 }
 
 /// Order book summary with bids and asks
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderBookSummary {
     pub market: String,
     pub asset_id: String,
@@ -267,13 +342,13 @@ impl OrderBookSummary {
 
     pub fn sort_bids(&self) -> Vec<PriceLevel> {
         let mut bids = self.bids.clone();
-        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        bids.sort_by_key(|b| std::cmp::Reverse(b.price));
         bids
     }
 
     pub fn sort_asks(&self) -> Vec<PriceLevel> {
         let mut asks = self.asks.clone();
-        asks.sort_by(|a, b| a.price.cmp(&b.price));
+        asks.sort_by_key(|a| a.price);
         asks
     }
 }
@@ -295,16 +370,24 @@ impl BookParams {
 }
 
 /// Response from posting an order
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostOrderResponse {
     pub error_msg: String,
     #[serde(rename = "orderID")]
     pub order_id: OrderId,
+    /// Raw status string as returned by the CLOB
     pub status: String,
     pub success: bool,
 }
 
+impl PostOrderResponse {
+    /// Typed form of [`status`](Self::status)
+    pub fn order_status(&self) -> OrderStatus {
+        OrderStatus::from(self.status.as_str())
+    }
+}
+
 /// Arguments for posting multiple orders
 #[derive(Debug, Clone)]
 pub struct PostOrderArgs {
@@ -325,8 +408,12 @@ impl PostOrderArgs {
 /// - `cancel_orders` - Cancel multiple orders
 /// - `cancel_all` - Cancel all orders
 /// - `cancel_market_orders` - Cancel orders by market/asset
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CancelOrdersResponse {
+    /// IDs of the orders that were canceled
+    #[serde(default)]
     pub canceled: Vec<OrderId>,
-    pub not_canceled: serde_json::Value,
+    /// Orders that could not be canceled, mapped to the reason
+    #[serde(default)]
+    pub not_canceled: HashMap<OrderId, String>,
 }
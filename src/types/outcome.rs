@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a binary (YES/NO) market
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Yes,
+    No,
+}
+
+impl Outcome {
+    /// Outcome index as used by the CTF and the data API (0 for YES, 1 for NO)
+    pub fn index(self) -> u32 {
+        match self {
+            Outcome::Yes => 0,
+            Outcome::No => 1,
+        }
+    }
+
+    /// Create an outcome from its index
+    pub fn from_index(index: u32) -> Option<Self> {
+        match index {
+            0 => Some(Outcome::Yes),
+            1 => Some(Outcome::No),
+            _ => None,
+        }
+    }
+
+    /// Index set selecting only this outcome
+    pub fn index_set(self) -> u32 {
+        1 << self.index()
+    }
+}
+
+/// Helpers for CTF index sets
+///
+/// An index set is a bitmask over a condition's outcome slots: bit `i` is set
+/// when outcome index `i` is included. For a binary market YES (index 0) is
+/// `0b01` and NO (index 1) is `0b10`; categorical markets follow the same rule
+/// for every outcome. Index sets are `u32`, so outcome indices must be below 32.
+pub struct IndexSet;
+
+impl IndexSet {
+    /// Index set containing a single outcome
+    ///
+    /// Returns `None` if `outcome_index` is 32 or greater.
+    pub fn from_outcome_index(outcome_index: u32) -> Option<u32> {
+        1u32.checked_shl(outcome_index)
+    }
+
+    /// Outcome indices included in an index set, in ascending order
+    pub fn to_outcome_indices(index_set: u32) -> Vec<u32> {
+        (0..u32::BITS)
+            .filter(|i| index_set & (1u32 << i) != 0)
+            .collect()
+    }
+
+    /// One single-outcome index set for each of `outcome_count` outcomes
+    ///
+    /// Useful for redeeming or merging every outcome of a condition, e.g.
+    /// `[1, 2]` for a binary market. Returns `None` if `outcome_count` is
+    /// greater than 32.
+    pub fn all_for_outcomes(outcome_count: u32) -> Option<Vec<u32>> {
+        (0..outcome_count).map(Self::from_outcome_index).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_outcome_index() {
+        assert_eq!(IndexSet::from_outcome_index(0), Some(0b1));
+        assert_eq!(IndexSet::from_outcome_index(1), Some(0b10));
+        assert_eq!(IndexSet::from_outcome_index(3), Some(0b1000));
+        assert_eq!(Outcome::Yes.index_set(), 1);
+        assert_eq!(Outcome::No.index_set(), 2);
+    }
+
+    #[test]
+    fn test_index_set_round_trip() {
+        for index in [0, 1, 3, 31] {
+            let index_set = IndexSet::from_outcome_index(index).unwrap();
+            assert_eq!(IndexSet::to_outcome_indices(index_set), vec![index]);
+        }
+
+        assert_eq!(IndexSet::to_outcome_indices(0b1011), vec![0, 1, 3]);
+        assert!(IndexSet::to_outcome_indices(0).is_empty());
+
+        for outcome in [Outcome::Yes, Outcome::No] {
+            assert_eq!(Outcome::from_index(outcome.index()), Some(outcome));
+        }
+        assert_eq!(Outcome::from_index(2), None);
+    }
+
+    #[test]
+    fn test_all_for_outcomes() {
+        assert_eq!(IndexSet::all_for_outcomes(2), Some(vec![1, 2]));
+        assert_eq!(IndexSet::all_for_outcomes(4), Some(vec![1, 2, 4, 8]));
+        assert_eq!(IndexSet::all_for_outcomes(0), Some(vec![]));
+        assert_eq!(IndexSet::all_for_outcomes(32).unwrap().len(), 32);
+        assert_eq!(IndexSet::all_for_outcomes(33), None);
+    }
+
+    #[test]
+    fn test_from_outcome_index_out_of_range() {
+        assert_eq!(IndexSet::from_outcome_index(32), None);
+        assert_eq!(IndexSet::from_outcome_index(u32::MAX), None);
+    }
+}
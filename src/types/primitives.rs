@@ -1,6 +1,9 @@
+use alloy_primitives::{hex, Address, B256};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::error::{Error, Result};
+
 /// Type-safe token identifier
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -172,3 +175,110 @@ impl AsRef<str> for MarketSlug {
         &self.0
     }
 }
+
+/// Parse a condition ID, which must be 32 bytes of hex (`0x` prefix optional)
+pub fn validate_condition_id(condition_id: &str) -> Result<B256> {
+    let hex = condition_id.strip_prefix("0x").unwrap_or(condition_id);
+    if hex.len() != 64 {
+        return Err(Error::InvalidParameter(format!(
+            "Invalid condition ID {}: expected 32 bytes of hex",
+            condition_id
+        )));
+    }
+
+    hex.parse().map_err(|e| {
+        Error::InvalidParameter(format!("Invalid condition ID {}: {}", condition_id, e))
+    })
+}
+
+/// Parse a 20-byte hex address (`0x` prefix optional)
+///
+/// All-lowercase and all-uppercase addresses are accepted as is; mixed-case
+/// addresses must carry a valid EIP-55 checksum.
+pub fn validate_address(address: &str) -> Result<Address> {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    let invalid =
+        |reason: &str| Error::InvalidParameter(format!("Invalid address {}: {}", address, reason));
+
+    if hex.len() != 40 {
+        return Err(invalid("expected 20 bytes of hex"));
+    }
+
+    let parsed: Address = hex
+        .parse()
+        .map_err(|_| invalid("expected 20 bytes of hex"))?;
+
+    let mixed_case =
+        hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    if mixed_case && parsed.to_checksum(None)[2..] != *hex {
+        return Err(invalid("EIP-55 checksum mismatch"));
+    }
+
+    Ok(parsed)
+}
+
+/// Normalize an address to the crate's canonical form: lowercase hex with `0x`
+///
+/// Addresses entering the crate go through this, so checksummed, lowercase
+/// and unprefixed spellings of the same address compare equal. Validation is
+/// the same as [`validate_address`].
+pub fn normalize_address(address: &str) -> Result<String> {
+    let parsed = validate_address(address)?;
+    Ok(format!("0x{}", hex::encode(parsed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONDITION_ID: &str = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+    #[test]
+    fn test_validate_condition_id() {
+        let parsed = validate_condition_id(CONDITION_ID).unwrap();
+        assert_eq!(parsed.to_string(), CONDITION_ID);
+        assert_eq!(validate_condition_id(&CONDITION_ID[2..]).unwrap(), parsed);
+
+        // 31 bytes
+        assert!(matches!(
+            validate_condition_id(&CONDITION_ID[..64]),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(validate_condition_id(&format!("0x{}", "zz".repeat(32))).is_err());
+    }
+
+    #[test]
+    fn test_validate_address() {
+        let checksummed = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let parsed = validate_address(checksummed).unwrap();
+
+        assert_eq!(
+            validate_address(&checksummed.to_lowercase()).unwrap(),
+            parsed
+        );
+        assert_eq!(validate_address(&checksummed[2..]).unwrap(), parsed);
+
+        // Mixed case with one letter flipped fails EIP-55
+        let bad_checksum = "0x4d97DCd97eC945f40cF65F87097ACe5EA0476045";
+        assert!(matches!(
+            validate_address(bad_checksum),
+            Err(Error::InvalidParameter(msg)) if msg.contains("checksum")
+        ));
+
+        // 21 bytes
+        assert!(validate_address(&format!("{}00", checksummed)).is_err());
+        assert!(validate_address("0xnot-an-address").is_err());
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        let checksummed = "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045";
+        let lowercase = "0x4d97dcd97ec945f40cf65f87097ace5ea0476045";
+
+        for input in [checksummed, lowercase, &checksummed[2..], &lowercase[2..]] {
+            assert_eq!(normalize_address(input).unwrap(), lowercase);
+        }
+        assert!(normalize_address("0x4d97DCd97eC945f40cF65F87097ACe5EA0476045").is_err());
+        assert!(normalize_address("0xabc").is_err());
+    }
+}
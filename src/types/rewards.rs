@@ -0,0 +1,158 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Liquidity rewards configuration of a market
+///
+/// Orders qualify for rewards when they are at least `rewards_min_size`
+/// shares and within `rewards_max_spread` cents of the midpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardConfig {
+    pub condition_id: String,
+    #[serde(default)]
+    pub question: Option<String>,
+    #[serde(default)]
+    pub market_slug: Option<String>,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rewards_min_size: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rewards_max_spread: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub market_competitiveness: Option<Decimal>,
+    #[serde(default)]
+    pub rewards_config: Vec<RewardRate>,
+}
+
+impl RewardConfig {
+    /// Total daily reward rate across all reward assets
+    pub fn daily_rate(&self) -> Decimal {
+        self.rewards_config
+            .iter()
+            .map(|rate| rate.rate_per_day)
+            .sum()
+    }
+}
+
+/// A reward program paying `rate_per_day` of an asset between two dates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardRate {
+    pub asset_address: String,
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rate_per_day: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub total_rewards: Option<Decimal>,
+}
+
+/// Paginated reward configurations from the CLOB API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardConfigsResponse {
+    pub limit: u64,
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<RewardConfig>,
+}
+
+/// Rewards a maker earned in one market on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserEarning {
+    pub date: NaiveDate,
+    pub condition_id: String,
+    pub asset_address: String,
+    pub maker_address: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub earnings: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub asset_rate: Decimal,
+}
+
+/// Paginated daily earnings from the authenticated CLOB API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEarningsResponse {
+    pub limit: u64,
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<UserEarning>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_reward_config_parses_rewards_payload() {
+        let payload = serde_json::json!({
+            "limit": 100,
+            "count": 1,
+            "next_cursor": "LTE=",
+            "data": [{
+                "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                "question": "Will it rain tomorrow?",
+                "market_slug": "will-it-rain-tomorrow",
+                "event_slug": "rain",
+                "rewards_max_spread": 3.5,
+                "rewards_min_size": 50,
+                "market_competitiveness": "0.82",
+                "tokens": [],
+                "rewards_config": [
+                    {
+                        "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                        "start_date": "2024-10-01",
+                        "end_date": "2500-12-31",
+                        "rate_per_day": 25.5,
+                        "total_rewards": 0
+                    },
+                    {
+                        "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                        "start_date": "2024-10-15",
+                        "end_date": "2024-10-31",
+                        "rate_per_day": "4.5",
+                        "total_rewards": null
+                    }
+                ]
+            }]
+        });
+
+        let page: RewardConfigsResponse = serde_json::from_value(payload).unwrap();
+        let config = &page.data[0];
+
+        assert_eq!(config.rewards_min_size, dec!(50));
+        assert_eq!(config.rewards_max_spread, dec!(3.5));
+        assert_eq!(config.market_competitiveness, Some(dec!(0.82)));
+        assert_eq!(config.rewards_config.len(), 2);
+        assert_eq!(config.rewards_config[0].rate_per_day, dec!(25.5));
+        assert_eq!(
+            config.rewards_config[0].start_date,
+            NaiveDate::from_ymd_opt(2024, 10, 1)
+        );
+        assert_eq!(config.rewards_config[1].total_rewards, None);
+        assert_eq!(config.daily_rate(), dec!(30));
+    }
+
+    #[test]
+    fn test_user_earning_parses() {
+        let earning: UserEarning = serde_json::from_value(serde_json::json!({
+            "date": "2024-10-20",
+            "condition_id": "0x5f65",
+            "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+            "maker_address": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "earnings": 1.234567,
+            "asset_rate": 1
+        }))
+        .unwrap();
+
+        assert_eq!(earning.date, NaiveDate::from_ymd_opt(2024, 10, 20).unwrap());
+        assert_eq!(earning.earnings, dec!(1.234567));
+        assert_eq!(earning.asset_rate, dec!(1));
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -42,13 +42,56 @@ where
     match Repr::deserialize(deserializer)? {
         Repr::Str(s) => Decimal::from_str(&s).map_err(serde::de::Error::custom),
         Repr::F64(f) => {
-            Decimal::from_f64(f).ok_or_else(|| serde::de::Error::custom("invalid f64 for Decimal"))
+            decimal_from_f64(f).ok_or_else(|| serde::de::Error::custom("invalid f64 for Decimal"))
         }
         Repr::U64(u) => Ok(Decimal::from(u)),
         Repr::I64(i) => Ok(Decimal::from(i)),
     }
 }
 
+/// Deserialize Option<Decimal> from a JSON number, string or null
+pub fn deserialize_optional_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_decimal")] Decimal);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(value)| value))
+}
+
+/// Deserialize a map of Decimals given as strings or numbers
+///
+/// Entries with a null value are left out.
+pub fn deserialize_decimal_map<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_decimal")] Decimal);
+
+    Ok(
+        HashMap::<String, Option<Wrapper>>::deserialize(deserializer)?
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|Wrapper(value)| (key, value)))
+            .collect(),
+    )
+}
+
+/// Convert an f64 to Decimal without picking up binary float noise
+///
+/// Goes through the shortest decimal representation that round-trips to the
+/// same f64, so a value parsed from JSON `0.345` becomes exactly `0.345`
+/// rather than `0.34499999999999997`. Returns None for NaN and infinities.
+pub fn decimal_from_f64(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    Decimal::from_str(&value.to_string()).ok()
+}
+
 /// Deserialize Option<DateTime<Utc>> from an optional datetime string
 /// Supports multiple formats:
 /// - RFC3339: "2022-07-27T14:41:12.085+00:00" or "2022-07-27T14:41:12.085Z"
@@ -74,7 +117,7 @@ where
             }
 
             // Try RFC3339 first
-            if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
                 return Ok(Some(dt.with_timezone(&Utc)));
             }
 
@@ -94,7 +137,7 @@ where
             }
 
             // Try date-only format: "2022-07-27"
-            if let Ok(date) = NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
                 let dt = date
                     .and_hms_opt(0, 0, 0)
                     .ok_or_else(|| serde::de::Error::custom("invalid date"))?
@@ -122,6 +165,20 @@ mod tests {
         date: Option<DateTime<Utc>>,
     }
 
+    #[test]
+    fn test_decimal_from_f64_is_exact() {
+        assert_eq!(
+            decimal_from_f64(0.345),
+            Some(Decimal::from_str("0.345").unwrap())
+        );
+        assert_eq!(
+            decimal_from_f64(0.1 + 0.2),
+            Some(Decimal::from_str("0.30000000000000004").unwrap())
+        );
+        assert_eq!(decimal_from_f64(f64::NAN), None);
+        assert_eq!(decimal_from_f64(f64::INFINITY), None);
+    }
+
     #[test]
     fn test_deserialize_optional_datetime_z_suffix() {
         let json = r#"{"date": "2025-12-09T00:30:00Z"}"#;
@@ -1,25 +1,26 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 
 use crate::{types::ActivityType, Side};
 
-/// Custom deserializer for optional Side that treats empty strings as None
+/// Custom deserializer for optional Side that treats empty strings and null as None
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s: String = Deserialize::deserialize(deserializer)?;
-    if s.is_empty() {
-        Ok(None)
-    } else {
-        serde_json::from_str(&format!("\"{}\"", s))
+    // A missing side is serialized back as null
+    let s: Option<String> = Deserialize::deserialize(deserializer)?;
+    match s.as_deref() {
+        None | Some("") => Ok(None),
+        Some(s) => serde_json::from_str(&format!("\"{}\"", s))
             .map(Some)
-            .map_err(serde::de::Error::custom)
+            .map_err(serde::de::Error::custom),
     }
 }
 
 /// User position information
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     #[serde(rename = "proxyWallet")]
     pub proxy_wallet: String,
@@ -91,6 +92,93 @@ pub struct Position {
     pub negative_risk: bool,
 }
 
+impl Position {
+    /// Amount paid for the shares currently held (`size * avg_price`)
+    pub fn cost_basis(&self) -> Decimal {
+        self.size * self.avg_price
+    }
+
+    /// Gain or loss if the position were valued at `current_value`
+    pub fn unrealized_pnl(&self) -> Decimal {
+        self.current_value - self.cost_basis()
+    }
+
+    /// Unrealized P&L as a percentage of the cost basis
+    ///
+    /// Returns zero when the cost basis is zero (e.g. shares received for free),
+    /// since there is nothing to measure the return against.
+    pub fn percent_return(&self) -> Decimal {
+        percent_of(self.unrealized_pnl(), self.cost_basis())
+    }
+}
+
+/// Aggregate value and P&L over a set of positions
+///
+/// Positions are valued at `current_value`. Redeemable positions from resolved
+/// markets are included like any other: a winning position that has not been
+/// redeemed yet counts at its current value (its payout), a losing one at zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioSummary {
+    /// Sum of `current_value`
+    pub total_value: Decimal,
+    /// Sum of cost bases (see [`Position::cost_basis`])
+    pub total_cost: Decimal,
+    /// `total_value - total_cost`
+    pub total_unrealized_pnl: Decimal,
+    pub position_count: usize,
+    /// Breakdown per market, keyed by condition ID
+    pub by_market: BTreeMap<String, MarketPositionSummary>,
+}
+
+/// Positions held in one market, summed over its outcomes
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketPositionSummary {
+    pub title: String,
+    /// Sum of share sizes across outcomes
+    pub size: Decimal,
+    pub value: Decimal,
+    pub cost: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub position_count: usize,
+}
+
+impl PortfolioSummary {
+    /// Aggregate positions, grouping them by `condition_id`
+    pub fn from_positions(positions: &[Position]) -> Self {
+        let mut summary = Self::default();
+        for position in positions {
+            let cost = position.cost_basis();
+            summary.total_value += position.current_value;
+            summary.total_cost += cost;
+            summary.position_count += 1;
+
+            let market = summary
+                .by_market
+                .entry(position.condition_id.clone())
+                .or_insert_with(|| MarketPositionSummary {
+                    title: position.title.clone(),
+                    ..Default::default()
+                });
+            market.size += position.size;
+            market.value += position.current_value;
+            market.cost += cost;
+            market.unrealized_pnl = market.value - market.cost;
+            market.position_count += 1;
+        }
+        summary.total_unrealized_pnl = summary.total_value - summary.total_cost;
+        summary
+    }
+}
+
+/// `amount` as a percentage of `base`, or zero if `base` is zero
+fn percent_of(amount: Decimal, base: Decimal) -> Decimal {
+    if base.is_zero() {
+        Decimal::ZERO
+    } else {
+        amount / base * Decimal::ONE_HUNDRED
+    }
+}
+
 /// User position value summary
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PositionValue {
@@ -100,7 +188,7 @@ pub struct PositionValue {
 }
 
 /// Trade information from the data API
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Trade {
     #[serde(rename = "proxyWallet")]
     pub proxy_wallet: String,
@@ -133,7 +221,7 @@ pub struct Trade {
 }
 
 /// Activity information from the data API
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Activity {
     #[serde(rename = "proxyWallet")]
     pub proxy_wallet: String,
@@ -168,7 +256,7 @@ pub struct Activity {
 }
 
 /// Closed position information from the data API
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClosedPosition {
     #[serde(rename = "proxyWallet")]
     pub proxy_wallet: String,
@@ -212,6 +300,20 @@ pub struct ClosedPosition {
     pub end_date: String,
 }
 
+impl ClosedPosition {
+    /// Amount paid for the shares that were closed (`total_bought * avg_price`)
+    pub fn cost_basis(&self) -> Decimal {
+        self.total_bought * self.avg_price
+    }
+
+    /// Realized P&L as a percentage of the cost basis, zero if the cost basis is zero
+    ///
+    /// The realized P&L itself is reported by the API in `realized_pnl`.
+    pub fn percent_return(&self) -> Decimal {
+        percent_of(self.realized_pnl, self.cost_basis())
+    }
+}
+
 /// Parameters for querying trades
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct TradeParams {
@@ -221,6 +323,7 @@ pub struct TradeParams {
     pub asset_id: Option<String>,
     pub before: Option<u64>,
     pub after: Option<u64>,
+    pub next_cursor: Option<String>,
 }
 
 impl TradeParams {
@@ -258,6 +361,11 @@ impl TradeParams {
         self
     }
 
+    pub fn next_cursor(mut self, next_cursor: impl Into<String>) -> Self {
+        self.next_cursor = Some(next_cursor.into());
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, String)> {
         let mut params = Vec::with_capacity(6);
 
@@ -285,6 +393,308 @@ impl TradeParams {
             params.push(("maker_address", maker_address.clone()));
         }
 
+        if let Some(ref next_cursor) = self.next_cursor {
+            params.push(("next_cursor", next_cursor.clone()));
+        }
+
         params
     }
 }
+
+/// Paginated trade history from the authenticated CLOB API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradesResponse {
+    pub limit: u64,
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<ClobTrade>,
+}
+
+/// Trade from the authenticated CLOB API
+///
+/// Unlike [`Trade`] from the data API, this includes fees and the maker
+/// orders matched against the taker order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClobTrade {
+    pub id: String,
+    pub taker_order_id: String,
+    pub market: String,
+    pub asset_id: String,
+    pub side: Side,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub fee_rate_bps: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    pub status: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
+    pub match_time: u64,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_number_from_string"
+    )]
+    pub last_update: u64,
+    pub outcome: String,
+    #[serde(default)]
+    pub bucket_index: u64,
+    pub owner: String,
+    pub maker_address: String,
+    #[serde(default)]
+    pub maker_orders: Vec<ClobMakerOrder>,
+    #[serde(default)]
+    pub transaction_hash: String,
+    /// "TAKER" or "MAKER", from the point of view of the requesting account
+    pub trader_side: String,
+}
+
+/// Maker order filled as part of a [`ClobTrade`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClobMakerOrder {
+    pub order_id: String,
+    pub owner: String,
+    pub maker_address: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub matched_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub fee_rate_bps: Decimal,
+    pub asset_id: String,
+    pub outcome: String,
+    #[serde(default)]
+    pub side: Option<Side>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn position(size: Decimal, avg_price: Decimal, cur_price: Decimal) -> Position {
+        Position {
+            size,
+            avg_price,
+            cur_price,
+            initial_value: size * avg_price,
+            current_value: size * cur_price,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_winning_position_pnl() {
+        let position = position(dec!(100), dec!(0.40), dec!(0.65));
+
+        assert_eq!(position.cost_basis(), dec!(40));
+        assert_eq!(position.unrealized_pnl(), dec!(25));
+        assert_eq!(position.percent_return(), dec!(62.5));
+    }
+
+    #[test]
+    fn test_losing_position_pnl() {
+        let position = position(dec!(50), dec!(0.80), dec!(0.20));
+
+        assert_eq!(position.cost_basis(), dec!(40));
+        assert_eq!(position.unrealized_pnl(), dec!(-30));
+        assert_eq!(position.percent_return(), dec!(-75));
+    }
+
+    #[test]
+    fn test_zero_cost_basis() {
+        let position = position(dec!(10), dec!(0), dec!(0.5));
+        assert_eq!(position.unrealized_pnl(), dec!(5));
+        assert_eq!(position.percent_return(), Decimal::ZERO);
+
+        let closed = ClosedPosition::default();
+        assert_eq!(closed.percent_return(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_portfolio_summary_groups_by_market() {
+        let in_market = |condition_id: &str, position: Position| Position {
+            condition_id: condition_id.to_string(),
+            title: format!("Market {}", condition_id),
+            ..position
+        };
+        let positions = vec![
+            in_market("0xa", position(dec!(100), dec!(0.40), dec!(0.65))),
+            in_market("0xa", position(dec!(20), dec!(0.50), dec!(0.35))),
+            // Resolved in our favour but not redeemed yet
+            Position {
+                redeemable: true,
+                ..in_market("0xb", position(dec!(50), dec!(0.80), dec!(1)))
+            },
+        ];
+
+        let summary = PortfolioSummary::from_positions(&positions);
+        assert_eq!(summary.position_count, 3);
+        assert_eq!(summary.total_value, dec!(122));
+        assert_eq!(summary.total_cost, dec!(90));
+        assert_eq!(summary.total_unrealized_pnl, dec!(32));
+
+        let a = &summary.by_market["0xa"];
+        assert_eq!(a.title, "Market 0xa");
+        assert_eq!(a.size, dec!(120));
+        assert_eq!(a.value, dec!(72));
+        assert_eq!(a.cost, dec!(50));
+        assert_eq!(a.unrealized_pnl, dec!(22));
+        assert_eq!(a.position_count, 2);
+
+        let b = &summary.by_market["0xb"];
+        assert_eq!(b.value, dec!(50));
+        assert_eq!(b.unrealized_pnl, dec!(10));
+
+        assert_eq!(
+            PortfolioSummary::from_positions(&[]),
+            PortfolioSummary::default()
+        );
+    }
+
+    #[test]
+    fn test_closed_position_return() {
+        let closed = ClosedPosition {
+            avg_price: dec!(0.25),
+            total_bought: dec!(200),
+            realized_pnl: dec!(150),
+            ..Default::default()
+        };
+
+        assert_eq!(closed.cost_basis(), dec!(50));
+        assert_eq!(closed.percent_return(), dec!(300));
+    }
+
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+        serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_position_serde_round_trip() {
+        let position: Position = serde_json::from_str(
+            r#"{
+                "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                "asset": "87769991026114894163580777793845523168226980076553814689875238288185044414090",
+                "conditionId": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+                "size": 1250.5,
+                "avgPrice": 0.3421,
+                "initialValue": 427.796,
+                "currentValue": 431.4225,
+                "cashPnl": 3.6265,
+                "percentPnl": 0.8477,
+                "totalBought": 1250.5,
+                "realizedPnl": 0,
+                "percentRealizedPnl": 0,
+                "curPrice": 0.345,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "Fed decreases interest rates by 25 bps after December 2025 meeting?",
+                "eventId": "16085",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "13411284055273560855537595688801764123705139415061660246624128667183605973730",
+                "endDate": "2025-12-10",
+                "negativeRisk": true
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(round_trip(&position), position);
+        assert_eq!(position.avg_price, dec!(0.3421));
+    }
+
+    #[test]
+    fn test_trade_serde_round_trip() {
+        let trade: Trade = serde_json::from_str(
+            r#"{
+                "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                "side": "BUY",
+                "asset": "87769991026114894163580777793845523168226980076553814689875238288185044414090",
+                "conditionId": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+                "size": 120,
+                "price": 0.345,
+                "timestamp": 1760712345,
+                "title": "Fed decreases interest rates by 25 bps after December 2025 meeting?",
+                "slug": "fed-decreases-interest-rates-by-25-bps-after-december-2025-meeting",
+                "icon": "https://polymarket-upload.s3.us-east-2.amazonaws.com/fed.png",
+                "eventSlug": "fed-decision-in-december",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "name": "trader",
+                "pseudonym": "Quiet-Heron",
+                "bio": "",
+                "profileImage": "",
+                "profileImageOptimized": "",
+                "transactionHash": "0x9c1b7d3e5a6f8e2c4b0d1a3e5f7092b4c6d8e0f1a2b3c4d5e6f708192a3b4c5d"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(round_trip(&trade), trade);
+    }
+
+    #[test]
+    fn test_activity_without_side_round_trips() {
+        // Redemptions come back with an empty side
+        let activity: Activity = serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "timestamp": 1760712345,
+            "conditionId": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "type": "REDEEM",
+            "size": 100,
+            "usdcSize": 100,
+            "transactionHash": "0x9c1b7d3e5a6f8e2c4b0d1a3e5f7092b4c6d8e0f1a2b3c4d5e6f708192a3b4c5d",
+            "price": 0,
+            "asset": "",
+            "side": "",
+            "outcomeIndex": 999,
+            "title": "Fed decision in December?",
+            "slug": "fed-decision-in-december",
+            "icon": "",
+            "eventSlug": "fed-decision-in-december",
+            "outcome": "",
+            "name": "trader"
+        }))
+        .unwrap();
+
+        assert_eq!(activity.side, None);
+        assert_eq!(round_trip(&activity), activity);
+    }
+
+    #[test]
+    fn test_clob_trade_serde_round_trip() {
+        let trade: ClobTrade = serde_json::from_value(serde_json::json!({
+            "id": "28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e",
+            "taker_order_id": "0x06bc63e346ed4ceddce9efd6b3af37c8f8f440c92fe7da6b2d0f9e4ccbc50c42",
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "side": "BUY",
+            "size": "40",
+            "fee_rate_bps": "0",
+            "price": "0.51",
+            "status": "CONFIRMED",
+            "match_time": "1700000000",
+            "last_update": "1700000010",
+            "outcome": "Yes",
+            "bucket_index": 0,
+            "owner": "f4f247b7-4ac7-ff29-a152-04fda0a8755a",
+            "maker_address": "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+            "maker_orders": [{
+                "order_id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+                "owner": "a1b2c3d4-0000-0000-0000-000000000000",
+                "maker_address": "0x9d84ce0306f8551e02efef1680475fc0f1dc1344",
+                "matched_amount": "40",
+                "price": "0.51",
+                "fee_rate_bps": "0",
+                "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+                "outcome": "Yes",
+                "side": "SELL"
+            }],
+            "transaction_hash": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+            "trader_side": "TAKER"
+        }))
+        .unwrap();
+
+        assert_eq!(round_trip(&trade), trade);
+    }
+}
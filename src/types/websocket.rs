@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::order::PriceLevel;
 use super::Side;
@@ -9,7 +10,10 @@ use super::Side;
 // ============================================================================
 
 /// Websocket event from the market stream
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Events are told apart by their `event_type`. Types this crate does not know
+/// yet are kept as [`WsEvent::Unknown`] instead of failing to parse.
+#[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum WsEvent {
     /// Emitted When: First subscribed to a market / when there is a trade that affects the book
@@ -20,6 +24,31 @@ pub enum WsEvent {
     LastTradePrice(LastTradePriceEvent),
     /// Emitted When: The minimum tick size of the market changes. This happens when the book’s price reaches the limits: price > 0.96 or price < 0.04
     TickSizeChange(TickSizeChangeEvent),
+    /// Event with an unrecognized `event_type`, as received
+    Unknown(serde_json::Value),
+    /// Reply to a keep-alive `PING`, as a text `PONG` or a pong frame
+    ///
+    /// Carries no market data. It is yielded so that
+    /// [`ReconnectConfig::idle_timeout`](crate::websocket::ReconnectConfig::idle_timeout)
+    /// can tell a quiet connection from a dead one.
+    Pong,
+}
+
+impl<'de> Deserialize<'de> for WsEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event = match value.get("event_type").and_then(|t| t.as_str()) {
+            Some("book") => serde_json::from_value(value).map(WsEvent::Book),
+            Some("price_change") => serde_json::from_value(value).map(WsEvent::PriceChange),
+            Some("last_trade_price") => serde_json::from_value(value).map(WsEvent::LastTradePrice),
+            Some("tick_size_change") => serde_json::from_value(value).map(WsEvent::TickSizeChange),
+            _ => return Ok(WsEvent::Unknown(value)),
+        };
+        event.map_err(D::Error::custom)
+    }
 }
 
 /// Full order book snapshot event
@@ -74,6 +103,23 @@ pub struct PriceChange {
     /// New size at this price level (0 means remove the level)
     #[serde(with = "rust_decimal::serde::str")]
     pub size: Decimal,
+    /// Hash of the asset's order book after this change (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Best bid of the asset after this change (optional)
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub best_bid: Option<Decimal>,
+    /// Best ask of the asset after this change (optional)
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub best_ask: Option<Decimal>,
 }
 
 /// Last trade price event
@@ -128,6 +174,7 @@ pub struct TickSizeChangeEvent {
 /// Websocket event from the authenticated user stream
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserWsEvent {
     /// Trade execution event
     Trade(TradeEvent),
@@ -255,6 +302,38 @@ pub struct MarketSubscription {
     pub assets_ids: Vec<String>,
 }
 
+/// Level of detail kept from the market websocket
+///
+/// Not part of the subscription payload: the server always sends the full
+/// feed and the summary mode only trims it client-side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MarketSubscriptionMode {
+    /// Full order book depth, price changes and trades
+    #[default]
+    Full,
+    /// Top of book, price changes and trades only
+    Summary,
+}
+
+/// Subscription operation for updating an open market websocket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionOperation {
+    /// Start receiving events for the assets
+    Subscribe,
+    /// Stop receiving events for the assets
+    Unsubscribe,
+}
+
+/// Message adding or removing assets on an open market websocket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSubscriptionUpdate {
+    /// List of asset/token IDs to add or remove
+    pub assets_ids: Vec<String>,
+    /// Whether the assets are added or removed
+    pub operation: SubscriptionOperation,
+}
+
 /// Authentication message for user websocket
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct UserAuthentication {
@@ -263,6 +342,9 @@ pub struct UserAuthentication {
     pub msg_type: String,
     /// Authentication credentials
     pub auth: AuthCredentials,
+    /// Condition IDs of the markets to receive events for (all markets if empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markets: Vec<String>,
 }
 
 /// Authentication credentials for user websocket
@@ -287,6 +369,105 @@ impl UserAuthentication {
                 secret,
                 passphrase,
             },
+            markets: Vec::new(),
         }
     }
+
+    /// Only receive events for the given markets (condition IDs)
+    pub fn with_markets(mut self, markets: Vec<String>) -> Self {
+        self.markets = markets;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_user_trade_event() {
+        let json = r#"{
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "event_type": "trade",
+            "id": "28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e",
+            "last_update": "1672290701",
+            "maker_orders": [
+                {
+                    "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+                    "matched_amount": "10",
+                    "order_id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+                    "outcome": "YES",
+                    "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+                    "price": "0.57",
+                    "maker_address": "0x8d1d8a2e2c9d3a63ba6d9a1f4b8a0c8f4c7e1a7b"
+                }
+            ],
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "matchtime": "1672290701",
+            "outcome": "YES",
+            "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "price": "0.57",
+            "side": "BUY",
+            "size": "10",
+            "status": "MATCHED",
+            "taker_order_id": "0x06bc63e346ed4ceddce9efd6b3af37c8f8f440c92fe7da6b2d0f9e4ccbc50c42",
+            "timestamp": "1672290701",
+            "trade_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "type": "TRADE"
+        }"#;
+
+        let event: UserWsEvent = serde_json::from_str(json).unwrap();
+        let UserWsEvent::Trade(trade) = event else {
+            panic!("expected trade event");
+        };
+        assert_eq!(trade.status, TradeStatus::Matched);
+        assert_eq!(trade.price, dec!(0.57));
+        assert_eq!(trade.maker_orders.len(), 1);
+        assert_eq!(trade.maker_orders[0].matched_amount, dec!(10));
+    }
+
+    #[test]
+    fn test_parse_user_order_event() {
+        let json = r#"{
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "associate_trades": null,
+            "event_type": "order",
+            "id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "order_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "original_size": "10",
+            "outcome": "YES",
+            "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "price": "0.57",
+            "side": "SELL",
+            "size_matched": "0",
+            "timestamp": "1672290687",
+            "type": "PLACEMENT",
+            "order_type": "GTC",
+            "status": "LIVE",
+            "maker_address": "0x8d1d8a2e2c9d3a63ba6d9a1f4b8a0c8f4c7e1a7b"
+        }"#;
+
+        let event: UserWsEvent = serde_json::from_str(json).unwrap();
+        let UserWsEvent::Order(order) = event else {
+            panic!("expected order event");
+        };
+        assert_eq!(order.order_event_type, "PLACEMENT");
+        assert_eq!(order.original_size, dec!(10));
+        assert_eq!(order.size_matched, dec!(0));
+    }
+
+    #[test]
+    fn test_user_authentication_markets() {
+        let auth = UserAuthentication::new("key".into(), "secret".into(), "pass".into());
+        let json = serde_json::to_value(&auth).unwrap();
+        assert!(json.get("markets").is_none());
+
+        let auth = auth.with_markets(vec!["0xabc".to_string()]);
+        let json = serde_json::to_value(&auth).unwrap();
+        assert_eq!(json["type"], "user");
+        assert_eq!(json["auth"]["apiKey"], "key");
+        assert_eq!(json["markets"], serde_json::json!(["0xabc"]));
+    }
 }
@@ -3,11 +3,27 @@ use base64::{engine::general_purpose::URL_SAFE, Engine};
 use hmac::{Hmac, Mac};
 use serde::Serialize;
 use sha2::Sha256;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+use web_time::{SystemTime, UNIX_EPOCH};
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Wait for `duration` without blocking the executor
+///
+/// Uses a browser timer on wasm32, where Tokio's timer is not available.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
 /// Get current Unix timestamp in seconds
+///
+/// Reads the browser clock on wasm32, where `std::time::SystemTime` panics.
 pub fn get_current_unix_time_secs() -> Result<u64> {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
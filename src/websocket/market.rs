@@ -1,31 +1,150 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch, RwLock};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
 
+use super::config::WsConfig;
+use super::stream::{ReconnectConfig, ReconnectingStream};
+use crate::config::hosts;
 use crate::error::{Error, Result};
-use crate::types::{MarketSubscription, WsEvent};
+use crate::types::{
+    BookEvent, MarketSubscription, MarketSubscriptionMode, MarketSubscriptionUpdate,
+    SubscriptionOperation, WsEvent,
+};
 
-/// Handle for querying WebSocket subscription state
-///
-/// This handle provides read-only access to the current token IDs
-/// being subscribed to.
+/// State of a WebSocket connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WssConnectionState {
+    /// Connected and streaming events
+    Connected,
+    /// Connection lost, waiting to reconnect or connecting again
+    Reconnecting,
+    /// Closed, either explicitly or by the server; no more events follow
+    Closed,
+}
+
+/// Handle for managing the subscriptions of an open market WebSocket
 ///
-/// **Note**: Polymarket does not support updating subscriptions on an
-/// existing WebSocket connection. To change subscriptions, you must
-/// close the connection and create a new one with the updated token list.
+/// The handle tracks the token IDs currently subscribed to and can add or
+/// remove tokens on the live connection without reconnecting, so order book
+/// state for the other tokens is kept. It can be cloned to manage or
+/// [`close`](Self::close) the connection from another task.
 #[derive(Clone)]
 pub struct SubscriptionHandle {
     /// Shared state containing current token IDs
     current_tokens: Arc<RwLock<Vec<String>>>,
+    /// Messages to send on the connection
+    commands: mpsc::UnboundedSender<Message>,
+    /// Connection state, shared with the event stream
+    state: Arc<watch::Sender<WssConnectionState>>,
 }
 
 impl SubscriptionHandle {
+    /// Get the state of the connection
+    ///
+    /// The connection of a handle is never re-established, so this is either
+    /// [`Connected`](WssConnectionState::Connected) or
+    /// [`Closed`](WssConnectionState::Closed).
+    pub fn state(&self) -> WssConnectionState {
+        *self.state.borrow()
+    }
+
+    /// Close the connection gracefully
+    ///
+    /// Sends a normal close frame and ends the event stream, which then yields
+    /// `None` instead of an error. Closing an already closed connection does
+    /// nothing.
+    pub fn close(&self) {
+        if self.state.send_replace(WssConnectionState::Closed) == WssConnectionState::Closed {
+            return;
+        }
+
+        let frame = CloseFrame {
+            code: CloseCode::Normal,
+            reason: "".into(),
+        };
+        // The writer task is already gone if the connection dropped
+        let _ = self.commands.send(Message::Close(Some(frame)));
+    }
+
     /// Get the current token IDs being subscribed to
     pub async fn current_tokens(&self) -> Vec<String> {
         self.current_tokens.read().await.clone()
     }
+
+    /// Subscribe to additional token IDs on the open connection
+    ///
+    /// Tokens that are already subscribed are skipped; if nothing is left,
+    /// no message is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConnectionClosed`] if the event stream has been dropped
+    /// or the connection is gone.
+    pub async fn add_tokens<T: AsRef<str>>(&self, token_ids: &[T]) -> Result<()> {
+        let mut current = self.current_tokens.write().await;
+
+        let mut added: Vec<String> = Vec::new();
+        for token_id in token_ids {
+            let token_id = token_id.as_ref();
+            if !current.iter().any(|t| t == token_id) && !added.iter().any(|t| t == token_id) {
+                added.push(token_id.to_string());
+            }
+        }
+
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        self.send_update(added.clone(), SubscriptionOperation::Subscribe)?;
+        current.extend(added);
+        Ok(())
+    }
+
+    /// Unsubscribe from token IDs on the open connection
+    ///
+    /// Tokens that are not subscribed are skipped; if nothing is left,
+    /// no message is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConnectionClosed`] if the event stream has been dropped
+    /// or the connection is gone.
+    pub async fn remove_tokens<T: AsRef<str>>(&self, token_ids: &[T]) -> Result<()> {
+        let mut current = self.current_tokens.write().await;
+
+        let mut removed: Vec<String> = Vec::new();
+        for token_id in token_ids {
+            let token_id = token_id.as_ref();
+            if current.iter().any(|t| t == token_id) && !removed.iter().any(|t| t == token_id) {
+                removed.push(token_id.to_string());
+            }
+        }
+
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        self.send_update(removed.clone(), SubscriptionOperation::Unsubscribe)?;
+        current.retain(|t| !removed.contains(t));
+        Ok(())
+    }
+
+    fn send_update(&self, assets_ids: Vec<String>, operation: SubscriptionOperation) -> Result<()> {
+        let update = MarketSubscriptionUpdate {
+            assets_ids,
+            operation,
+        };
+        let msg = serde_json::to_string(&update)?;
+
+        self.commands
+            .send(Message::Text(msg))
+            .map_err(|_| Error::ConnectionClosed)
+    }
 }
 
 /// WebSocket client for streaming market data (order book updates)
@@ -36,114 +155,222 @@ impl SubscriptionHandle {
 /// # Connection Management
 ///
 /// The Polymarket WebSocket server will disconnect idle connections after 1-2 minutes.
-/// The Python client uses `ping_interval=5` to send keep-alive pings every 5 seconds.
+/// Polymarket documents a text `PING` message as the keep-alive, which the server
+/// answers with `PONG`.
 ///
 /// For Rust, the recommended approach is to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
 /// which automatically handles connection resets and reconnects with exponential backoff.
-/// This is more robust than manual ping/pong management.
+/// Keep-alive pings can additionally be enabled with [`with_ping_interval`](MarketWsClient::with_ping_interval).
+/// Their replies are yielded as [`WsEvent::Pong`], so with a ping interval shorter than
+/// [`ReconnectConfig::idle_timeout`](crate::websocket::ReconnectConfig::idle_timeout)
+/// a quiet market keeps its connection and only a dead one is reconnected.
 #[derive(Debug, Clone)]
 pub struct MarketWsClient {
     ws_url: String,
+    ping_interval: Option<Duration>,
+    config: WsConfig,
 }
 
-/// Parse a WebSocket message into a WsEvent
+/// Parse a WebSocket message into WsEvents
 ///
 /// This is a helper function that handles the parsing logic shared by both
-/// subscribe() and subscribe_with_handle() methods.
+/// subscribe() and subscribe_with_handle() methods. A frame can carry several
+/// events, e.g. the book snapshots for every subscribed asset.
 fn parse_ws_message(
     msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
+) -> Vec<Result<WsEvent>> {
+    let events = decode_ws_message(msg);
+    for event in &events {
+        if let Ok(WsEvent::Unknown(value)) = event {
+            log::debug!(
+                "Unrecognized market event type: {}",
+                value.get("event_type").unwrap_or(&serde_json::Value::Null)
+            );
+        }
+    }
+    events
+}
+
+fn decode_ws_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<WsEvent>> {
     match msg {
         Ok(Message::Text(text)) => {
             // Skip empty or whitespace-only messages
             let trimmed = text.trim();
             if trimmed.is_empty() {
-                return None;
+                return Vec::new();
             }
 
-            // Skip PING/PONG messages sent as text (some servers do this)
-            if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
+            // Keep-alives are sent as text: the server answers our PING with PONG
+            if trimmed.eq_ignore_ascii_case("pong") {
+                return vec![Ok(WsEvent::Pong)];
+            }
+            if trimmed.eq_ignore_ascii_case("ping") {
+                return Vec::new();
             }
 
-            // The server can send either a single object or an array
-            // Try to parse as array first
+            // The server can send either a single object or an array of events,
+            // such as one book snapshot per subscribed asset
             if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
+                return events
+                    .into_iter()
+                    .map(|event| {
+                        serde_json::from_value::<WsEvent>(event)
+                            .map_err(|e| Error::deserialization(e, &text))
+                    })
+                    .collect();
             }
 
             // Try parsing as single object
             match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
+                Ok(event) => vec![Ok(event)],
                 Err(e) => {
                     // Log unexpected message format for debugging
                     log::warn!(
                         "Unexpected WebSocket message (first 200 chars): {}",
                         &text.chars().take(200).collect::<String>()
                     );
-                    Some(Err(Error::Json(e)))
+                    vec![Err(Error::deserialization(e, &text))]
                 }
             }
         }
         Ok(Message::Close(_)) => {
             // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
+            vec![Err(Error::ConnectionClosed)]
         }
-        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-            // Ignore ping/pong frames (handled automatically)
-            None
+        Ok(Message::Pong(_)) => vec![Ok(WsEvent::Pong)],
+        Ok(Message::Ping(_)) => {
+            // Answered automatically
+            Vec::new()
         }
         Ok(Message::Binary(_)) => {
             // Unexpected binary message
-            Some(Err(Error::WebSocket(
+            vec![Err(Error::WebSocket(
                 "Unexpected binary message".to_string(),
-            )))
+            ))]
         }
         Ok(Message::Frame(_)) => {
             // Raw frame (shouldn't happen)
-            None
+            Vec::new()
         }
         Err(e) => {
             // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
+            vec![Err(Error::WebSocket(e.to_string()))]
         }
     }
 }
 
+/// Forward `commands` to `write` and send a text `PING` every `ping_interval`
+/// until the returned sender is dropped
+fn spawn_writer_task<W>(
+    mut write: W,
+    mut commands: Option<mpsc::UnboundedReceiver<Message>>,
+    ping_interval: Option<Duration>,
+) -> oneshot::Sender<()>
+where
+    W: Sink<Message> + Unpin + Send + 'static,
+{
+    let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+
+    tokio::spawn(async move {
+        let mut ticker = ping_interval.map(tokio::time::interval);
+        if let Some(ticker) = ticker.as_mut() {
+            // The first tick completes immediately
+            ticker.tick().await;
+        }
+
+        loop {
+            let next_command = async {
+                match commands.as_mut() {
+                    Some(commands) => commands.recv().await,
+                    None => std::future::pending().await,
+                }
+            };
+            let next_ping = async {
+                match ticker.as_mut() {
+                    Some(ticker) => ticker.tick().await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                _ = &mut stop_rx => break,
+                command = next_command => match command {
+                    Some(msg) => {
+                        let closing = matches!(msg, Message::Close(_));
+                        if write.send(msg).await.is_err() || closing {
+                            break;
+                        }
+                    }
+                    // Every handle is gone, keep pinging
+                    None => commands = None,
+                },
+                _ = next_ping => {
+                    if write.send(Message::Text("PING".to_string())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let _ = write.close().await;
+    });
+
+    stop_tx
+}
+
 impl MarketWsClient {
     /// Default WebSocket URL for market data
-    const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+    const DEFAULT_WS_URL: &'static str = hosts::MARKET_WS_URL;
+
+    /// Suggested number of tokens per connection for [`subscribe_sharded`](Self::subscribe_sharded)
+    ///
+    /// Large subscriptions on a single connection are slow to receive their
+    /// initial book snapshots; 100 tokens per connection keeps them responsive.
+    pub const DEFAULT_TOKENS_PER_CONNECTION: usize = 100;
 
     /// Create a new market WebSocket client with the default endpoint
     pub fn new() -> Self {
-        Self {
-            ws_url: Self::DEFAULT_WS_URL.to_string(),
-        }
+        Self::with_config(WsConfig::default())
     }
 
     /// Create a new market WebSocket client with a custom endpoint
     pub fn with_url(ws_url: impl Into<String>) -> Self {
+        Self::with_config(WsConfig {
+            custom_url: Some(ws_url.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Create a new market WebSocket client with proxy, TLS and endpoint settings
+    ///
+    /// The default endpoint is used unless `config.custom_url` is set.
+    pub fn with_config(config: WsConfig) -> Self {
         Self {
-            ws_url: ws_url.into(),
+            ws_url: config
+                .custom_url
+                .clone()
+                .unwrap_or_else(|| Self::DEFAULT_WS_URL.to_string()),
+            ping_interval: None,
+            config,
         }
     }
 
-    /// Subscribe to market updates with a handle to query subscription state
+    /// Send a text `PING` every `interval` to keep the connection alive
     ///
-    /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
-    /// to query which token IDs are currently subscribed.
+    /// The server's `PONG` replies are yielded as [`WsEvent::Pong`]. Pinging
+    /// stops when the returned event stream is dropped.
+    pub fn with_ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = Some(interval);
+        self
+    }
+
+    /// Subscribe to market updates with a handle to manage subscriptions
     ///
-    /// **Note**: Polymarket does not support updating subscriptions on an existing connection.
-    /// To change subscriptions, you must close the connection and create a new one.
+    /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
+    /// to query which token IDs are currently subscribed and to add or remove tokens
+    /// without reconnecting.
     ///
     /// # Arguments
     ///
@@ -153,7 +380,7 @@ impl MarketWsClient {
     ///
     /// A tuple containing:
     /// - Stream of [`WsEvent`] items
-    /// - [`SubscriptionHandle`] for querying current subscriptions
+    /// - [`SubscriptionHandle`] for querying and updating subscriptions
     ///
     /// # Events
     ///
@@ -175,7 +402,7 @@ impl MarketWsClient {
         SubscriptionHandle,
     )> {
         // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
+        let ws_stream = self.config.connect(&self.ws_url).await?;
 
         let (write, read) = ws_stream.split();
         let mut write = write;
@@ -193,17 +420,42 @@ impl MarketWsClient {
             .await
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        // Keep the write half for subscription updates and keep-alive pings
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let writer_guard = spawn_writer_task(write, Some(commands_rx), self.ping_interval);
 
         // Create shared state for current tokens
         let current_tokens = Arc::new(RwLock::new(token_ids));
 
+        let state = Arc::new(watch::Sender::new(WssConnectionState::Connected));
+        let mut closed = state.subscribe();
+
         // Create subscription handle
-        let handle = SubscriptionHandle { current_tokens };
+        let handle = SubscriptionHandle {
+            current_tokens,
+            commands,
+            state: state.clone(),
+        };
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        // The stream owns the writer guard, so updates and pings stop once it is dropped
+        let stream = read
+            .flat_map(move |msg| {
+                let _ = &writer_guard;
+                futures_util::stream::iter(parse_ws_message(msg))
+            })
+            .take_until(async move {
+                let _ = closed
+                    .wait_for(|state| *state == WssConnectionState::Closed)
+                    .await;
+            })
+            // Mark the connection closed once the stream ends, however it ended
+            .map(Some)
+            .chain(futures_util::stream::once(async move {
+                state.send_replace(WssConnectionState::Closed);
+                None
+            }))
+            .filter_map(futures_util::future::ready);
 
         Ok((Box::pin(stream), handle))
     }
@@ -215,7 +467,7 @@ impl MarketWsClient {
     ///
     /// **Note:** This method does not support dynamic subscription updates.
     /// Use [`subscribe_with_handle`](Self::subscribe_with_handle) if you need to
-    /// update subscriptions without reconnecting.
+    /// add or remove tokens without reconnecting.
     ///
     /// # Arguments
     ///
@@ -236,9 +488,109 @@ impl MarketWsClient {
     pub async fn subscribe(
         &self,
         token_ids: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        self.subscribe_with_mode(token_ids, MarketSubscriptionMode::Full)
+            .await
+    }
+
+    /// Subscribe to lightweight market updates for the specified token IDs
+    ///
+    /// Intended for dashboards that track prices for many tokens without the
+    /// full order book depth. The market channel has no lighter feed, so the
+    /// subscription message is the same as for [`subscribe`](Self::subscribe)
+    /// and the depth is trimmed client-side. This saves work for the consumer,
+    /// not bandwidth.
+    ///
+    /// # Events
+    ///
+    /// The stream yields the same event types as [`subscribe`](Self::subscribe),
+    /// but [`WsEvent::Book`] snapshots only contain the best bid and best ask.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails
+    /// - The subscription message cannot be sent
+    pub async fn subscribe_summary(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        self.subscribe_with_mode(token_ids, MarketSubscriptionMode::Summary)
+            .await
+    }
+
+    /// Subscribe to many tokens over several connections
+    ///
+    /// Splits `token_ids` into chunks of at most `per_conn_limit` tokens,
+    /// opens one connection per chunk and merges their events into a single
+    /// stream. Pass [`DEFAULT_TOKENS_PER_CONNECTION`](Self::DEFAULT_TOKENS_PER_CONNECTION)
+    /// unless you have a reason to pick a different size.
+    ///
+    /// Each shard is a [`ReconnectingStream`] with the default
+    /// [`ReconnectConfig`], so a dropped connection only reconnects its own
+    /// chunk and the other shards keep streaming. Connections are opened when
+    /// the stream is first polled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `per_conn_limit` is zero.
+    /// Connection errors are yielded by the stream.
+    pub fn subscribe_sharded(
+        &self,
+        token_ids: Vec<String>,
+        per_conn_limit: usize,
+    ) -> Result<impl Stream<Item = Result<WsEvent>> + Send> {
+        self.subscribe_sharded_with_config(token_ids, per_conn_limit, ReconnectConfig::default())
+    }
+
+    /// Subscribe to many tokens over several connections with a custom
+    /// reconnect configuration
+    ///
+    /// Same as [`subscribe_sharded`](Self::subscribe_sharded), but every shard
+    /// reconnects according to `config`. If a shard gives up after
+    /// [`ReconnectConfig::max_attempts`], its [`Error::ReconnectFailed`] is
+    /// yielded and the merged stream ends, since that shard's tokens are no
+    /// longer covered.
+    pub fn subscribe_sharded_with_config(
+        &self,
+        token_ids: Vec<String>,
+        per_conn_limit: usize,
+        config: ReconnectConfig,
+    ) -> Result<impl Stream<Item = Result<WsEvent>> + Send> {
+        let shards = shard_tokens(token_ids, per_conn_limit)?;
+
+        let streams = shards.into_iter().map(|shard| {
+            let client = self.clone();
+            Box::pin(ReconnectingStream::new(config.clone(), move || {
+                let client = client.clone();
+                let shard = shard.clone();
+                async move { client.subscribe(shard).await }
+            }))
+        });
+
+        // End the merged stream after the first shard gives up, without
+        // waiting for another item from the remaining shards
+        let merged = futures_util::stream::unfold(
+            (futures_util::stream::select_all(streams), false),
+            |(mut streams, gave_up)| async move {
+                if gave_up {
+                    return None;
+                }
+                let item = streams.next().await?;
+                let gave_up = matches!(item, Err(Error::ReconnectFailed { .. }));
+                Some((item, (streams, gave_up)))
+            },
+        );
+        Ok(Box::pin(merged))
+    }
+
+    async fn subscribe_with_mode(
+        &self,
+        token_ids: Vec<String>,
+        mode: MarketSubscriptionMode,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
         // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
+        let ws_stream = self.config.connect(&self.ws_url).await?;
 
         let (write, read) = ws_stream.split();
         let mut write = write;
@@ -256,16 +608,58 @@ impl MarketWsClient {
             .await
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        // Keep the write half for keep-alive pings, otherwise drop it since we
+        // don't need to send any more messages
+        let ping_guard = self
+            .ping_interval
+            .map(|interval| spawn_writer_task(write, None, Some(interval)));
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        // The stream owns the ping guard, so pinging stops once it is dropped
+        let stream = read.flat_map(move |msg| {
+            let _ = &ping_guard;
+            let events = parse_ws_message(msg)
+                .into_iter()
+                .map(move |event| match mode {
+                    MarketSubscriptionMode::Full => event,
+                    MarketSubscriptionMode::Summary => event.map(top_of_book),
+                });
+            futures_util::stream::iter(events)
+        });
 
         Ok(Box::pin(stream))
     }
 }
 
+/// Split token IDs into chunks of at most `per_conn_limit`, one per connection
+fn shard_tokens(token_ids: Vec<String>, per_conn_limit: usize) -> Result<Vec<Vec<String>>> {
+    if per_conn_limit == 0 {
+        return Err(Error::InvalidParameter(
+            "per_conn_limit must be greater than zero".to_string(),
+        ));
+    }
+    Ok(token_ids
+        .chunks(per_conn_limit)
+        .map(|chunk| chunk.to_vec())
+        .collect())
+}
+
+/// Trim book snapshots to the best bid and best ask
+fn top_of_book(event: WsEvent) -> WsEvent {
+    match event {
+        WsEvent::Book(book) => {
+            let best_bid = book.bids.iter().max_by_key(|level| level.price).cloned();
+            let best_ask = book.asks.iter().min_by_key(|level| level.price).cloned();
+            WsEvent::Book(BookEvent {
+                bids: best_bid.into_iter().collect(),
+                asks: best_ask.into_iter().collect(),
+                ..book
+            })
+        }
+        other => other,
+    }
+}
+
 impl Default for MarketWsClient {
     fn default() -> Self {
         Self::new()
@@ -276,6 +670,77 @@ impl Default for MarketWsClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_unknown_event_type_is_not_an_error() {
+        let text = r#"{"event_type":"new_thing","market":"0xabc","foo":1}"#;
+        let mut events = parse_ws_message(Ok(Message::Text(text.into())));
+        assert_eq!(events.len(), 1);
+        match events.remove(0).unwrap() {
+            WsEvent::Unknown(value) => assert_eq!(value["event_type"], "new_thing"),
+            other => panic!("expected an unknown event, got {:?}", other),
+        }
+
+        // A known event type with a malformed payload is still an error
+        let text = r#"{"event_type":"book","market":"0xabc"}"#;
+        assert!(matches!(
+            parse_ws_message(Ok(Message::Text(text.into()))).as_slice(),
+            [Err(Error::Deserialization { .. })]
+        ));
+    }
+
+    fn book_json(asset_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "event_type": "book",
+            "market": "0xabc",
+            "asset_id": asset_id,
+            "timestamp": "1",
+            "hash": "h",
+            "bids": [{ "price": "0.48", "size": "10" }],
+            "asks": [{ "price": "0.52", "size": "4" }]
+        })
+    }
+
+    #[test]
+    fn test_array_frame_yields_every_event() {
+        let text = serde_json::json!([book_json("1"), book_json("2"), book_json("3")]).to_string();
+        let assets: Vec<_> = parse_ws_message(Ok(Message::Text(text)))
+            .into_iter()
+            .map(|event| match event.unwrap() {
+                WsEvent::Book(book) => book.asset_id,
+                other => panic!("expected a book, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(assets, vec!["1", "2", "3"]);
+
+        // A malformed element does not hide the others
+        let text = serde_json::json!([book_json("1"), { "event_type": "book" }]).to_string();
+        let events = parse_ws_message(Ok(Message::Text(text)));
+        assert!(matches!(
+            events.as_slice(),
+            [Ok(WsEvent::Book(_)), Err(Error::Deserialization { .. })]
+        ));
+
+        assert!(parse_ws_message(Ok(Message::Text("[]".into()))).is_empty());
+    }
+
+    #[test]
+    fn test_shard_tokens() {
+        let token_ids: Vec<String> = (0..250).map(|i| i.to_string()).collect();
+        let shards = shard_tokens(token_ids, 100).unwrap();
+        assert_eq!(shards.len(), 3);
+        assert_eq!(
+            shards.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![100, 100, 50]
+        );
+        assert_eq!(shards[2][0], "200");
+
+        assert!(shard_tokens(Vec::new(), 100).unwrap().is_empty());
+        assert!(matches!(
+            shard_tokens(vec!["1".to_string()], 0),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
     #[test]
     fn test_client_creation() {
         let client = MarketWsClient::new();
@@ -288,4 +753,459 @@ mod tests {
         let client = MarketWsClient::with_url(custom_url);
         assert_eq!(client.ws_url, custom_url);
     }
+
+    #[tokio::test]
+    async fn test_config_custom_url() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            match ws.next().await {
+                Some(Ok(Message::Text(text))) => {
+                    serde_json::from_str::<serde_json::Value>(&text).unwrap()
+                }
+                other => panic!("expected a subscription, got {:?}", other),
+            }
+        });
+
+        let client = MarketWsClient::with_config(WsConfig {
+            custom_url: Some(format!("ws://{}", addr)),
+            ..Default::default()
+        });
+        let _stream = client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        let subscription = tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription["assets_ids"], serde_json::json!(["123"]));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_sharded_opens_one_connection_per_shard() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let (subscriptions_tx, mut subscriptions) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let subscriptions_tx = subscriptions_tx.clone();
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    if let Some(Ok(Message::Text(text))) = ws.next().await {
+                        let subscription: serde_json::Value = serde_json::from_str(&text).unwrap();
+                        let count = subscription["assets_ids"].as_array().unwrap().len();
+                        subscriptions_tx.send(count).unwrap();
+                    }
+                    // Keep the connection open
+                    while ws.next().await.is_some() {}
+                });
+            }
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let token_ids: Vec<String> = (0..250).map(|i| i.to_string()).collect();
+        let mut stream = client.subscribe_sharded(token_ids, 100).unwrap();
+        let poller = tokio::spawn(async move { while stream.next().await.is_some() {} });
+
+        let mut counts = Vec::new();
+        for _ in 0..3 {
+            let count = tokio::time::timeout(Duration::from_secs(2), subscriptions.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            counts.push(count);
+        }
+        counts.sort_unstable();
+        assert_eq!(counts, vec![50, 100, 100]);
+        poller.abort();
+    }
+
+    #[tokio::test]
+    async fn test_sharded_subscription_gets_every_snapshot_in_an_array() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Answers each subscription with one array of snapshots, like the market channel
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    if let Some(Ok(Message::Text(text))) = ws.next().await {
+                        let subscription: serde_json::Value = serde_json::from_str(&text).unwrap();
+                        let books: Vec<_> = subscription["assets_ids"]
+                            .as_array()
+                            .unwrap()
+                            .iter()
+                            .map(|id| book_json(id.as_str().unwrap()))
+                            .collect();
+                        let frame = serde_json::Value::Array(books).to_string();
+                        ws.send(Message::Text(frame)).await.unwrap();
+                    }
+                    while ws.next().await.is_some() {}
+                });
+            }
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let token_ids: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let stream = client.subscribe_sharded(token_ids, 3).unwrap();
+
+        let events: Vec<_> = tokio::time::timeout(Duration::from_secs(2), stream.take(5).collect())
+            .await
+            .unwrap();
+        let mut assets: Vec<String> = events
+            .into_iter()
+            .map(|event| match event.unwrap() {
+                WsEvent::Book(book) => book.asset_id,
+                other => panic!("expected a book, got {:?}", other),
+            })
+            .collect();
+        assets.sort();
+        assert_eq!(assets, vec!["0", "1", "2", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_sharded_ends_when_a_shard_gives_up() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    if let Some(Ok(Message::Text(text))) = ws.next().await {
+                        // Drop the shard holding token "0" right away, keep the other
+                        if text.contains(r#""0""#) {
+                            return;
+                        }
+                    }
+                    while ws.next().await.is_some() {}
+                });
+            }
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(20),
+            max_attempts: Some(2),
+            jitter: 0.0,
+            ..Default::default()
+        };
+        let token_ids = vec!["0".to_string(), "1".to_string()];
+        let stream = client
+            .subscribe_sharded_with_config(token_ids, 1, config)
+            .unwrap();
+
+        let items: Vec<_> = tokio::time::timeout(Duration::from_secs(2), stream.collect())
+            .await
+            .expect("merged stream should end once a shard gives up");
+        assert!(matches!(
+            items.last(),
+            Some(Err(Error::ReconnectFailed { attempts: 2, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ping_interval_sends_text_pings() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+            let mut saw_subscription = false;
+            while let Some(Ok(msg)) = ws.next().await {
+                match msg {
+                    Message::Text(text) if text == "PING" => return saw_subscription,
+                    Message::Text(_) => saw_subscription = true,
+                    _ => {}
+                }
+            }
+            false
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr))
+            .with_ping_interval(Duration::from_millis(20));
+        let _stream = client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        let saw_ping = tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saw_ping);
+    }
+
+    #[test]
+    fn test_pong_replies_are_yielded() {
+        for msg in [Message::Text("PONG".into()), Message::Pong(Vec::new())] {
+            assert!(matches!(
+                parse_ws_message(Ok(msg)).as_slice(),
+                [Ok(WsEvent::Pong)]
+            ));
+        }
+        assert!(parse_ws_message(Ok(Message::Ping(Vec::new()))).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_quiet_answering_server_is_not_reconnected() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Sends no market data, only answers keep-alives
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    while let Some(Ok(msg)) = ws.next().await {
+                        if matches!(&msg, Message::Text(text) if text == "PING") {
+                            let _ = ws.send(Message::Text("PONG".to_string())).await;
+                        }
+                    }
+                });
+            }
+        });
+
+        let connects = Arc::new(AtomicUsize::new(0));
+        let client = MarketWsClient::with_url(format!("ws://{}", addr))
+            .with_ping_interval(Duration::from_millis(20));
+        let config = ReconnectConfig {
+            idle_timeout: Some(Duration::from_millis(100)),
+            jitter: 0.0,
+            ..Default::default()
+        };
+        let counter = connects.clone();
+        let stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { client.subscribe(vec!["1".to_string()]).await }
+        });
+
+        // Several idle timeouts pass without market data
+        let items: Vec<_> = stream
+            .take_until(tokio::time::sleep(Duration::from_millis(500)))
+            .collect()
+            .await;
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+        assert!(items.len() >= 5);
+        assert!(items.iter().all(|item| matches!(item, Ok(WsEvent::Pong))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_sends_subscription_updates() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+            let mut frames = Vec::new();
+            while let Some(Ok(msg)) = ws.next().await {
+                if let Message::Text(text) = msg {
+                    frames.push(serde_json::from_str::<serde_json::Value>(&text).unwrap());
+                    if frames.len() == 3 {
+                        break;
+                    }
+                }
+            }
+            frames
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let (_stream, handle) = client
+            .subscribe_with_handle(vec!["1".to_string()])
+            .await
+            .unwrap();
+
+        handle.add_tokens(&["1", "2"]).await.unwrap();
+        // Already subscribed, nothing is sent
+        handle.add_tokens(&["2"]).await.unwrap();
+        handle.remove_tokens(&["1", "3"]).await.unwrap();
+
+        assert_eq!(handle.current_tokens().await, vec!["2".to_string()]);
+
+        let frames = tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(frames[0], serde_json::json!({ "assets_ids": ["1"] }));
+        assert_eq!(
+            frames[1],
+            serde_json::json!({ "assets_ids": ["2"], "operation": "subscribe" })
+        );
+        assert_eq!(
+            frames[2],
+            serde_json::json!({ "assets_ids": ["1"], "operation": "unsubscribe" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors_after_stream_dropped() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let (stream, handle) = client
+            .subscribe_with_handle(vec!["1".to_string()])
+            .await
+            .unwrap();
+        drop(stream);
+
+        // Wait for the writer task to stop
+        tokio::time::timeout(Duration::from_secs(2), handle.commands.closed())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            handle.add_tokens(&["2"]).await,
+            Err(Error::ConnectionClosed)
+        ));
+        assert_eq!(handle.current_tokens().await, vec!["1".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_close_ends_stream() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(msg)) = ws.next().await {
+                if let Message::Close(frame) = msg {
+                    return frame.map(|frame| frame.code);
+                }
+            }
+            None
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let (mut stream, handle) = client
+            .subscribe_with_handle(vec!["1".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(handle.state(), WssConnectionState::Connected);
+
+        // Close from another task through a cloned handle
+        let closer = handle.clone();
+        tokio::spawn(async move { closer.close() });
+
+        let next = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert!(next.is_none());
+        assert_eq!(handle.state(), WssConnectionState::Closed);
+
+        let code = tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(code, Some(CloseCode::Normal));
+
+        // The writer task stops after sending the close frame
+        tokio::time::timeout(Duration::from_secs(2), handle.commands.closed())
+            .await
+            .unwrap();
+        handle.close();
+    }
+
+    #[tokio::test]
+    async fn test_subscription_message_per_mode() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let mut frames = Vec::new();
+            for _ in 0..2 {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                if let Some(Ok(Message::Text(text))) = ws.next().await {
+                    frames.push(serde_json::from_str::<serde_json::Value>(&text).unwrap());
+                }
+            }
+            frames
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let _full = client.subscribe(vec!["1".to_string()]).await.unwrap();
+        let _summary = client
+            .subscribe_summary(vec!["2".to_string()])
+            .await
+            .unwrap();
+
+        let frames = tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .unwrap()
+            .unwrap();
+        // The summary mode is client-side only, so both send the same payload
+        assert_eq!(frames[0], serde_json::json!({ "assets_ids": ["1"] }));
+        assert_eq!(frames[1], serde_json::json!({ "assets_ids": ["2"] }));
+    }
+
+    #[tokio::test]
+    async fn test_summary_trims_book_depth() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await;
+            let book = serde_json::json!({
+                "event_type": "book",
+                "market": "0xabc",
+                "asset_id": "1",
+                "timestamp": "1",
+                "hash": "h",
+                "bids": [
+                    { "price": "0.48", "size": "10" },
+                    { "price": "0.50", "size": "5" },
+                    { "price": "0.49", "size": "7" }
+                ],
+                "asks": [
+                    { "price": "0.53", "size": "3" },
+                    { "price": "0.52", "size": "4" }
+                ]
+            });
+            ws.send(Message::Text(book.to_string())).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let mut stream = client
+            .subscribe_summary(vec!["1".to_string()])
+            .await
+            .unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        match event {
+            WsEvent::Book(book) => {
+                assert_eq!(book.bids.len(), 1);
+                assert_eq!(book.bids[0].price.to_string(), "0.50");
+                assert_eq!(book.asks.len(), 1);
+                assert_eq!(book.asks[0].price.to_string(), "0.52");
+            }
+            other => panic!("expected book event, got {:?}", other),
+        }
+    }
 }
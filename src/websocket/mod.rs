@@ -10,16 +10,19 @@
 //! For production use, it's recommended to use [`ReconnectingStream`] to automatically
 //! handle disconnections and reconnect with exponential backoff.
 
+mod config;
 mod market;
 mod stream;
 mod user;
 
-pub use market::{MarketWsClient, SubscriptionHandle};
-pub use stream::{ReconnectConfig, ReconnectingStream};
+pub use config::WsConfig;
+pub use market::{MarketWsClient, SubscriptionHandle, WssConnectionState};
+pub use stream::{ReconnectConfig, ReconnectHook, ReconnectingStream};
 pub use user::UserWsClient;
 
 // Re-export commonly used types for convenience
 pub use crate::types::{
-    BookEvent, LastTradePriceEvent, MarketSubscription, OrderEvent, PriceChange, PriceChangeEvent,
-    PriceLevel, TradeEvent, UserAuthentication, UserWsEvent, WsEvent,
+    BookEvent, LastTradePriceEvent, MarketSubscription, MarketSubscriptionUpdate, OrderEvent,
+    PriceChange, PriceChangeEvent, PriceLevel, SubscriptionOperation, TradeEvent,
+    UserAuthentication, UserWsEvent, WsEvent,
 };
@@ -1,14 +1,24 @@
 use futures_util::Stream;
+use rand::Rng;
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
 use tokio::time::sleep;
 
+use super::market::WssConnectionState;
 use crate::error::{Error, Result};
 
+/// Callback invoked before each reconnection attempt
+///
+/// Receives the error that caused the reconnect and the attempt number
+/// (starting at 1).
+pub type ReconnectHook = Arc<dyn Fn(&Error, u32) + Send + Sync>;
+
 /// Configuration for reconnection behavior
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ReconnectConfig {
     /// Initial delay before first reconnection attempt
     pub initial_delay: Duration,
@@ -18,6 +28,47 @@ pub struct ReconnectConfig {
     pub multiplier: f64,
     /// Maximum number of reconnection attempts (None = infinite)
     pub max_attempts: Option<u32>,
+    /// Reconnect if no item arrives within this window (None = never)
+    ///
+    /// Keep-alive replies count: the market client yields them as
+    /// [`WsEvent::Pong`](crate::types::WsEvent::Pong), so set its
+    /// [ping interval](crate::websocket::MarketWsClient::with_ping_interval)
+    /// below this timeout to keep a quiet but live connection.
+    pub idle_timeout: Option<Duration>,
+    /// Randomize each delay by up to ±`jitter` of its value (0.0 to 1.0)
+    ///
+    /// Spreads out the reconnects of many clients dropped at the same time.
+    /// Values outside the range are clamped, and a delay is never shorter than
+    /// one millisecond.
+    pub jitter: f64,
+    /// Called with the disconnect reason before each reconnection attempt
+    ///
+    /// Useful for metrics and for telling abnormal disconnects apart from idle
+    /// timeouts. See [`with_on_reconnect`](Self::with_on_reconnect).
+    pub on_reconnect: Option<ReconnectHook>,
+}
+
+impl ReconnectConfig {
+    /// Set a callback invoked with the disconnect reason and attempt number
+    /// before each reconnection attempt
+    pub fn with_on_reconnect(mut self, hook: impl Fn(&Error, u32) + Send + Sync + 'static) -> Self {
+        self.on_reconnect = Some(Arc::new(hook));
+        self
+    }
+}
+
+impl fmt::Debug for ReconnectConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReconnectConfig")
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("max_attempts", &self.max_attempts)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("jitter", &self.jitter)
+            .field("on_reconnect", &self.on_reconnect.is_some())
+            .finish()
+    }
 }
 
 impl Default for ReconnectConfig {
@@ -27,6 +78,9 @@ impl Default for ReconnectConfig {
             max_delay: Duration::from_secs(60),
             multiplier: 2.0,
             max_attempts: None,
+            idle_timeout: None,
+            jitter: 0.1,
+            on_reconnect: None,
         }
     }
 }
@@ -34,17 +88,28 @@ impl Default for ReconnectConfig {
 /// Exponential backoff calculator
 #[derive(Debug, Clone)]
 struct ExponentialBackoff {
+    initial_delay: Duration,
     current_delay: Duration,
     max_delay: Duration,
     multiplier: f64,
+    jitter: f64,
 }
 
+/// Shortest delay returned when jitter is applied
+const MIN_JITTERED_DELAY: Duration = Duration::from_millis(1);
+
 impl ExponentialBackoff {
-    fn new(initial_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
+    fn new(initial_delay: Duration, max_delay: Duration, multiplier: f64, jitter: f64) -> Self {
         Self {
+            initial_delay,
             current_delay: initial_delay,
             max_delay,
             multiplier,
+            jitter: if jitter.is_nan() {
+                0.0
+            } else {
+                jitter.clamp(0.0, 1.0)
+            },
         }
     }
 
@@ -55,12 +120,22 @@ impl ExponentialBackoff {
             Duration::from_secs_f64(delay.as_secs_f64() * self.multiplier),
             self.max_delay,
         );
-        delay
+        self.apply_jitter(delay)
+    }
+
+    /// Randomize a delay by ±jitter, keeping it positive
+    fn apply_jitter(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+
+        let factor = 1.0 + rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        Duration::from_secs_f64(delay.as_secs_f64() * factor).max(MIN_JITTERED_DELAY)
     }
 
     /// Reset the backoff to initial delay
     fn reset(&mut self) {
-        self.current_delay = Duration::from_secs(1);
+        self.current_delay = self.initial_delay;
     }
 }
 
@@ -69,15 +144,9 @@ enum StreamState<S, Fut> {
     /// Currently connected and streaming
     Connected(S),
     /// Connection failed, waiting to reconnect
-    Reconnecting {
-        attempts: u32,
-        delay: Duration,
-    },
+    Reconnecting { delay: Duration },
     /// Reconnection in progress
-    Connecting {
-        attempts: u32,
-        future: Option<Pin<Box<Fut>>>,
-    },
+    Connecting { future: Option<Pin<Box<Fut>>> },
     /// Stream has been terminated
     Terminated,
 }
@@ -89,6 +158,18 @@ enum StreamState<S, Fut> {
 /// - Using exponential backoff between reconnection attempts
 /// - Optionally limiting the number of reconnection attempts
 ///
+/// # Termination
+///
+/// With [`ReconnectConfig::max_attempts`] set, the stream yields a final
+/// [`Error::ReconnectFailed`] carrying the attempt count and the last
+/// underlying error once the attempts are used up, and then ends with `None`.
+/// A stream that ends without that error was not given up on after retries.
+///
+/// Attempts count consecutive failures: failed connects, disconnects and
+/// stream errors since the last item received. Receiving an item resets the
+/// count and the backoff, so a connection that keeps failing before
+/// delivering anything backs off and eventually gives up.
+///
 /// # Example
 ///
 /// ```no_run
@@ -131,8 +212,12 @@ where
     config: ReconnectConfig,
     /// Exponential backoff calculator
     backoff: ExponentialBackoff,
+    /// Consecutive failures since the last item received
+    attempts: u32,
     /// Sleep future for reconnection delay
     sleep_future: Option<Pin<Box<tokio::time::Sleep>>>,
+    /// Sleep future that fires when the connection has been idle too long
+    idle_future: Option<Pin<Box<tokio::time::Sleep>>>,
 }
 
 impl<T, S, F, Fut> ReconnectingStream<T, S, F, Fut>
@@ -152,35 +237,69 @@ where
             config.initial_delay,
             config.max_delay,
             config.multiplier,
+            config.jitter,
         );
 
         Self {
             connect_fn,
-            state: StreamState::Connecting {
-                attempts: 0,
-                future: None,
-            },
+            state: StreamState::Connecting { future: None },
             config,
             backoff,
+            attempts: 0,
             sleep_future: None,
+            idle_future: None,
+        }
+    }
+
+    /// Get the state of the underlying connection
+    ///
+    /// Reports [`Reconnecting`](WssConnectionState::Reconnecting) while waiting
+    /// to reconnect and while a connection attempt is in progress, including
+    /// the first one.
+    pub fn connection_state(&self) -> WssConnectionState {
+        match self.state {
+            StreamState::Connected(_) => WssConnectionState::Connected,
+            StreamState::Reconnecting { .. } | StreamState::Connecting { .. } => {
+                WssConnectionState::Reconnecting
+            }
+            StreamState::Terminated => WssConnectionState::Closed,
         }
     }
 
+    /// Restart the idle timer, if an idle timeout is configured
+    fn reset_idle_timer(&mut self) {
+        self.idle_future = self
+            .config
+            .idle_timeout
+            .map(|timeout| Box::pin(sleep(timeout)));
+    }
+
     /// Handle a disconnection and prepare for reconnection
-    fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
+    ///
+    /// Returns `Poll::Ready` with [`Error::ReconnectFailed`] once the attempts
+    /// are used up, `Poll::Pending` otherwise.
+    fn handle_disconnection(&mut self, error: &Error) -> Poll<Option<Result<T>>> {
+        self.idle_future = None;
+        self.attempts += 1;
+        let attempts = self.attempts;
+
         // Check if we've exceeded max attempts
         if let Some(max) = self.config.max_attempts {
             if attempts >= max {
                 self.state = StreamState::Terminated;
                 return Poll::Ready(Some(Err(Error::ReconnectFailed {
                     attempts,
-                    last_error: "Maximum reconnection attempts reached".to_string(),
+                    last_error: error.to_string(),
                 })));
             }
         }
 
+        if let Some(hook) = &self.config.on_reconnect {
+            hook(error, attempts);
+        }
+
         let delay = self.backoff.next_delay();
-        self.state = StreamState::Reconnecting { attempts, delay };
+        self.state = StreamState::Reconnecting { delay };
         self.sleep_future = Some(Box::pin(sleep(delay)));
         Poll::Pending
     }
@@ -201,38 +320,59 @@ where
                     match Pin::new(stream).poll_next(cx) {
                         Poll::Ready(Some(Ok(item))) => {
                             // Successfully received an item, reset backoff
+                            self.attempts = 0;
                             self.backoff.reset();
+                            self.reset_idle_timer();
                             return Poll::Ready(Some(Ok(item)));
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
                             // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            match self.handle_disconnection(&Error::ConnectionClosed) {
+                                Poll::Pending => continue,
+                                ready => return ready,
+                            }
                         }
                         Poll::Ready(Some(Err(e))) => {
-                            // Other error, pass through and prepare to reconnect
-                            let _ = self.handle_disconnection(1);
-                            return Poll::Ready(Some(Err(e)));
+                            // Other error, pass through and prepare to reconnect,
+                            // unless the attempts are used up
+                            return match self.handle_disconnection(&e) {
+                                Poll::Pending => Poll::Ready(Some(Err(e))),
+                                terminal => terminal,
+                            };
                         }
                         Poll::Ready(None) => {
                             // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            match self.handle_disconnection(&Error::ConnectionClosed) {
+                                Poll::Pending => continue,
+                                ready => return ready,
+                            }
                         }
                         Poll::Pending => {
+                            let idle = match self.idle_future.as_mut() {
+                                Some(idle_fut) => idle_fut.as_mut().poll(cx).is_ready(),
+                                None => false,
+                            };
+                            if idle {
+                                // No message within the idle timeout, treat the connection as dead
+                                log::warn!("WebSocket connection idle, reconnecting");
+                                let error =
+                                    Error::WebSocket("No message within idle timeout".to_string());
+                                match self.handle_disconnection(&error) {
+                                    Poll::Pending => continue,
+                                    ready => return ready,
+                                }
+                            }
                             return Poll::Pending;
                         }
                     }
                 }
-                StreamState::Reconnecting { attempts, .. } => {
-                    let attempts = *attempts;
+                StreamState::Reconnecting { .. } => {
                     // Wait for the sleep delay
                     if let Some(mut sleep_fut) = self.sleep_future.take() {
                         match Pin::new(&mut sleep_fut).poll(cx) {
                             Poll::Ready(()) => {
                                 // Delay complete, start connecting
-                                self.state = StreamState::Connecting {
-                                    attempts,
-                                    future: None,
-                                };
+                                self.state = StreamState::Connecting { future: None };
                                 continue;
                             }
                             Poll::Pending => {
@@ -250,8 +390,7 @@ where
                         continue;
                     }
                 }
-                StreamState::Connecting { attempts, future } => {
-                    let current_attempts = *attempts;
+                StreamState::Connecting { future } => {
                     // Get or create the connection future
                     let mut boxed_fut = if let Some(fut) = future.take() {
                         fut
@@ -261,20 +400,22 @@ where
 
                     match boxed_fut.as_mut().poll(cx) {
                         Poll::Ready(Ok(stream)) => {
+                            // Attempts and backoff are reset by the first item, not
+                            // here, so connections that fail at once still back off
                             self.state = StreamState::Connected(stream);
-                            self.backoff.reset();
+                            self.reset_idle_timer();
                             continue;
                         }
-                        Poll::Ready(Err(_e)) => {
+                        Poll::Ready(Err(e)) => {
                             // Connection failed, prepare to reconnect
-                            // Increment attempts (or start at 1 if this is the first attempt)
-                            let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            match self.handle_disconnection(&e) {
+                                Poll::Pending => continue,
+                                ready => return ready,
+                            }
                         }
                         Poll::Pending => {
                             // Store the future for next poll
                             self.state = StreamState::Connecting {
-                                attempts: current_attempts,
                                 future: Some(boxed_fut),
                             };
                             return Poll::Pending;
@@ -295,11 +436,8 @@ mod tests {
 
     #[test]
     fn test_backoff() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(60),
-            2.0,
-        );
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(60), 2.0, 0.0);
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
         assert_eq!(backoff.next_delay(), Duration::from_secs(2));
@@ -309,11 +447,8 @@ mod tests {
 
     #[test]
     fn test_backoff_max() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(5),
-            2.0,
-        );
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(5), 2.0, 0.0);
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
         assert_eq!(backoff.next_delay(), Duration::from_secs(2));
@@ -324,11 +459,8 @@ mod tests {
 
     #[test]
     fn test_backoff_reset() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(60),
-            2.0,
-        );
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(60), 2.0, 0.0);
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
         assert_eq!(backoff.next_delay(), Duration::from_secs(2));
@@ -337,4 +469,232 @@ mod tests {
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_backoff_jitter_bounds() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(4), 2.0, 0.25);
+
+        for base in [1.0, 2.0, 4.0, 4.0] {
+            let delay = backoff.next_delay().as_secs_f64();
+            assert!(
+                delay >= base * 0.75 && delay <= base * 1.25,
+                "{} outside jitter bounds of {}",
+                delay,
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn test_backoff_jitter_never_zero() {
+        // Full jitter can scale a delay down to nothing; it is floored instead
+        for initial_delay in [Duration::ZERO, Duration::from_millis(10)] {
+            let mut backoff = ExponentialBackoff::new(initial_delay, initial_delay, 1.0, 5.0);
+            assert_eq!(backoff.jitter, 1.0);
+            for _ in 0..100 {
+                let delay = backoff.next_delay();
+                assert!(delay >= MIN_JITTERED_DELAY);
+                assert!(delay <= Duration::from_millis(20));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout_triggers_reconnect() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+
+        let connects = Arc::new(AtomicU32::new(0));
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            multiplier: 1.0,
+            max_attempts: None,
+            idle_timeout: Some(Duration::from_millis(50)),
+            jitter: 0.0,
+            on_reconnect: None,
+        };
+
+        let counter = connects.clone();
+        let mut stream = ReconnectingStream::new(config, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            // A connection that never sends anything
+            async { Ok(futures_util::stream::pending::<Result<u32>>()) }
+        });
+
+        let result = tokio::time::timeout(Duration::from_millis(300), stream.next()).await;
+        assert!(result.is_err(), "silent stream should not yield items");
+        assert!(connects.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_idle_timeout_keeps_connection() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+
+        let connects = Arc::new(AtomicU32::new(0));
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+
+        let counter = connects.clone();
+        let mut stream = ReconnectingStream::new(config, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(futures_util::stream::pending::<Result<u32>>()) }
+        });
+
+        let _ = tokio::time::timeout(Duration::from_millis(100), stream.next()).await;
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_connection_state() {
+        use futures_util::StreamExt;
+
+        let config = ReconnectConfig {
+            max_attempts: Some(1),
+            ..Default::default()
+        };
+        let mut stream = ReconnectingStream::new(config, || async {
+            Ok(futures_util::stream::iter(vec![Ok(1u32)]))
+        });
+        assert_eq!(stream.connection_state(), WssConnectionState::Reconnecting);
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(stream.connection_state(), WssConnectionState::Connected);
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ReconnectFailed { .. }))
+        ));
+        assert_eq!(stream.connection_state(), WssConnectionState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_on_reconnect_hook_receives_error_and_attempt() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Mutex;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            ..Default::default()
+        }
+        .with_on_reconnect(move |error, attempt| {
+            recorded.lock().unwrap().push((error.to_string(), attempt));
+        });
+
+        let connects = Arc::new(AtomicU32::new(0));
+        let counter = connects.clone();
+        let mut stream = ReconnectingStream::new(config, move || {
+            let first = counter.fetch_add(1, Ordering::SeqCst) == 0;
+            async move {
+                if first {
+                    Err(Error::WebSocket("connection refused".to_string()))
+                } else {
+                    Ok(futures_util::stream::iter(vec![Ok(7u32)]))
+                }
+            }
+        });
+
+        let item = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .unwrap();
+        assert_eq!(item.unwrap().unwrap(), 7);
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].1, 1);
+        assert!(calls[0].0.contains("connection refused"), "{}", calls[0].0);
+    }
+
+    #[tokio::test]
+    async fn test_max_attempts_yields_terminal_error() {
+        use futures_util::StreamExt;
+
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_attempts: Some(2),
+            ..Default::default()
+        };
+        let mut stream = ReconnectingStream::new(config, || async {
+            Err::<futures_util::stream::Pending<Result<u32>>, _>(Error::WebSocket(
+                "connection refused".to_string(),
+            ))
+        });
+
+        let item = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .unwrap();
+        match item {
+            Some(Err(Error::ReconnectFailed {
+                attempts,
+                last_error,
+            })) => {
+                assert_eq!(attempts, 2);
+                assert!(last_error.contains("connection refused"), "{}", last_error);
+            }
+            other => panic!("expected ReconnectFailed, got {:?}", other),
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failing_connection_stops_after_max_attempts() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Mutex;
+
+        let attempts_seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = attempts_seen.clone();
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_attempts: Some(3),
+            jitter: 0.0,
+            ..Default::default()
+        }
+        .with_on_reconnect(move |_, attempt| recorded.lock().unwrap().push(attempt));
+
+        // Every connection succeeds, then fails with an error before any item
+        let connects = Arc::new(AtomicU32::new(0));
+        let counter = connects.clone();
+        let mut stream = ReconnectingStream::new(config, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok(futures_util::stream::iter(vec![Err::<u32, _>(
+                    Error::WebSocket("protocol error".to_string()),
+                )]))
+            }
+        });
+
+        let items: Vec<_> = tokio::time::timeout(Duration::from_secs(1), stream.by_ref().collect())
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert!(matches!(&items[0], Err(Error::WebSocket(_))));
+        assert!(matches!(&items[1], Err(Error::WebSocket(_))));
+        match &items[2] {
+            Err(Error::ReconnectFailed {
+                attempts,
+                last_error,
+            }) => {
+                assert_eq!(*attempts, 3);
+                assert!(last_error.contains("protocol error"), "{}", last_error);
+            }
+            other => panic!("expected ReconnectFailed, got {:?}", other),
+        }
+        assert_eq!(connects.load(Ordering::SeqCst), 3);
+        assert_eq!(*attempts_seen.lock().unwrap(), vec![1, 2]);
+        assert_eq!(stream.connection_state(), WssConnectionState::Closed);
+    }
 }
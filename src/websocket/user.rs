@@ -2,6 +2,8 @@ use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::stream::{ReconnectConfig, ReconnectingStream};
+use crate::config::hosts;
 use crate::error::{Error, Result};
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 
@@ -19,10 +21,9 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 /// # Example with Auto-Reconnect
 ///
 /// ```no_run
-/// use polymarket_rs::websocket::{UserWsClient, ReconnectConfig, ReconnectingStream};
+/// use polymarket_rs::websocket::{ReconnectConfig, UserWsClient};
 /// use polymarket_rs::types::ApiCreds;
 /// use futures_util::StreamExt;
-/// use std::time::Duration;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -33,20 +34,11 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 ///     );
 ///
 ///     let client = UserWsClient::new();
-///
-///     let config = ReconnectConfig {
-///         initial_delay: Duration::from_secs(1),
-///         max_delay: Duration::from_secs(30),
-///         multiplier: 2.0,
-///         max_attempts: None,
-///     };
-///
-///     let creds_clone = creds.clone();
-///     let mut stream = ReconnectingStream::new(config, move || {
-///         let client = client.clone();
-///         let creds = creds_clone.clone();
-///         async move { client.subscribe_with_creds(&creds).await }
-///     });
+///     let mut stream = client.subscribe_markets_reconnecting(
+///         &creds,
+///         vec!["0xcondition_id".to_string()],
+///         ReconnectConfig::default(),
+///     );
 ///
 ///     while let Some(event) = stream.next().await {
 ///         match event {
@@ -64,7 +56,7 @@ pub struct UserWsClient {
 
 impl UserWsClient {
     /// Default WebSocket URL for user events
-    const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
+    const DEFAULT_WS_URL: &'static str = hosts::USER_WS_URL;
 
     /// Create a new user WebSocket client with the default endpoint
     pub fn new() -> Self {
@@ -165,15 +157,74 @@ impl UserWsClient {
         api_key: String,
         api_secret: String,
         api_passphrase: String,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
+        self.connect(UserAuthentication::new(api_key, api_secret, api_passphrase))
+            .await
+    }
+
+    /// Subscribe to user events for specific markets only
+    ///
+    /// Same as [`subscribe_with_creds`](Self::subscribe_with_creds), but the server only
+    /// sends events for the given markets.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - API credentials (api_key, secret, passphrase)
+    /// * `markets` - Condition IDs of the markets to receive events for
+    pub async fn subscribe_markets_with_creds(
+        &self,
+        creds: &ApiCreds,
+        markets: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
+        let auth = UserAuthentication::new(
+            creds.api_key.clone(),
+            creds.secret.clone(),
+            creds.passphrase.clone(),
+        )
+        .with_markets(markets);
+
+        self.connect(auth).await
+    }
+
+    /// Subscribe to user events for specific markets, reconnecting on failure
+    ///
+    /// Same as [`subscribe_markets_with_creds`](Self::subscribe_markets_with_creds),
+    /// but wrapped in a [`ReconnectingStream`] that reconnects and sends the
+    /// authentication message again according to `config`. Pass an empty
+    /// `markets` to receive events for all markets. The connection is opened
+    /// when the stream is first polled.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - API credentials (api_key, secret, passphrase)
+    /// * `markets` - Condition IDs of the markets to receive events for
+    /// * `config` - Reconnection behavior
+    pub fn subscribe_markets_reconnecting(
+        &self,
+        creds: &ApiCreds,
+        markets: Vec<String>,
+        config: ReconnectConfig,
+    ) -> impl Stream<Item = Result<UserWsEvent>> + Send {
+        let client = self.clone();
+        let creds = creds.clone();
+        ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            let creds = creds.clone();
+            let markets = markets.clone();
+            async move { client.subscribe_markets_with_creds(&creds, markets).await }
+        })
+    }
+
+    /// Connect, authenticate and return the parsed event stream
+    async fn connect(
+        &self,
+        auth: UserAuthentication,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
         // Connect to the WebSocket endpoint
         let (ws_stream, _) = connect_async(&self.ws_url).await?;
 
         let (mut write, read) = ws_stream.split();
 
-        // Create authentication message
-        let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
-
         let auth_msg = serde_json::to_string(&auth)?;
 
         // Send authentication message
@@ -183,61 +234,7 @@ impl UserWsClient {
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
         // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
-
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+        let stream = read.flat_map(|msg| futures_util::stream::iter(parse_user_message(msg)));
 
         Ok(Box::pin(stream))
     }
@@ -249,13 +246,176 @@ impl Default for UserWsClient {
     }
 }
 
+/// Parse a user WebSocket message into UserWsEvents
+///
+/// A frame can carry several events, e.g. every trade of one match.
+fn parse_user_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<UserWsEvent>> {
+    match msg {
+        Ok(Message::Text(text)) => {
+            // The server can send either a single object or an array of events
+            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
+                return events
+                    .into_iter()
+                    .map(|event| {
+                        serde_json::from_value::<UserWsEvent>(event)
+                            .map_err(|e| Error::deserialization(e, &text))
+                    })
+                    .collect();
+            }
+
+            // Try parsing as single object
+            match serde_json::from_str::<UserWsEvent>(&text) {
+                Ok(event) => vec![Ok(event)],
+                Err(e) => vec![Err(Error::deserialization(e, &text))],
+            }
+        }
+        Ok(Message::Close(close_frame)) => {
+            // Connection closed - may indicate auth failure
+            if let Some(frame) = close_frame {
+                vec![Err(Error::WebSocket(format!(
+                    "Connection closed: code={}, reason={}",
+                    frame.code, frame.reason
+                )))]
+            } else {
+                vec![Err(Error::ConnectionClosed)]
+            }
+        }
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
+            // Ignore ping/pong frames (handled automatically)
+            Vec::new()
+        }
+        Ok(Message::Binary(_)) => {
+            // Unexpected binary message
+            vec![Err(Error::WebSocket(
+                "Unexpected binary message".to_string(),
+            ))]
+        }
+        Ok(Message::Frame(_)) => {
+            // Raw frame (shouldn't happen)
+            Vec::new()
+        }
+        Err(e) => {
+            // WebSocket error
+            vec![Err(Error::WebSocket(e.to_string()))]
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::time::Duration;
+
     #[test]
     fn test_client_creation() {
         let client = UserWsClient::new();
         assert_eq!(client.ws_url, UserWsClient::DEFAULT_WS_URL);
     }
+
+    fn order_json(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "associate_trades": null,
+            "event_type": "order",
+            "id": id,
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "order_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "original_size": "10",
+            "outcome": "YES",
+            "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+            "price": "0.57",
+            "side": "SELL",
+            "size_matched": "0",
+            "timestamp": "1672290687",
+            "type": "PLACEMENT",
+            "order_type": "GTC",
+            "status": "LIVE",
+            "maker_address": "0x8d1d8a2e2c9d3a63ba6d9a1f4b8a0c8f4c7e1a7b"
+        })
+    }
+
+    fn order_id(event: Result<UserWsEvent>) -> String {
+        match event.unwrap() {
+            UserWsEvent::Order(order) => order.id,
+            other => panic!("expected an order, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_array_frame_yields_every_event() {
+        let text =
+            serde_json::json!([order_json("1"), order_json("2"), order_json("3")]).to_string();
+        let ids: Vec<_> = parse_user_message(Ok(Message::Text(text)))
+            .into_iter()
+            .map(order_id)
+            .collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+
+        // A malformed element does not hide the others
+        let text = serde_json::json!([order_json("1"), { "event_type": "order" }]).to_string();
+        assert!(matches!(
+            parse_user_message(Ok(Message::Text(text))).as_slice(),
+            [
+                Ok(UserWsEvent::Order(_)),
+                Err(Error::Deserialization { .. })
+            ]
+        ));
+
+        assert!(parse_user_message(Ok(Message::Text("[]".into()))).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_markets_reconnecting_authenticates_again() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Each connection gets one order event, then the server hangs up
+        let server = tokio::spawn(async move {
+            let mut auths = Vec::new();
+            for id in ["1", "2"] {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                match ws.next().await {
+                    Some(Ok(Message::Text(text))) => {
+                        auths.push(serde_json::from_str::<serde_json::Value>(&text).unwrap())
+                    }
+                    other => panic!("expected an auth message, got {:?}", other),
+                }
+                ws.send(Message::Text(order_json(id).to_string()))
+                    .await
+                    .unwrap();
+                ws.close(None).await.unwrap();
+            }
+            auths
+        });
+
+        let creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let stream = UserWsClient::with_url(format!("ws://{}", addr))
+            .subscribe_markets_reconnecting(&creds, vec!["0xabc".to_string()], config);
+        futures_util::pin_mut!(stream);
+
+        let mut ids = Vec::new();
+        while ids.len() < 2 {
+            let event = tokio::time::timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap()
+                .unwrap();
+            ids.push(order_id(event));
+        }
+        assert_eq!(ids, vec!["1", "2"]);
+
+        let auths = server.await.unwrap();
+        assert_eq!(auths.len(), 2);
+        for auth in auths {
+            assert_eq!(auth["auth"]["apiKey"], "key");
+            assert_eq!(auth["markets"], serde_json::json!(["0xabc"]));
+        }
+    }
 }